@@ -1,3 +1,5 @@
+use near_contract_standards::storage_management::StorageBalance;
+
 use crate::*;
 
 #[near_bindgen]
@@ -29,6 +31,7 @@ impl BountiesContract {
     deadline: Option<U64>,
     description: String,
     slot: Option<usize>,
+    team: Option<Vec<TeamMember>>,
   ) -> bool {
     if !is_promise_success() || result.is_err() {
       env::log_str("Could not create claimant proposal");
@@ -40,6 +43,7 @@ impl BountiesContract {
         None,
         deadline,
         description,
+        team,
         Some(U64(result.unwrap())),
         slot,
         None,
@@ -55,15 +59,23 @@ impl BountiesContract {
     claimant: Option<(AccountId, Option<u8>)>,
     amounts: (U128, U128, U128),
     place: Option<usize>,
+    payouts: Vec<(AccountId, U128)>,
+    transfer_call: bool,
   ) -> bool {
-    if !is_promise_success() {
-      env::log_str("Bounty payout failed");
-      false
-    } else {
-      let bounty = self.get_bounty(id);
-      self.internal_bounty_completion(id, bounty, claimant, amounts, place);
-      true
+    let (receiver_id, claim_number) = claimant.clone().unwrap();
+    let is_success = self.internal_record_payout_results(
+      id,
+      receiver_id,
+      claim_number,
+      payouts,
+      transfer_call
+    );
+    if !is_success {
+      env::log_str("Bounty payout failed, the unpaid amount is recorded as a pending payout");
     }
+    let bounty = self.internal_get_bounty(id);
+    self.internal_bounty_completion(id, bounty, claimant, amounts, place);
+    is_success
   }
 
   #[private]
@@ -73,15 +85,22 @@ impl BountiesContract {
     receiver_id: AccountId,
     claim_number: Option<u8>,
     slot: usize,
+    payouts: Vec<(AccountId, U128)>,
+    transfer_call: bool,
   ) -> bool {
-    if !is_promise_success() {
-      env::log_str("Bounty payout failed");
-      false
-    } else {
-      let bounty = self.get_bounty(id);
-      self.internal_slot_finalize(id, bounty, receiver_id, claim_number, slot);
-      true
+    let is_success = self.internal_record_payout_results(
+      id,
+      receiver_id.clone(),
+      claim_number,
+      payouts,
+      transfer_call
+    );
+    if !is_success {
+      env::log_str("Bounty payout failed, the unpaid amount is recorded as a pending payout");
     }
+    let bounty = self.internal_get_bounty(id);
+    self.internal_slot_finalize(id, bounty, receiver_id, claim_number, slot);
+    is_success
   }
 
   #[private]
@@ -93,7 +112,7 @@ impl BountiesContract {
       env::log_str("Bounty refund failed");
       false
     } else {
-      let bounty = self.get_bounty(id);
+      let bounty = self.internal_get_bounty(id);
       self.internal_bounty_cancellation(id, bounty);
       true
     }
@@ -120,10 +139,71 @@ impl BountiesContract {
     }
   }
 
+  #[private]
+  pub fn after_platform_fee_share_payout(
+    &mut self,
+    token_id: AccountId,
+    account_id: AccountId,
+    amount: U128,
+  ) -> bool {
+    let success = is_promise_success();
+    if !success {
+      env::log_str("Platform fee share payout failed");
+    }
+    self.internal_platform_fee_share_completion(token_id, account_id, amount, success);
+    success
+  }
+
+  #[private]
+  pub fn after_storage_balance_of(
+    &mut self,
+    #[callback_result] result: Result<Option<StorageBalance>, PromiseError>,
+    token_id: AccountId,
+    account_id: AccountId,
+  ) -> PromiseOrValue<()> {
+    // If the storage balance cannot be checked, the transfer is still attempted
+    if !matches!(result, Ok(None)) {
+      return PromiseOrValue::Value(());
+    }
+    self.internal_subsidize_storage_deposit(token_id, account_id)
+  }
+
+  #[private]
+  pub fn after_storage_deposit(
+    &mut self,
+    token_id: AccountId,
+    account_id: AccountId,
+    amount: U128,
+  ) -> bool {
+    if !is_promise_success() {
+      self.storage_subsidy_pool += amount.0;
+      log!("Failed to register {} on {}", account_id, token_id);
+      false
+    } else {
+      log!("{} is registered on {} at the expense of the storage subsidy pool", account_id, token_id);
+      true
+    }
+  }
+
+  #[private]
+  pub fn after_pending_payout(
+    &mut self,
+    account_id: AccountId,
+    token_id: AccountId,
+    amount: U128,
+  ) -> bool {
+    let success = is_promise_success();
+    if !success {
+      env::log_str("Pending payout failed");
+    }
+    self.internal_pending_payout_completion(account_id, token_id, amount, success);
+    success
+  }
+
   #[private]
   pub fn after_check_bounty_payout_proposal(
     &mut self,
-    #[callback_result] result: Result<Proposal, PromiseError>,
+    #[callback_result] result: Result<serde_json::Value, PromiseError>,
     id: BountyIndex,
     claimant: Option<(AccountId, Option<u8>)>,
     proposal_id: U64,
@@ -131,25 +211,23 @@ impl BountiesContract {
     if !is_promise_success() || result.is_err() {
       env::panic_str("Error checking proposal status");
     } else {
-      let proposal = result.unwrap();
-      assert_eq!(proposal.id, proposal_id.0);
-      assert_eq!(proposal.proposer, env::current_account_id());
+      let proposal = self.internal_get_dao_proposal(id, result, proposal_id);
 
       if claimant.is_none() {
-        let bounty = self.get_bounty(id);
+        let bounty = self.internal_get_bounty(id);
         assert!(
           matches!(bounty.status, BountyStatus::ManyClaimed),
           "Bounty status does not allow completion"
         );
 
-        if proposal.status == "Approved" {
+        if proposal.status == DaoProposalStatus::Approved {
           self.internal_bounty_payout(id, None, None)
         } else {
           env::panic_str("The proposal status is not being processed");
         }
       } else {
         let (receiver_id, claim_number) = claimant.clone().unwrap();
-        let (mut bounty, claim, _) = self.internal_get_and_check_bounty_and_claim(
+        let (mut bounty, claim, _) = self.try_get_and_check_bounty_and_claim(
           id.clone(),
           receiver_id.clone(),
           claim_number,
@@ -158,12 +236,12 @@ impl BountiesContract {
           false,
           "Bounty status does not allow completion",
           "The claim status does not allow you to complete the bounty"
-        );
+        ).unwrap_or_else(|e| panic!("{}", e));
         let (claim_id, mut bounty_claim) = claim.unwrap();
 
-        if proposal.status == "Approved" {
+        if proposal.status == DaoProposalStatus::Approved {
           self.internal_bounty_payout(id, claimant, None)
-        } else if proposal.status == "Rejected" {
+        } else if proposal.status == DaoProposalStatus::Rejected {
           self.internal_reject_claim(id, receiver_id, &mut bounty, claim_id, &mut bounty_claim)
         } else {
           env::panic_str("The proposal status is not being processed");
@@ -175,7 +253,7 @@ impl BountiesContract {
   #[private]
   pub fn after_check_approve_claimant_proposal(
     &mut self,
-    #[callback_result] result: Result<Proposal, PromiseError>,
+    #[callback_result] result: Result<serde_json::Value, PromiseError>,
     id: BountyIndex,
     receiver_id: AccountId,
     claim_number: Option<u8>,
@@ -184,12 +262,10 @@ impl BountiesContract {
     if !is_promise_success() || result.is_err() {
       env::panic_str("Error checking proposal status");
     } else {
-      let proposal = result.unwrap();
-      assert_eq!(proposal.id, proposal_id.0);
-      assert_eq!(proposal.proposer, env::current_account_id());
-      if proposal.status == "Approved" {
+      let proposal = self.internal_get_dao_proposal(id, result, proposal_id);
+      if proposal.status == DaoProposalStatus::Approved {
         self.internal_approval_and_save_claim(id, Some((receiver_id, claim_number)), None, None)
-      } else if proposal.status == "Rejected" {
+      } else if proposal.status == DaoProposalStatus::Rejected {
         self.internal_rejection_and_save_claim(Some((id, receiver_id, claim_number)), None)
       } else {
         env::panic_str("The proposal status is not being processed");
@@ -197,6 +273,72 @@ impl BountiesContract {
     }
   }
 
+  #[private]
+  pub fn after_check_stuck_proposal(
+    &mut self,
+    #[callback_result] result: Result<serde_json::Value, PromiseError>,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+    proposal_id: U64,
+    payer_id: AccountId,
+    bond: U128,
+  ) -> PromiseOrValue<()> {
+    let resubmitted = if !is_promise_success() || result.is_err() {
+      Err("Error checking proposal status".to_string())
+    } else {
+      let proposal = self.internal_get_dao_proposal(id, result, proposal_id);
+      self.internal_resubmit_proposal(
+        id,
+        receiver_id,
+        claim_number,
+        Some(proposal),
+        payer_id.clone(),
+        bond,
+      )
+    };
+    resubmitted.unwrap_or_else(|e| {
+      env::log_str(&e);
+      Self::internal_return_proposal_bond(payer_id, bond)
+    })
+  }
+
+  #[private]
+  pub fn after_resubmit_proposal(
+    &mut self,
+    #[callback_result] result: Result<u64, PromiseError>,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+    kind: PendingReviewKind,
+    payer_id: AccountId,
+    bond: U128,
+  ) -> bool {
+    if !is_promise_success() || result.is_err() {
+      env::log_str("Could not resubmit the proposal");
+      Self::internal_return_proposal_bond(payer_id, bond);
+      return false;
+    }
+
+    // The claim could have been changed while the proposal was being created
+    match self.try_get_pending_proposal(id, receiver_id.clone(), claim_number) {
+      Ok((bounty, _, pending_kind, None)) if pending_kind == kind => {
+        let proposal_id = result.unwrap();
+        if let Some(Reviewers::ValidatorsDao { validators_dao }) = bounty.reviewers {
+          if bond.0 > 0 {
+            self.proposal_bonds.insert(&(validators_dao.account_id, proposal_id), &(payer_id, bond));
+          }
+        }
+        self.internal_set_proposal_id(id, receiver_id, claim_number, kind, Some(U64(proposal_id)));
+        true
+      },
+      _ => {
+        env::log_str("The claim is no longer waiting for the resubmitted proposal");
+        false
+      }
+    }
+  }
+
   #[private]
   pub fn after_create_dispute(
     &mut self,
@@ -210,7 +352,7 @@ impl BountiesContract {
       false
     } else {
       let dispute_id = result.unwrap();
-      let (_, claim, _) = self.internal_get_and_check_bounty_and_claim(
+      let (_, claim, _) = self.try_get_and_check_bounty_and_claim(
         id.clone(),
         receiver_id.clone(),
         claim_number,
@@ -219,7 +361,7 @@ impl BountiesContract {
         false,
         "Bounty status does not allow opening a dispute",
         "The claim status does not allow opening a dispute"
-      );
+      ).unwrap_or_else(|e| panic!("{}", e));
       let (claim_id, mut bounty_claim) = claim.unwrap();
       bounty_claim.status = ClaimStatus::Disputed;
       bounty_claim.dispute_id = Some(dispute_id.into());
@@ -240,7 +382,7 @@ impl BountiesContract {
       env::panic_str("Error checking dispute status");
     } else {
       let dispute = result.unwrap();
-      let (mut bounty, claim, _) = self.internal_get_and_check_bounty_and_claim(
+      let (mut bounty, claim, _) = self.try_get_and_check_bounty_and_claim(
         id.clone(),
         receiver_id.clone(),
         claim_number,
@@ -249,7 +391,7 @@ impl BountiesContract {
         false,
         "Bounty status does not allow to reject a claim as a result of a dispute",
         "Claim status does not allow rejection as a result of a dispute"
-      );
+      ).unwrap_or_else(|e| panic!("{}", e));
       let (claim_id, mut bounty_claim) = claim.unwrap();
 
       if dispute.status == "InFavorOfClaimant" || dispute.status == "CanceledByProjectOwner" {
@@ -285,29 +427,78 @@ impl BountiesContract {
       env::log_str("Error getting token metadata");
       false
     } else {
-      self.total_fees.insert(&token_id, &FeeStats::new());
-      self.tokens.insert(&token_id, &TokenDetails {
-        enabled: true,
-        min_amount_for_kyc,
-      });
+      let metadata = result.unwrap();
+      let token_details = match self.tokens.get(&token_id) {
+        Some(token_details) => TokenDetails {
+          symbol: Some(metadata.symbol),
+          decimals: Some(metadata.decimals),
+          ..token_details
+        },
+        None => {
+          self.total_fees.insert(&token_id, &FeeStats::new());
+          TokenDetails {
+            enabled: true,
+            min_amount_for_kyc,
+            min_amount_for_kyb: None,
+            symbol: Some(metadata.symbol),
+            decimals: Some(metadata.decimals),
+            min_bounty_amount: None,
+            max_bounty_amount: None,
+            min_slot_amount: None,
+          }
+        },
+      };
+      self.tokens.insert(&token_id, &token_details);
       true
     }
   }
 
+  /// Returns the amount of tokens to be refunded to the owner.
   #[private]
-  pub fn after_check_if_whitelisted(
+  pub fn after_check_if_owner_verified(
     &mut self,
     #[callback_result] result: Result<bool, PromiseError>,
+    bounty_create: BountyCreate,
+    owner_id: AccountId,
+    token_id: AccountId,
+    amount: U128,
+  ) -> U128 {
+    match result {
+      Ok(true) => {
+        self.internal_create_bounty(bounty_create, &owner_id, Some(token_id), amount);
+        U128(0)
+      },
+      Ok(false) => {
+        log!("The bounty owner {} has not passed KYB, the tokens are refunded", owner_id);
+        amount
+      },
+      Err(_) => {
+        env::log_str("Error determining the owner's KYB status, the tokens are refunded");
+        amount
+      },
+    }
+  }
+
+  #[private]
+  pub fn after_check_if_whitelisted(
+    &mut self,
     id: BountyIndex,
     receiver_id: AccountId,
     claim_number: Option<u8>,
     place_of_check: PlaceOfCheckKYC,
     slot: Option<usize>,
   ) -> PromiseOrValue<()> {
-    if !is_promise_success() || result.is_err() {
+    // There is one result for each member of a team claim
+    let results: Vec<Option<bool>> = (0..env::promise_results_count())
+      .map(|index| match env::promise_result(index) {
+        PromiseResult::Successful(value) => serde_json::from_slice::<bool>(&value).ok(),
+        _ => None,
+      })
+      .collect();
+    if results.is_empty() || results.contains(&None) {
       env::panic_str("Error determining the claimant's KYC status");
     } else {
-      let is_whitelisted = result.unwrap();
+      let is_whitelisted = results.into_iter().all(|result| result.unwrap());
       if is_whitelisted {
         match place_of_check {
           PlaceOfCheckKYC::CreatingClaim { .. } => {
@@ -328,6 +519,10 @@ impl BountiesContract {
               is_kyc_delayed
             )
           },
+          PlaceOfCheckKYC::ClaimHandover { .. } => {
+            self.internal_hand_over_claim(id, receiver_id, claim_number);
+            PromiseOrValue::Value(())
+          },
           _ => {
             self.internal_add_proposal_and_update_claim(id, receiver_id, claim_number, place_of_check)
           },
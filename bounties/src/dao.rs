@@ -0,0 +1,195 @@
+use crate::*;
+
+/// Framework of the validators DAO, defines how proposals are created and checked
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug))]
+pub enum DaoKind {
+  SputnikV2,
+  Multisig,
+}
+
+impl Default for DaoKind {
+  fn default() -> Self {
+    DaoKind::SputnikV2
+  }
+}
+
+impl DaoKind {
+  pub fn adapter(&self) -> &'static dyn DaoAdapter {
+    match self {
+      DaoKind::SputnikV2 => &SputnikV2Adapter,
+      DaoKind::Multisig => &MultisigAdapter,
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug))]
+pub enum DaoProposalStatus {
+  InProgress,
+  Approved,
+  Rejected,
+  Expired,
+  Removed,
+  Other,
+}
+
+/// DAO proposal reduced to the fields used by the bounty contract
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct DaoProposal {
+  pub id: u64,
+  pub proposer: AccountId,
+  pub status: DaoProposalStatus,
+}
+
+/// Maps the proposals of a DAO framework onto the approve-claimant and bounty-payout flows.
+/// The framework must accept proposals from the bounty contract, call the given method
+/// of the bounty contract once a proposal is approved, and keep the proposal with its id,
+/// proposer and status after it has been decided, so that bounty_finalize can check it.
+pub trait DaoAdapter {
+  /// The method and the arguments of the DAO call that creates the proposal
+  fn add_proposal_call(
+    &self,
+    description: String,
+    method_name: &str,
+    args: Vec<u8>,
+    gas: U64,
+  ) -> (String, Vec<u8>);
+
+  /// The method and the arguments of the DAO view call that returns the proposal
+  fn get_proposal_call(&self, proposal_id: U64) -> (String, Vec<u8>);
+
+  /// Decodes the result of the view call
+  fn parse_proposal(&self, value: serde_json::Value) -> Result<DaoProposal, String>;
+
+  /// The DAO call that applies the final status to the proposal whose voting period is over
+  /// and returns the bond of the expired proposal to the proposer.
+  /// None if the framework does not keep the bond.
+  fn finalize_proposal_call(&self, proposal_id: U64) -> Option<(String, Vec<u8>)>;
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+  pub id: u64,
+  pub proposer: AccountId,
+  pub status: String,
+}
+
+/// Sputnik DAO v2: `add_proposal` with a FunctionCall proposal kind and `get_proposal`
+pub struct SputnikV2Adapter;
+
+impl DaoAdapter for SputnikV2Adapter {
+  fn add_proposal_call(
+    &self,
+    description: String,
+    method_name: &str,
+    args: Vec<u8>,
+    gas: U64,
+  ) -> (String, Vec<u8>) {
+    let arguments = json!({
+      "proposal": {
+        "description": description,
+        "kind": {
+          "FunctionCall" : {
+            "receiver_id": env::current_account_id(),
+            "actions": [
+              {
+                "method_name": method_name,
+                "args": Base64VecU8::from(args),
+                "deposit": "1",
+                "gas": gas,
+              }
+            ],
+          }
+        }
+      }
+    });
+    ("add_proposal".to_string(), arguments.to_string().into_bytes())
+  }
+
+  fn get_proposal_call(&self, proposal_id: U64) -> (String, Vec<u8>) {
+    ("get_proposal".to_string(), json!({"id": proposal_id.0}).to_string().into_bytes())
+  }
+
+  fn parse_proposal(&self, value: serde_json::Value) -> Result<DaoProposal, String> {
+    let proposal: Proposal = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    let status = match proposal.status.as_str() {
+      "InProgress" => DaoProposalStatus::InProgress,
+      "Approved" => DaoProposalStatus::Approved,
+      "Rejected" => DaoProposalStatus::Rejected,
+      "Expired" => DaoProposalStatus::Expired,
+      "Removed" => DaoProposalStatus::Removed,
+      _ => DaoProposalStatus::Other,
+    };
+    Ok(DaoProposal { id: proposal.id, proposer: proposal.proposer, status })
+  }
+
+  fn finalize_proposal_call(&self, proposal_id: U64) -> Option<(String, Vec<u8>)> {
+    Some((
+      "act_proposal".to_string(),
+      json!({"id": proposal_id.0, "action": "Finalize"}).to_string().into_bytes(),
+    ))
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MultisigRequest {
+  pub request_id: u64,
+  pub proposer: AccountId,
+  pub status: String,
+}
+
+/// A simple on-chain multisig contract: `add_request` with one FunctionCall action,
+/// which is executed by the multisig when enough members confirm it, and `get_request`
+pub struct MultisigAdapter;
+
+impl DaoAdapter for MultisigAdapter {
+  fn add_proposal_call(
+    &self,
+    description: String,
+    method_name: &str,
+    args: Vec<u8>,
+    gas: U64,
+  ) -> (String, Vec<u8>) {
+    let arguments = json!({
+      "request": {
+        "receiver_id": env::current_account_id(),
+        "description": description,
+        "actions": [
+          {
+            "type": "FunctionCall",
+            "method_name": method_name,
+            "args": Base64VecU8::from(args),
+            "deposit": "1",
+            "gas": gas,
+          }
+        ],
+      }
+    });
+    ("add_request".to_string(), arguments.to_string().into_bytes())
+  }
+
+  fn get_proposal_call(&self, proposal_id: U64) -> (String, Vec<u8>) {
+    ("get_request".to_string(), json!({"request_id": proposal_id.0}).to_string().into_bytes())
+  }
+
+  fn parse_proposal(&self, value: serde_json::Value) -> Result<DaoProposal, String> {
+    let request: MultisigRequest = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    let status = match request.status.as_str() {
+      "Pending" => DaoProposalStatus::InProgress,
+      "Executed" => DaoProposalStatus::Approved,
+      "Rejected" => DaoProposalStatus::Rejected,
+      "Expired" => DaoProposalStatus::Expired,
+      "Deleted" => DaoProposalStatus::Removed,
+      _ => DaoProposalStatus::Other,
+    };
+    Ok(DaoProposal { id: request.request_id, proposer: request.proposer, status })
+  }
+
+  fn finalize_proposal_call(&self, _proposal_id: U64) -> Option<(String, Vec<u8>)> {
+    None
+  }
+}
@@ -1,18 +1,20 @@
 use std::collections::HashSet;
+use near_sdk::IntoStorageKey;
 
 use crate::*;
 
 impl BountiesContract {
   pub(crate) fn assert_that_token_is_allowed(&self, account_id: &AccountId) {
-    let token_details = self.tokens.get(account_id);
-    assert!(
-      token_details.is_some(),
-      "Predecessor account ID is not an allowed FT contract"
-    );
-    assert!(
-      token_details.unwrap().enabled,
-      "Creating a bounty using this token is not currently supported"
-    );
+    self.try_that_token_is_allowed(account_id).unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  pub(crate) fn try_that_token_is_allowed(&self, account_id: &AccountId) -> Result<(), String> {
+    match self.tokens.get(account_id) {
+      None => Err("Predecessor account ID is not an allowed FT contract".to_string()),
+      Some(token_details) if !token_details.enabled =>
+        Err("Creating a bounty using this token is not currently supported".to_string()),
+      _ => Ok(()),
+    }
   }
 
   pub(crate) fn assert_admins_whitelist(&self, account_id: &AccountId) {
@@ -22,28 +24,89 @@ impl BountiesContract {
     );
   }
 
-  pub(crate) fn assert_bounty_category_is_correct(&self, category: String) {
-    assert!(
-      self.config.clone().to_config().categories.contains(&category),
-      "Invalid bounty type {}",
-      category
-    );
+  pub(crate) fn assert_whitelist_manager(&self, account_id: &AccountId, whitelist_type: &WhitelistType) {
+    match whitelist_type {
+      WhitelistType::AdministratorsWhitelist => self.assert_admins_whitelist(account_id),
+      _ => self.assert_role(Role::WhitelistManager, account_id),
+    }
   }
 
-  pub(crate) fn assert_bounty_tags_are_correct(&self, tags: Vec<String>) {
-    tags.into_iter().for_each(|t| {
-      assert!(self.config.clone().to_config().tags.contains(&t), "Invalid bounty tag {}", t);
-    });
+  pub(crate) fn has_role(&self, role: Role, account_id: &AccountId) -> bool {
+    self.admins_whitelist.contains(account_id) ||
+      self.roles.get(&role).unwrap_or_default().contains(account_id)
   }
 
-  pub(crate) fn assert_bounty_currency_is_correct(&self, currency: String) {
+  pub(crate) fn assert_role(&self, role: Role, account_id: &AccountId) {
     assert!(
-      self.config.clone().to_config().currencies.contains(&currency),
-      "Invalid currency {}",
-      currency
+      self.has_role(role, account_id),
+      "The account does not have the {:?} role",
+      role
     );
   }
 
+  pub(crate) fn try_bounty_category_is_correct(&self, category: String) -> Result<(), String> {
+    if !self.config.clone().to_config().categories.contains(&category) {
+      return Err(format!("Invalid bounty type {}", category));
+    }
+    Ok(())
+  }
+
+  pub(crate) fn try_validators_dao_details_are_correct(
+    &self,
+    dao_details: &ValidatorsDaoDetails,
+  ) -> Result<(), String> {
+    if dao_details.fee_percentage > 100_000 {
+      return Err("The fee percentage of the validators DAO cannot exceed 100%".to_string());
+    }
+    dao_details.categories
+      .iter()
+      .flatten()
+      .try_for_each(|category| self.try_bounty_category_is_correct(category.clone()))
+  }
+
+  /// The registry entry of the validators DAO of the new bounty
+  pub(crate) fn try_get_validators_dao_details(
+    &self,
+    bounty_create: &BountyCreate,
+  ) -> Result<Option<ValidatorsDaoDetails>, String> {
+    let dao_account_id = match bounty_create.reviewers.as_ref() {
+      Some(reviewers) => reviewers.get_validators_dao_account_id(),
+      None => None,
+    };
+    match dao_account_id {
+      Some(dao_account_id) => {
+        let dao_details = self.validators_daos
+          .get(&dao_account_id)
+          .ok_or_else(|| "The validators DAO is not registered".to_string())?;
+        dao_details.try_category_is_supported(&bounty_create.metadata.category)?;
+        Ok(Some(dao_details))
+      },
+      None => Ok(None),
+    }
+  }
+
+  pub(crate) fn internal_get_validators_dao_details(
+    &self,
+    bounty_create: &BountyCreate,
+  ) -> Option<ValidatorsDaoDetails> {
+    self.try_get_validators_dao_details(bounty_create).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  pub(crate) fn try_bounty_tags_are_correct(&self, tags: Vec<String>) -> Result<(), String> {
+    let config = self.config.clone().to_config();
+    match tags.into_iter().find(|t| !config.tags.contains(t)) {
+      Some(t) => Err(format!("Invalid bounty tag {}", t)),
+      None => Ok(()),
+    }
+  }
+
+  pub(crate) fn try_bounty_currency_is_correct(&self, currency: String) -> Result<(), String> {
+    if !self.config.clone().to_config().currencies.contains(&currency) {
+      return Err(format!("Invalid currency {}", currency));
+    }
+    Ok(())
+  }
+
   pub(crate) fn assert_postpaid_is_ready(
     bounty: &Bounty,
     claim: &BountyClaim,
@@ -115,7 +178,8 @@ impl BountiesContract {
 
   pub(crate) fn internal_add_bounty(&mut self, bounty: Bounty) -> BountyIndex {
     let id = self.last_bounty_id;
-    self.internal_update_bounty(&id, bounty.clone());
+    self.internal_update_bounty_indexes(id, None, Some(&bounty.get_index_keys()));
+    self.bounties.insert(&id, &bounty.clone().into());
     let mut indices = self
       .account_bounties
       .get(&bounty.owner)
@@ -152,15 +216,20 @@ impl BountiesContract {
     token_id: Option<AccountId>,
     amount: U128
   ) {
+    let dao_details = self.internal_get_validators_dao_details(&bounty_create);
     let mut bounty = bounty_create.to_bounty(
       payer_id,
-      token_id,
+      token_id.clone(),
       amount,
-      self.config.clone().to_config()
+      self.config.clone().to_config(),
+      self.internal_get_min_amount_for_kyc(&token_id),
+      dao_details.as_ref()
     );
     self.internal_tag_filter(&mut bounty);
-    self.check_bounty(&bounty);
+    self.check_bounty(&mut bounty);
+    let initial_storage = env::storage_usage();
     let index = self.internal_add_bounty(bounty);
+    self.internal_charge_storage(payer_id, initial_storage);
     log!(
           "Created new bounty for {} with index {}",
           payer_id,
@@ -221,10 +290,112 @@ impl BountiesContract {
   }
 
   pub(crate) fn internal_get_unlocked_platform_fee_amount(&self, token_id: AccountId) -> U128 {
-    self.total_fees
+    let available_balance = self.total_fees
       .get(&token_id)
       .expect("No platform fees found")
-      .get_available_balance()
+      .get_available_balance();
+    let distributed_amount: Balance = self.platform_fee_shares
+      .get(&token_id)
+      .unwrap_or_default()
+      .iter()
+      .map(|s| s.amount.0)
+      .sum();
+    U128(
+      available_balance.0
+        .checked_sub(distributed_amount)
+        .expect("The platform fee shares exceed the available balance")
+    )
+  }
+
+  pub(crate) fn internal_distribute_platform_fee(&mut self, token_id: &AccountId) {
+    if self.platform_fee_recipients.is_empty() || self.total_fees.get(token_id).is_none() {
+      return;
+    }
+    let amount = self.internal_get_unlocked_platform_fee_amount(token_id.clone()).0;
+    if amount == 0 {
+      return;
+    }
+
+    let total_weight: u128 = self.platform_fee_recipients.iter().map(|r| r.weight as u128).sum();
+    let mut shares = self.platform_fee_shares.get(token_id).unwrap_or_default();
+    let mut rest = amount;
+    let last_index = self.platform_fee_recipients.len() - 1;
+    for (index, recipient) in self.platform_fee_recipients.iter().enumerate() {
+      let portion = if index == last_index {
+        rest
+      } else {
+        amount * recipient.weight as u128 / total_weight
+      };
+      rest -= portion;
+
+      if let Some(share) = shares.iter_mut().find(|s| s.account_id == recipient.account_id) {
+        share.amount = U128(share.amount.0 + portion);
+      } else {
+        shares.push(PlatformFeeShare {
+          account_id: recipient.account_id.clone(),
+          amount: U128(portion),
+          in_progress: false,
+        });
+      }
+    }
+    self.platform_fee_shares.insert(token_id, &shares);
+  }
+
+  pub(crate) fn internal_platform_fee_share_payout(
+    &mut self,
+    token_id: AccountId,
+    account_id: AccountId,
+  ) -> Promise {
+    let mut shares = self.platform_fee_shares.get(&token_id).unwrap_or_default();
+    let share = shares
+      .iter_mut()
+      .find(|s| s.account_id == account_id)
+      .expect("No platform fee share found");
+    share.in_progress = true;
+    let amount = share.amount;
+    self.platform_fee_shares.insert(&token_id, &shares);
+
+    ext_ft_contract::ext(token_id.clone())
+      .with_attached_deposit(ONE_YOCTO)
+      .with_static_gas(GAS_FOR_FT_TRANSFER)
+      .ft_transfer(
+        account_id.clone(),
+        amount,
+        Some(format!("Bounties platform fee transfer to {}", account_id)),
+      )
+      .then(
+        Self::ext(env::current_account_id())
+          .with_static_gas(GAS_FOR_AFTER_FT_TRANSACT)
+          .after_platform_fee_share_payout(token_id, account_id, amount)
+      )
+  }
+
+  pub(crate) fn internal_platform_fee_share_completion(
+    &mut self,
+    token_id: AccountId,
+    account_id: AccountId,
+    amount: U128,
+    success: bool,
+  ) {
+    let mut shares = self.platform_fee_shares.get(&token_id).unwrap_or_default();
+    let share_index = shares
+      .iter()
+      .position(|s| s.account_id == account_id)
+      .expect("No platform fee share found");
+    shares[share_index].in_progress = false;
+    if success {
+      shares[share_index].amount = U128(shares[share_index].amount.0 - amount.0);
+      if shares[share_index].amount.0 == 0 {
+        shares.remove(share_index);
+      }
+      self.internal_platform_fee_withdraw(token_id.clone(), amount);
+    }
+
+    if shares.is_empty() {
+      self.platform_fee_shares.remove(&token_id);
+    } else {
+      self.platform_fee_shares.insert(&token_id, &shares);
+    }
   }
 
   pub(crate) fn internal_platform_fee_withdraw(
@@ -386,10 +557,12 @@ impl BountiesContract {
         config.penalty_platform_fee_percentage as u128 /
         config.platform_fee_percentage as u128
     } else { 0 };
-    let penalty_validators_dao_fee: u128 = if config.validators_dao_fee_percentage != 0 {
-      dao_fee *
+    // The penalty is scaled by the fee rate of the DAO used when the bounty was created
+    let dao_fee_percentage = bounty.dao_fee_percentage.unwrap_or(config.validators_dao_fee_percentage);
+    let penalty_validators_dao_fee: u128 = if dao_fee_percentage != 0 {
+      (dao_fee *
         config.penalty_validators_dao_fee_percentage as u128 /
-        config.validators_dao_fee_percentage as u128
+        dao_fee_percentage as u128).min(dao_fee)
     } else { 0 };
     let amount_to_return = amount + platform_fee - penalty_platform_fee +
       dao_fee - penalty_validators_dao_fee;
@@ -574,9 +747,21 @@ impl BountiesContract {
     receiver_id: AccountId,
     claim_number: Option<u8>
   ) -> (ClaimIndex, BountyClaim) {
-    let claims = self.internal_get_claims_by_account_id_and_bounty_id(&id, &receiver_id, false);
+    self.try_get_claim(id, receiver_id, claim_number).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  pub(crate) fn try_get_claim(
+    &self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>
+  ) -> Result<(ClaimIndex, BountyClaim), String> {
+    if self.bounty_claimants.get(&receiver_id).unwrap_or_default().is_empty() {
+      return Err("No claimant found".to_string());
+    }
+    let claims = self.internal_get_claims_by_account_id_and_bounty_id(&id, &receiver_id, true);
     let claim = self.internal_find_claim(&claims, id, receiver_id, claim_number);
-    claim.expect("No bounty claim found")
+    claim.ok_or("No bounty claim found".to_string())
   }
 
   pub(crate) fn internal_get_payment_timestamps(
@@ -622,6 +807,7 @@ impl BountiesContract {
     claim_status: Option<ClaimStatus>,
     return_bond: bool,
   ) -> PromiseOrValue<()> {
+    let old_keys = bounty.get_index_keys();
     let old_status = bounty.status.clone();
     if bounty.multitasking.is_none() {
       bounty.status = BountyStatus::New;
@@ -660,7 +846,7 @@ impl BountiesContract {
       }
     }
 
-    self.internal_update_bounty(&id, bounty.clone());
+    self.internal_update_bounty(&id, bounty.clone(), &old_keys);
     self.internal_claim_closure(
       receiver_id,
       bounty.owner.clone(),
@@ -746,12 +932,216 @@ impl BountiesContract {
     )
   }
 
+  pub(crate) fn internal_get_bounty(&self, id: BountyIndex) -> Bounty {
+    let bounty = self.bounties.get(&id).expect("Bounty not found");
+    bounty.into()
+  }
+
+  /// Saves the changed bounty. The old index keys must be taken before the bounty was changed.
   pub(crate) fn internal_update_bounty(
     &mut self,
     id: &BountyIndex,
     bounty: Bounty,
+    old_keys: &BountyIndexKeys,
   ) {
-    self.bounties.insert(&id, &bounty.clone().into());
+    let mut bounty = bounty;
+    bounty.finalized_at = if bounty.is_finalized() {
+      bounty.finalized_at.or(Some(U64::from(env::block_timestamp())))
+    } else {
+      None
+    };
+    self.internal_update_bounty_indexes(*id, Some(old_keys), Some(&bounty.get_index_keys()));
+    self.bounties.insert(&id, &bounty.into());
+  }
+
+  fn internal_update_index<K: BorshSerialize + PartialEq>(
+    index: &mut LookupMap<K, UnorderedSet<BountyIndex>>,
+    index_key: StorageKey,
+    id: BountyIndex,
+    old_keys: &[K],
+    new_keys: &[K],
+  ) {
+    for key in old_keys.iter().filter(|k| !new_keys.contains(k)) {
+      if let Some(mut entry) = index.get(key) {
+        entry.remove(&id);
+        if entry.is_empty() {
+          index.remove(key);
+        } else {
+          index.insert(key, &entry);
+        }
+      }
+    }
+    let index_prefix = index_key.into_storage_key();
+    for key in new_keys.iter().filter(|k| !old_keys.contains(k)) {
+      let mut entry = index.get(key).unwrap_or_else(|| {
+        UnorderedSet::new(StorageKey::BountyIndexEntries {
+          index_prefix: index_prefix.clone(),
+          key_hash: env::sha256_array(&key.try_to_vec().unwrap()),
+        })
+      });
+      if entry.insert(&id) {
+        index.insert(key, &entry);
+      }
+    }
+  }
+
+  /// Moves the bounty between the entries of the status, category, tag, token and reviewer indexes.
+  pub(crate) fn internal_update_bounty_indexes(
+    &mut self,
+    id: BountyIndex,
+    old_keys: Option<&BountyIndexKeys>,
+    new_keys: Option<&BountyIndexKeys>,
+  ) {
+    let statuses = |k: Option<&BountyIndexKeys>| {
+      k.map(|k| vec![k.status.clone()]).unwrap_or_default()
+    };
+    let categories = |k: Option<&BountyIndexKeys>| {
+      k.map(|k| vec![k.category.clone()]).unwrap_or_default()
+    };
+    let tags = |k: Option<&BountyIndexKeys>| k.map(|k| k.tags.clone()).unwrap_or_default();
+    let tokens = |k: Option<&BountyIndexKeys>| {
+      k.and_then(|k| k.token.clone()).into_iter().collect::<Vec<AccountId>>()
+    };
+    let reviewers = |k: Option<&BountyIndexKeys>| {
+      k.map(|k| k.reviewers.clone()).unwrap_or_default()
+    };
+
+    Self::internal_update_index(
+      &mut self.bounties_by_status,
+      StorageKey::BountiesByStatus,
+      id,
+      &statuses(old_keys),
+      &statuses(new_keys),
+    );
+    Self::internal_update_index(
+      &mut self.bounties_by_category,
+      StorageKey::BountiesByCategory,
+      id,
+      &categories(old_keys),
+      &categories(new_keys),
+    );
+    Self::internal_update_index(
+      &mut self.bounties_by_tag,
+      StorageKey::BountiesByTag,
+      id,
+      &tags(old_keys),
+      &tags(new_keys),
+    );
+    Self::internal_update_index(
+      &mut self.bounties_by_token,
+      StorageKey::BountiesByToken,
+      id,
+      &tokens(old_keys),
+      &tokens(new_keys),
+    );
+    Self::internal_update_index(
+      &mut self.bounties_by_reviewer,
+      StorageKey::BountiesByReviewer,
+      id,
+      &reviewers(old_keys),
+      &reviewers(new_keys),
+    );
+  }
+
+  /// Returns `limit` of the bounties reviewed by the account, starting from the position
+  /// `from_index` in the reviewer index.
+  pub(crate) fn internal_get_reviewer_bounty_ids(
+    &self,
+    account_id: &AccountId,
+    from_index: usize,
+    limit: usize,
+  ) -> Vec<BountyIndex> {
+    self.bounties_by_reviewer
+      .get(account_id)
+      .map(|entry| {
+        let ids = entry.as_vector();
+        (from_index as u64..std::cmp::min((from_index + limit) as u64, ids.len()))
+          .filter_map(|index| ids.get(index))
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  /// Returns the claims of the unfinished bounties reviewed by the account that are waiting
+  /// for the claimant approval or the verdict on the result. Only `limit` of the reviewed bounties
+  /// starting from the position `from_index` are checked.
+  pub(crate) fn internal_get_pending_reviews(
+    &self,
+    account_id: &AccountId,
+    from_index: usize,
+    limit: usize,
+  ) -> Vec<PendingReview> {
+    self.internal_get_reviewer_bounty_ids(account_id, from_index, limit)
+      .into_iter()
+      .filter(|id| !self.internal_get_bounty(*id).is_finalized())
+      .flat_map(|id| {
+        self.internal_get_claims_by_bounty_id(id)
+          .into_iter()
+          .filter_map(move |(claim_id, claim)| {
+            let (kind, proposal_id) = match claim.status {
+              ClaimStatus::New =>
+                (PendingReviewKind::ClaimantApproval, claim.approve_claimant_proposal_id),
+              ClaimStatus::Completed =>
+                (PendingReviewKind::ResultVerdict, claim.bounty_payout_proposal_id),
+              _ => return None,
+            };
+            Some(PendingReview { bounty_id: id, claim_id, claim, kind, proposal_id })
+          })
+      })
+      .collect()
+  }
+
+  /// Returns `limit` of the bounty IDs of the smallest index entry that matches the filter,
+  /// starting from the position `from_index` in the entry. Only the lengths of the other entries
+  /// are compared. None if the filter does not use any of the indexes.
+  pub(crate) fn internal_get_filter_candidates(
+    &self,
+    filter: &BountyFilter,
+    from_index: u64,
+    limit: u64,
+  ) -> Option<Vec<BountyIndex>> {
+    // The entry of the statuses filter consists of the sets of all specified statuses
+    let mut entries: Vec<Vec<UnorderedSet<BountyIndex>>> = vec![];
+    if let Some(statuses) = filter.statuses.as_ref() {
+      entries.push(statuses.iter().filter_map(|s| self.bounties_by_status.get(s)).collect());
+    }
+    if let Some(category) = filter.category.as_ref() {
+      entries.push(self.bounties_by_category.get(category).into_iter().collect());
+    }
+    for tag in filter.tags.clone().unwrap_or_default() {
+      entries.push(self.bounties_by_tag.get(&tag).into_iter().collect());
+    }
+    if let Some(token) = filter.token.as_ref() {
+      entries.push(self.bounties_by_token.get(token).into_iter().collect());
+    }
+    let entry = entries
+      .into_iter()
+      .map(|sets| (sets.iter().map(|set| set.len()).sum::<u64>(), sets))
+      .min_by_key(|(len, _)| *len);
+
+    // The bounties of the owner are stored as one vector, its length is known only after loading
+    let owner_bounties = filter.owner.as_ref().map(|o| self.account_bounties.get(o).unwrap_or_default());
+    if let Some((len, sets)) = entry {
+      if !matches!(&owner_bounties, Some(ids) if (ids.len() as u64) < len) {
+        let mut ids = vec![];
+        let mut from_index = from_index;
+        for set in sets {
+          let set_ids = set.as_vector();
+          if from_index >= set_ids.len() {
+            from_index -= set_ids.len();
+            continue;
+          }
+          let end = std::cmp::min(set_ids.len(), from_index + limit - ids.len() as u64);
+          ids.extend((from_index..end).filter_map(|index| set_ids.get(index)));
+          if ids.len() as u64 >= limit {
+            break;
+          }
+          from_index = 0;
+        }
+        return Some(ids);
+      }
+    }
+    owner_bounties.map(|ids| ids.into_iter().skip(from_index as usize).take(limit as usize).collect())
   }
 
   pub(crate) fn internal_change_status_and_save_bounty(
@@ -760,8 +1150,9 @@ impl BountiesContract {
     bounty: &mut Bounty,
     status: BountyStatus,
   ) {
+    let old_keys = bounty.get_index_keys();
     bounty.status = status;
-    self.internal_update_bounty(&id, bounty.clone());
+    self.internal_update_bounty(&id, bounty.clone(), &old_keys);
   }
 
   pub(crate) fn is_claim_active(claim: &BountyClaim) -> bool {
@@ -771,6 +1162,42 @@ impl BountiesContract {
       || claim.status == ClaimStatus::Disputed
   }
 
+  /// Cancels all unfinished claims of the bounty and returns their bonds.
+  /// If some claimants have already been paid, the bounty is switched to the AwaitingClaims status,
+  /// so that only the remaining amount is returned to the owner.
+  /// Disputed claims are left to the dispute contract, while any of them remains the bounty
+  /// keeps its status and false is returned.
+  pub(crate) fn internal_force_cancel_claims(&mut self, id: BountyIndex, bounty: &mut Bounty) -> bool {
+    let claims = self.internal_get_claims_by_bounty_id(id);
+    let has_approved_claims = claims.iter().any(|(_, c)| c.status == ClaimStatus::Approved);
+    let has_disputed_claims = claims.iter().any(|(_, c)| c.status == ClaimStatus::Disputed);
+
+    for (claim_id, mut claim) in claims {
+      if [
+        ClaimStatus::New,
+        ClaimStatus::ReadyToStart,
+        ClaimStatus::InProgress,
+        ClaimStatus::Competes,
+        ClaimStatus::Completed,
+        ClaimStatus::Rejected,
+      ].contains(&claim.status) {
+        claim.status = ClaimStatus::Canceled;
+        self.claims.insert(&claim_id, &claim.clone().into());
+        self.internal_return_bonds(&claim.owner, claim.bond);
+      }
+    }
+
+    if has_disputed_claims {
+      return false;
+    }
+    bounty.status = if bounty.is_one_bounty_for_many_claimants() && has_approved_claims {
+      BountyStatus::AwaitingClaims
+    } else {
+      BountyStatus::New
+    };
+    true
+  }
+
   pub(crate) fn internal_find_active_claim(
     &self,
     id: BountyIndex,
@@ -778,7 +1205,7 @@ impl BountiesContract {
     let claim_ids = self.bounty_claims.get(&id).unwrap_or_default();
 
     for claim_id in claim_ids {
-      let bounty_claim = self.get_bounty_claim(claim_id);
+      let bounty_claim = self.internal_get_bounty_claim(claim_id);
       if Self::is_claim_active(&bounty_claim) {
         return (claim_id, bounty_claim);
       }
@@ -793,7 +1220,7 @@ impl BountiesContract {
     claimant: Option<(AccountId, Option<u8>)>,
     place: Option<usize>,
   ) -> PromiseOrValue<()> {
-    let bounty = self.get_bounty(id);
+    let bounty = self.internal_get_bounty(id);
     let amounts = Self::internal_get_bounty_amount_for_payment(&bounty, place);
     self.assert_locked_amount_greater_than_or_equal_transaction_amount(
       &bounty,
@@ -806,100 +1233,443 @@ impl BountiesContract {
       return PromiseOrValue::Value(())
     }
 
-    let (receiver_id, _) = claimant.clone().expect("Incorrect claimant parameter");
-    ext_ft_contract::ext(bounty.token.clone().unwrap())
-      .with_attached_deposit(ONE_YOCTO)
-      .with_static_gas(GAS_FOR_FT_TRANSFER)
-      .ft_transfer(
-        receiver_id,
+    let (receiver_id, claim_number) = claimant.clone().expect("Incorrect claimant parameter");
+    let (_, bounty_claim) = self.internal_get_claim(id, receiver_id, claim_number);
+    let (transfers, payouts, transfer_call) = if bounty_claim.team.is_some() {
+      let (transfers, payouts) = Self::internal_team_transfers(id, &bounty, &bounty_claim, amounts.0);
+      (transfers, payouts, false)
+    } else {
+      Self::internal_reward_transfer(
+        bounty.token.clone().unwrap(),
+        &bounty_claim,
         amounts.0,
-        Some(format!("Bounty {} payout", id)),
+        format!("Bounty {} payout", id),
       )
+    };
+    transfers
       .then(
         Self::ext(env::current_account_id())
           .with_static_gas(GAS_FOR_AFTER_FT_TRANSFER)
-          .after_ft_transfer(id, claimant, amounts, place)
+          .after_ft_transfer(id, claimant, amounts, place, payouts, transfer_call)
       )
       .into()
   }
 
-  pub(crate) fn internal_bounty_withdraw(
-    &mut self,
-    id: BountyIndex,
-    bounty: Bounty,
+  /// Transfers the reward to the claimant or to the payout target of the claim.
+  /// Also returns the receiver with the amount and whether ft_transfer_call is used.
+  pub(crate) fn internal_reward_transfer(
+    token_id: AccountId,
+    bounty_claim: &BountyClaim,
+    amount: U128,
+    memo: String,
+  ) -> (Promise, Vec<(AccountId, U128)>, bool) {
+    let token = ext_ft_contract::ext(token_id.clone()).with_attached_deposit(ONE_YOCTO);
+    let (transfer, receiver_id, transfer_call) = match bounty_claim.payout_target.clone() {
+      Some(PayoutTarget { receiver_id, msg: Some(msg) }) => (
+        token
+          .with_static_gas(GAS_FOR_FT_TRANSFER_CALL)
+          .ft_transfer_call(receiver_id.clone(), amount, Some(memo), msg),
+        receiver_id,
+        true,
+      ),
+      payout_target => {
+        let receiver_id = payout_target.map_or(bounty_claim.owner.clone(), |target| target.receiver_id);
+        (
+          token
+            .with_static_gas(GAS_FOR_FT_TRANSFER)
+            .ft_transfer(receiver_id.clone(), amount, Some(memo)),
+          receiver_id,
+          false,
+        )
+      },
+    };
+    (
+      Self::internal_with_receiver_registration(token_id, receiver_id.clone(), transfer),
+      vec![(receiver_id, amount)],
+      transfer_call,
+    )
+  }
+
+  /// Checks that the receiver is registered on the token contract before the transfer.
+  /// If not, the storage deposit is paid from the storage subsidy pool.
+  pub(crate) fn internal_with_receiver_registration(
+    token_id: AccountId,
     receiver_id: AccountId,
-    claim_number: Option<u8>,
-    slot: usize,
-  ) -> PromiseOrValue<()> {
-    let amount = self.internal_get_partial_amount(
-      id,
-      &bounty,
-      receiver_id.clone(),
-      claim_number,
-      slot
-    );
+    transfer: Promise,
+  ) -> Promise {
+    ext_ft_contract::ext(token_id.clone())
+      .with_static_gas(GAS_FOR_STORAGE_BALANCE_OF)
+      .storage_balance_of(receiver_id.clone())
+      .then(
+        Self::ext(env::current_account_id())
+          .with_static_gas(GAS_FOR_AFTER_STORAGE_BALANCE_OF)
+          .after_storage_balance_of(token_id, receiver_id)
+      )
+      .then(transfer)
+  }
 
-    if bounty.is_payment_outside_contract() {
-      self.internal_slot_finalize(id, bounty, receiver_id, claim_number, slot);
-      return PromiseOrValue::Value(())
+  /// Pays the storage deposit of the account on the token contract from the storage subsidy pool.
+  pub(crate) fn internal_subsidize_storage_deposit(
+    &mut self,
+    token_id: AccountId,
+    account_id: AccountId,
+  ) -> PromiseOrValue<()> {
+    let amount = self.config.clone().to_config().ft_storage_deposit;
+    if self.storage_subsidy_pool < amount.0 {
+      log!("The storage subsidy pool is not enough to register {} on {}", account_id, token_id);
+      return PromiseOrValue::Value(());
     }
 
-    ext_ft_contract::ext(bounty.token.clone().unwrap())
-      .with_attached_deposit(ONE_YOCTO)
-      .with_static_gas(GAS_FOR_FT_TRANSFER)
-      .ft_transfer(
-        receiver_id.clone(),
-        amount,
-        Some(format!("Bounty {} payment for {}", id, receiver_id)),
-      )
+    self.storage_subsidy_pool -= amount.0;
+    ext_ft_contract::ext(token_id.clone())
+      .with_attached_deposit(amount.0)
+      .with_static_gas(GAS_FOR_STORAGE_DEPOSIT)
+      .storage_deposit(Some(account_id.clone()), Some(true))
       .then(
         Self::ext(env::current_account_id())
-          .with_static_gas(GAS_FOR_AFTER_FT_TRANSFER)
-          .after_bounty_withdraw(id, receiver_id, claim_number, slot)
+          .with_static_gas(GAS_FOR_AFTER_STORAGE_DEPOSIT)
+          .after_storage_deposit(token_id, account_id, amount)
       )
       .into()
   }
 
-  pub(crate) fn internal_claim_return_after_dispute(
-    &mut self,
-    claim_id: ClaimIndex,
-    claim: &mut BountyClaim
-  ) -> PromiseOrValue<()> {
-    claim.status = ClaimStatus::CompletedWithDispute;
-    self.claims.insert(&claim_id, &claim.clone().into());
-    PromiseOrValue::Value(())
+  /// Transfers their shares of the amount to the team members who have not been paid yet.
+  /// The members are not registered on the token contract here, otherwise the checks of all
+  /// members do not fit into the gas limit. The failed transfers are recorded as pending payouts,
+  /// and withdraw_pending_payout registers the member before the transfer.
+  pub(crate) fn internal_team_transfers(
+    id: BountyIndex,
+    bounty: &Bounty,
+    bounty_claim: &BountyClaim,
+    amount: U128,
+  ) -> (Promise, Vec<(AccountId, U128)>) {
+    let payouts = bounty_claim.get_team_payouts(amount);
+    let transfers = payouts
+      .iter()
+      .map(|(account_id, amount)| {
+        ext_ft_contract::ext(bounty.token.clone().unwrap())
+          .with_attached_deposit(ONE_YOCTO)
+          .with_static_gas(GAS_FOR_FT_TRANSFER)
+          .ft_transfer(
+            account_id.clone(),
+            *amount,
+            Some(format!("Bounty {} payment for {}", id, account_id)),
+          )
+      })
+      .reduce(|promise, next| promise.and(next))
+      .expect("All team members have already been paid");
+    (transfers, payouts)
   }
 
-  pub(crate) fn internal_slot_finalize(
+  /// Records the rewards whose transfer has failed as pending payouts. The part of the reward
+  /// refunded by the receiver of ft_transfer_call is recorded for the claimant.
+  /// Marks the members of the team claim as paid. Returns true if all transfers have succeeded.
+  pub(crate) fn internal_record_payout_results(
     &mut self,
     id: BountyIndex,
-    mut bounty: Bounty,
     receiver_id: AccountId,
     claim_number: Option<u8>,
-    slot: usize,
-  ) {
-    let (claim_id, mut bounty_claim) = self.internal_get_claim(id, receiver_id.clone(), claim_number);
-    let with_dispute = bounty_claim.status == ClaimStatus::CompletedWithDispute;
-    assert!(
-      bounty_claim.status == ClaimStatus::Completed || with_dispute,
-      "The claim status does not allow this action"
-    );
-
-    bounty_claim.status = ClaimStatus::Approved;
-    self.claims.insert(&claim_id, &bounty_claim.clone().into());
-
-    self.internal_reset_slot(&mut bounty, slot);
-    self.internal_update_bounty(&id, bounty.clone());
+    payouts: Vec<(AccountId, U128)>,
+    transfer_call: bool,
+  ) -> bool {
+    let token_id = self.internal_get_bounty(id).token.unwrap();
+    let mut is_success = true;
+    for (index, (account_id, amount)) in payouts.iter().enumerate() {
+      let unpaid_amount = match env::promise_result(index as u64) {
+        PromiseResult::Successful(value) if transfer_call => serde_json::from_slice::<U128>(&value)
+          .map_or(0, |used_amount| amount.0.saturating_sub(used_amount.0)),
+        PromiseResult::Successful(_) => 0,
+        _ => amount.0,
+      };
+      if unpaid_amount > 0 {
+        is_success = false;
+        let creditor_id = if transfer_call { &receiver_id } else { account_id };
+        self.internal_add_pending_payout(creditor_id, &token_id, U128(unpaid_amount));
+      }
+    }
 
-    self.internal_update_statistic(
-      Some(receiver_id.clone()),
-      Some(bounty.owner),
-      ReputationActionKind::SuccessfulClaim { with_dispute },
-    );
-    self.internal_return_bonds(&receiver_id, bounty_claim.bond);
+    let (claim_id, mut bounty_claim) = self.internal_get_claim(id, receiver_id, claim_number);
+    if bounty_claim.team.is_some() {
+      let mut paid_team_members = bounty_claim.paid_team_members.clone().unwrap_or_default();
+      paid_team_members.extend(payouts.into_iter().map(|(account_id, _)| account_id));
+      bounty_claim.paid_team_members = Some(paid_team_members);
+      self.claims.insert(&claim_id, &bounty_claim.into());
+    }
+    is_success
   }
 
-  pub(crate) fn internal_bounty_cancellation(
+  pub(crate) fn internal_find_pending_payout(
+    &self,
+    account_id: &AccountId,
+    token_id: &AccountId,
+  ) -> Option<PendingPayout> {
+    self.pending_payouts
+      .get(account_id)
+      .unwrap_or_default()
+      .into_iter()
+      .find(|p| &p.token_id == token_id)
+  }
+
+  pub(crate) fn internal_add_pending_payout(
+    &mut self,
+    account_id: &AccountId,
+    token_id: &AccountId,
+    amount: U128,
+  ) {
+    let mut pending_payouts = self.pending_payouts.get(account_id).unwrap_or_default();
+    if let Some(pending_payout) = pending_payouts.iter_mut().find(|p| &p.token_id == token_id) {
+      pending_payout.amount = U128(pending_payout.amount.0 + amount.0);
+    } else {
+      pending_payouts.push(PendingPayout {
+        token_id: token_id.clone(),
+        amount,
+        in_progress: false,
+      });
+    }
+    self.pending_payouts.insert(account_id, &pending_payouts);
+    log!("Payout of {} {} to {} is pending", amount.0, token_id, account_id);
+  }
+
+  /// Removes the pending payout of the account and returns its amount.
+  pub(crate) fn internal_take_pending_payout(
+    &mut self,
+    account_id: &AccountId,
+    token_id: &AccountId,
+  ) -> U128 {
+    let mut pending_payouts = self.pending_payouts.get(account_id).unwrap_or_default();
+    let index = pending_payouts
+      .iter()
+      .position(|p| &p.token_id == token_id)
+      .expect("No pending payout found");
+    let pending_payout = pending_payouts.remove(index);
+    if pending_payouts.is_empty() {
+      self.pending_payouts.remove(account_id);
+    } else {
+      self.pending_payouts.insert(account_id, &pending_payouts);
+    }
+    pending_payout.amount
+  }
+
+  pub(crate) fn internal_pending_payout_transfer(
+    &mut self,
+    account_id: AccountId,
+    token_id: AccountId,
+  ) -> Promise {
+    let mut pending_payouts = self.pending_payouts.get(&account_id).unwrap_or_default();
+    let pending_payout = pending_payouts
+      .iter_mut()
+      .find(|p| p.token_id == token_id)
+      .expect("No pending payout found");
+    pending_payout.in_progress = true;
+    let amount = pending_payout.amount;
+    self.pending_payouts.insert(&account_id, &pending_payouts);
+
+    let transfer = ext_ft_contract::ext(token_id.clone())
+      .with_attached_deposit(ONE_YOCTO)
+      .with_static_gas(GAS_FOR_FT_TRANSFER)
+      .ft_transfer(
+        account_id.clone(),
+        amount,
+        Some(format!("Bounties pending payout to {}", account_id)),
+      );
+    Self::internal_with_receiver_registration(token_id.clone(), account_id.clone(), transfer)
+      .then(
+        Self::ext(env::current_account_id())
+          .with_static_gas(GAS_FOR_AFTER_FT_TRANSACT)
+          .after_pending_payout(account_id, token_id, amount)
+      )
+  }
+
+  pub(crate) fn internal_pending_payout_completion(
+    &mut self,
+    account_id: AccountId,
+    token_id: AccountId,
+    amount: U128,
+    success: bool,
+  ) {
+    let mut pending_payouts = self.pending_payouts.get(&account_id).unwrap_or_default();
+    let index = pending_payouts
+      .iter()
+      .position(|p| p.token_id == token_id)
+      .expect("No pending payout found");
+    pending_payouts[index].in_progress = false;
+    if success {
+      pending_payouts[index].amount = U128(pending_payouts[index].amount.0 - amount.0);
+      if pending_payouts[index].amount.0 == 0 {
+        pending_payouts.remove(index);
+      }
+    }
+
+    if pending_payouts.is_empty() {
+      self.pending_payouts.remove(&account_id);
+    } else {
+      self.pending_payouts.insert(&account_id, &pending_payouts);
+    }
+  }
+
+  /// Records the successful claim for the team members other than the claim owner.
+  pub(crate) fn internal_update_team_statistic(&self, bounty_claim: &BountyClaim, with_dispute: bool) {
+    bounty_claim
+      .get_members()
+      .into_iter()
+      .filter(|account_id| account_id != &bounty_claim.owner)
+      .for_each(|account_id| {
+        self.internal_update_statistic(
+          Some(account_id),
+          None,
+          ReputationActionKind::SuccessfulClaim { with_dispute },
+        );
+      });
+  }
+
+  pub(crate) fn internal_bounty_withdraw(
+    &mut self,
+    id: BountyIndex,
+    bounty: Bounty,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+    slot: usize,
+  ) -> PromiseOrValue<()> {
+    let amount = self.internal_get_partial_amount(
+      id,
+      &bounty,
+      receiver_id.clone(),
+      claim_number,
+      slot
+    );
+
+    if bounty.is_payment_outside_contract() {
+      self.internal_slot_finalize(id, bounty, receiver_id, claim_number, slot);
+      return PromiseOrValue::Value(())
+    }
+
+    let (_, bounty_claim) = self.internal_get_claim(id, receiver_id.clone(), claim_number);
+    let (transfers, payouts, transfer_call) = if bounty_claim.team.is_some() {
+      let (transfers, payouts) = Self::internal_team_transfers(id, &bounty, &bounty_claim, amount);
+      (transfers, payouts, false)
+    } else {
+      Self::internal_reward_transfer(
+        bounty.token.clone().unwrap(),
+        &bounty_claim,
+        amount,
+        format!("Bounty {} payment for {}", id, receiver_id),
+      )
+    };
+    transfers
+      .then(
+        Self::ext(env::current_account_id())
+          .with_static_gas(GAS_FOR_AFTER_FT_TRANSFER)
+          .after_bounty_withdraw(id, receiver_id, claim_number, slot, payouts, transfer_call)
+      )
+      .into()
+  }
+
+  pub(crate) fn internal_claim_return_after_dispute(
+    &mut self,
+    claim_id: ClaimIndex,
+    claim: &mut BountyClaim
+  ) -> PromiseOrValue<()> {
+    claim.status = ClaimStatus::CompletedWithDispute;
+    self.claims.insert(&claim_id, &claim.clone().into());
+    PromiseOrValue::Value(())
+  }
+
+  pub(crate) fn internal_slot_finalize(
+    &mut self,
+    id: BountyIndex,
+    mut bounty: Bounty,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+    slot: usize,
+  ) {
+    let old_keys = bounty.get_index_keys();
+    let (claim_id, mut bounty_claim) = self.internal_get_claim(id, receiver_id.clone(), claim_number);
+    let with_dispute = bounty_claim.status == ClaimStatus::CompletedWithDispute;
+    assert!(
+      bounty_claim.status == ClaimStatus::Completed || with_dispute,
+      "The claim status does not allow this action"
+    );
+
+    bounty_claim.status = ClaimStatus::Approved;
+    self.claims.insert(&claim_id, &bounty_claim.clone().into());
+
+    self.internal_reset_slot(&mut bounty, slot);
+    self.internal_update_bounty(&id, bounty.clone(), &old_keys);
+
+    self.internal_update_statistic(
+      Some(receiver_id.clone()),
+      Some(bounty.owner),
+      ReputationActionKind::SuccessfulClaim { with_dispute },
+    );
+    self.internal_update_team_statistic(&bounty_claim, with_dispute);
+    self.internal_return_bonds(&receiver_id, bounty_claim.bond);
+  }
+
+  /// Moves the claim to the successor as a new claim with the same start time, deadline and bond.
+  /// The original claim is closed with the HandedOver status. The successor pays for the new claim.
+  pub(crate) fn internal_hand_over_claim(
+    &mut self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) {
+    let (_, _, _, successor_id) = self
+      .try_get_claim_handover(id, receiver_id.clone(), claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
+    let (mut bounty, claim_id, mut bounty_claim, successor_claim_number) = self
+      .try_check_if_allowed_to_take_over_claim(id, &successor_id, receiver_id.clone(), claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
+    let old_keys = bounty.get_index_keys();
+
+    let initial_storage = env::storage_usage();
+    let successor_claim = BountyClaim {
+      owner: successor_id.clone(),
+      created_at: U64::from(env::block_timestamp()),
+      approve_claimant_proposal_id: None,
+      is_kyc_delayed: None,
+      claim_number: successor_claim_number,
+      handover_to: None,
+      handover_accepted: false,
+      payout_target: None,
+      reviewer_votes: None,
+      ..bounty_claim.clone()
+    };
+    bounty_claim.status = ClaimStatus::HandedOver;
+    bounty_claim.bond = None;
+    bounty_claim.handover_to = None;
+    bounty_claim.handover_accepted = false;
+    self.claims.insert(&claim_id, &bounty_claim.into());
+    self.internal_record_storage_usage(&receiver_id, initial_storage);
+
+    let initial_storage = env::storage_usage();
+    self.internal_add_claim(&successor_claim);
+
+    if bounty.is_different_tasks() {
+      self.internal_set_slot_account(
+        &mut bounty,
+        successor_claim.slot.unwrap(),
+        successor_id.clone(),
+        successor_claim_number,
+      );
+      self.internal_update_bounty(&id, bounty.clone(), &old_keys);
+    }
+    self.internal_charge_storage(&successor_id, initial_storage);
+
+    self.internal_update_statistic(
+      Some(successor_id.clone()),
+      Some(bounty.owner),
+      ReputationActionKind::ClaimHandedOver,
+    );
+    log!(
+      "The claim of {} #{:?} for bounty {} is handed over to {}",
+      receiver_id,
+      claim_number,
+      id,
+      successor_id
+    );
+  }
+
+  pub(crate) fn internal_bounty_cancellation(
     &mut self,
     id: BountyIndex,
     mut bounty: Bounty,
@@ -1022,41 +1792,23 @@ impl BountiesContract {
         PlaceOfCheckKYC::ClaimDone { description } => description,
         _ => unreachable!(),
       };
+      let (method_name, args, gas) = Self::internal_get_proposal_action(
+        id,
+        &bounty,
+        &validators_dao,
+        PendingReviewKind::ResultVerdict,
+        &receiver_id,
+        claim_number
+      );
       Self::internal_add_proposal(
-        validators_dao.account_id,
-        json!({
-          "proposal": {
-            "description": description,
-            "kind": {
-              "FunctionCall" : {
-                "receiver_id": env::current_account_id(),
-                "actions": [
-                  {
-                    "method_name": "bounty_approve",
-                    "args": Base64VecU8::from(json!({
-                      "id": id.clone(),
-                      "receiver_id": receiver_id.to_string(),
-                      "claim_number": claim_number,
-                      "place": Option::<usize>::None,
-                    })
-                      .to_string()
-                      .into_bytes()
-                      .to_vec()),
-                    "deposit": "1",
-                    "gas": validators_dao.gas_for_claim_approval,
-                  }
-                ],
-              }
-            }
-          }
-        })
-          .to_string()
-          .into_bytes(),
+        &validators_dao,
+        description,
+        method_name,
+        args,
+        gas,
         Self::ext(env::current_account_id())
           .with_static_gas(GAS_FOR_ON_ADDED_PROPOSAL_CALLBACK)
           .on_added_proposal_callback(id, receiver_id, claim_number),
-        validators_dao.add_proposal_bond,
-        validators_dao.gas_for_add_proposal,
       )
     } else {
       unreachable!();
@@ -1076,38 +1828,23 @@ impl BountiesContract {
         PlaceOfCheckKYC::ClaimDone { description } => description,
         _ => unreachable!(),
       };
+      let (method_name, args, gas) = Self::internal_get_proposal_action(
+        id,
+        &bounty,
+        &validators_dao,
+        PendingReviewKind::ResultVerdict,
+        &receiver_id,
+        claim_number
+      );
       Self::internal_add_proposal(
-        validators_dao.account_id,
-        json!({
-          "proposal": {
-            "description": description,
-            "kind": {
-              "FunctionCall" : {
-                "receiver_id": env::current_account_id(),
-                "actions": [
-                  {
-                    "method_name": "bounty_approve_of_several",
-                    "args": Base64VecU8::from(json!({
-                      "id": id.clone(),
-                    })
-                      .to_string()
-                      .into_bytes()
-                      .to_vec()),
-                    "deposit": "1",
-                    "gas": validators_dao.gas_for_claim_approval,
-                  }
-                ],
-              }
-            }
-          }
-        })
-          .to_string()
-          .into_bytes(),
+        &validators_dao,
+        description,
+        method_name,
+        args,
+        gas,
         Self::ext(env::current_account_id())
           .with_static_gas(GAS_FOR_ON_ADDED_PROPOSAL_CALLBACK)
           .on_added_proposal_callback(id, receiver_id, claim_number),
-        validators_dao.add_proposal_bond,
-        validators_dao.gas_for_add_proposal,
       )
     } else {
       unreachable!();
@@ -1123,61 +1860,93 @@ impl BountiesContract {
     deadline: Option<U64>,
     description: String,
     slot: Option<usize>,
+    team: Option<Vec<TeamMember>>,
   ) -> PromiseOrValue<()> {
     if let Reviewers::ValidatorsDao { validators_dao } = bounty.reviewers.clone().unwrap() {
+      let (method_name, args, gas) = Self::internal_get_proposal_action(
+        id,
+        &bounty,
+        &validators_dao,
+        PendingReviewKind::ClaimantApproval,
+        &receiver_id,
+        claim_number
+      );
       Self::internal_add_proposal(
-        validators_dao.account_id,
-        json!({
-          "proposal": {
-            "description": description,
-            "kind": {
-              "FunctionCall" : {
-                "receiver_id": env::current_account_id(),
-                "actions": [
-                  {
-                    "method_name": "accept_claimant",
-                    "args": Base64VecU8::from(json!({
-                      "id": id.clone(),
-                      "receiver_id": receiver_id.clone(),
-                      "claim_number": claim_number,
-                    })
-                      .to_string()
-                      .into_bytes()
-                      .to_vec()),
-                    "deposit": "1",
-                    "gas": validators_dao.gas_for_claimant_approval,
-                  }
-                ],
-              }
-            }
-          }
-        })
-          .to_string()
-          .into_bytes(),
+        &validators_dao,
+        description.clone(),
+        method_name,
+        args,
+        gas,
         Self::ext(env::current_account_id())
           .with_static_gas(GAS_FOR_AFTER_ADD_PROPOSAL)
-          .after_add_proposal(id, receiver_id, deadline, description, slot),
-        validators_dao.add_proposal_bond,
-        validators_dao.gas_for_add_proposal,
+          .after_add_proposal(id, receiver_id, deadline, description, slot, team),
       )
     } else {
       unreachable!();
     }
   }
 
+  /// The method of this contract, its arguments and gas to be called by the DAO proposal.
+  pub(crate) fn internal_get_proposal_action(
+    id: BountyIndex,
+    bounty: &Bounty,
+    validators_dao: &ValidatorsDao,
+    kind: PendingReviewKind,
+    receiver_id: &AccountId,
+    claim_number: Option<u8>,
+  ) -> (&'static str, serde_json::Value, U64) {
+    match kind {
+      PendingReviewKind::ClaimantApproval => (
+        "accept_claimant",
+        json!({
+          "id": id.clone(),
+          "receiver_id": receiver_id.clone(),
+          "claim_number": claim_number,
+        }),
+        validators_dao.gas_for_claimant_approval,
+      ),
+      PendingReviewKind::ResultVerdict if bounty.is_different_tasks() => (
+        "bounty_approve_of_several",
+        json!({
+          "id": id.clone(),
+        }),
+        validators_dao.gas_for_claim_approval,
+      ),
+      PendingReviewKind::ResultVerdict => (
+        "bounty_approve",
+        json!({
+          "id": id.clone(),
+          "receiver_id": receiver_id.to_string(),
+          "claim_number": claim_number,
+          "place": Option::<usize>::None,
+        }),
+        validators_dao.gas_for_claim_approval,
+      ),
+    }
+  }
+
+  /// Creates a proposal in the validators DAO to call the method of this contract.
+  /// The shape of the call depends on the DAO framework.
   pub(crate) fn internal_add_proposal(
-    receiver: AccountId,
-    arguments: Vec<u8>,
-    callback: Promise,
-    amount: U128,
+    validators_dao: &ValidatorsDao,
+    description: String,
+    method_name: &str,
+    args: serde_json::Value,
     gas: U64,
+    callback: Promise,
   ) -> PromiseOrValue<()> {
-    Promise::new(receiver)
+    let (method, arguments) = validators_dao.dao_kind.adapter().add_proposal_call(
+      description,
+      method_name,
+      args.to_string().into_bytes(),
+      gas,
+    );
+    Promise::new(validators_dao.account_id.clone())
       .function_call(
-        "add_proposal".to_string(),
+        method,
         arguments,
-        amount.0,
-        Gas(gas.0),
+        validators_dao.add_proposal_bond.0,
+        Gas(validators_dao.gas_for_add_proposal.0),
       )
       .then(callback)
       .into()
@@ -1192,7 +1961,7 @@ impl BountiesContract {
   ) -> PromiseOrValue<()> {
     if let Reviewers::ValidatorsDao {validators_dao} = bounty.reviewers.clone().unwrap() {
       Self::internal_check_proposal(
-        validators_dao.account_id,
+        &validators_dao,
         proposal_id,
         Self::ext(env::current_account_id())
           .with_static_gas(GAS_FOR_AFTER_CHECK_BOUNTY_PAYOUT_PROPOSAL)
@@ -1213,7 +1982,7 @@ impl BountiesContract {
   ) -> PromiseOrValue<()> {
     if let Reviewers::ValidatorsDao {validators_dao} = bounty.reviewers.clone().unwrap() {
       Self::internal_check_proposal(
-        validators_dao.account_id,
+        &validators_dao,
         proposal_id,
         Self::ext(env::current_account_id())
           .with_static_gas(GAS_FOR_AFTER_CHECK_APPROVE_CLAIMANT_PROPOSAL)
@@ -1224,22 +1993,212 @@ impl BountiesContract {
     }
   }
 
-  pub(crate) fn internal_check_proposal(
-    receiver: AccountId,
+  /// Finalizes the proposal before the check, because Sputnik DAO v2 keeps the status
+  /// of the proposal whose voting period is over as InProgress until it is finalized.
+  /// The check is done even if the finalization fails.
+  pub(crate) fn internal_finalize_and_check_proposal(
+    validators_dao: &ValidatorsDao,
     proposal_id: U64,
     callback: Promise,
   ) -> PromiseOrValue<()> {
-    Promise::new(receiver)
+    match validators_dao.dao_kind.adapter().finalize_proposal_call(proposal_id) {
+      Some((method, arguments)) => Promise::new(validators_dao.account_id.clone())
+        .function_call(method, arguments, NO_DEPOSIT, GAS_FOR_FINALIZE_PROPOSAL)
+        .then(Self::internal_get_proposal_promise(validators_dao, proposal_id))
+        .then(callback)
+        .into(),
+      None => Self::internal_check_proposal(validators_dao, proposal_id, callback),
+    }
+  }
+
+  pub(crate) fn internal_get_proposal_promise(
+    validators_dao: &ValidatorsDao,
+    proposal_id: U64,
+  ) -> Promise {
+    let (method, arguments) = validators_dao.dao_kind.adapter().get_proposal_call(proposal_id);
+    Promise::new(validators_dao.account_id.clone())
       .function_call(
-        "get_proposal".to_string(),
-        json!({"id": proposal_id.0}).to_string().into_bytes(),
+        method,
+        arguments,
         NO_DEPOSIT,
         GAS_FOR_CHECK_PROPOSAL,
       )
+  }
+
+  pub(crate) fn internal_check_proposal(
+    validators_dao: &ValidatorsDao,
+    proposal_id: U64,
+    callback: Promise,
+  ) -> PromiseOrValue<()> {
+    Self::internal_get_proposal_promise(validators_dao, proposal_id)
       .then(callback)
       .into()
   }
 
+  /// The proposal of the validators DAO that the claim is waiting for.
+  /// The proposal ID is None if the previous proposal was dropped and the new one was not created.
+  pub(crate) fn try_get_pending_proposal(
+    &self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, BountyClaim, PendingReviewKind, Option<U64>), String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    bounty.try_claim_number(claim_number)?;
+    if !bounty.is_validators_dao_used() {
+      return Err("Validators DAO are not used".to_string());
+    }
+    let (_, claim) = self.try_get_claim(id, receiver_id, claim_number)?;
+
+    if claim.status == ClaimStatus::New && bounty.status == BountyStatus::New {
+      let proposal_id = claim.approve_claimant_proposal_id;
+      Ok((bounty, claim, PendingReviewKind::ClaimantApproval, proposal_id))
+    } else if bounty.is_different_tasks() &&
+      claim.status == ClaimStatus::Completed &&
+      bounty.status == BountyStatus::ManyClaimed &&
+      self.internal_are_all_slots_complete(id, &bounty, None)
+    {
+      let proposal_id = Self::internal_get_bounty_payout_proposal_id(&bounty);
+      Ok((bounty, claim, PendingReviewKind::ResultVerdict, proposal_id))
+    } else if !bounty.is_different_tasks() &&
+      !bounty.is_contest_or_hackathon() &&
+      claim.status == ClaimStatus::Completed &&
+      (bounty.status == BountyStatus::Claimed || bounty.status == BountyStatus::ManyClaimed)
+    {
+      let proposal_id = claim.bounty_payout_proposal_id;
+      Ok((bounty, claim, PendingReviewKind::ResultVerdict, proposal_id))
+    } else {
+      Err("The claim is not waiting for a proposal of the validators DAO".to_string())
+    }
+  }
+
+  pub(crate) fn internal_set_proposal_id(
+    &mut self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+    kind: PendingReviewKind,
+    proposal_id: Option<U64>,
+  ) {
+    let mut bounty = self.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    if kind == PendingReviewKind::ResultVerdict && bounty.is_different_tasks() {
+      bounty.multitasking = Some(
+        bounty.multitasking.clone().unwrap().set_bounty_payout_proposal_id(proposal_id)
+      );
+      self.internal_update_bounty(&id, bounty, &old_keys);
+    } else {
+      let (claim_id, mut claim) = self.internal_get_claim(id, receiver_id, claim_number);
+      match kind {
+        PendingReviewKind::ClaimantApproval => claim.approve_claimant_proposal_id = proposal_id,
+        PendingReviewKind::ResultVerdict => claim.bounty_payout_proposal_id = proposal_id,
+      }
+      self.claims.insert(&claim_id, &claim.into());
+    }
+  }
+
+  /// Drops the stuck proposal and creates a new one instead
+  pub(crate) fn internal_resubmit_proposal(
+    &mut self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+    stuck_proposal: Option<DaoProposal>,
+    payer_id: AccountId,
+    bond: U128,
+  ) -> Result<PromiseOrValue<()>, String> {
+    let (bounty, claim, kind, proposal_id) = self
+      .try_get_pending_proposal(id, receiver_id.clone(), claim_number)?;
+    let validators_dao = match bounty.reviewers.clone() {
+      Some(Reviewers::ValidatorsDao { validators_dao }) => validators_dao,
+      _ => unreachable!(),
+    };
+
+    if let Some(stuck_proposal) = stuck_proposal {
+      if proposal_id != Some(U64(stuck_proposal.id)) {
+        return Err("The proposal has already been changed".to_string());
+      }
+      let is_stuck = match stuck_proposal.status {
+        DaoProposalStatus::Expired | DaoProposalStatus::Removed => true,
+        // The rejection of the payout of several claims cannot be applied to the claims
+        DaoProposalStatus::Rejected =>
+          kind == PendingReviewKind::ResultVerdict && bounty.is_different_tasks(),
+        _ => false,
+      };
+      if !is_stuck {
+        return Err("The proposal is not stuck, it can be processed by bounty_finalize".to_string());
+      }
+
+      self.internal_set_proposal_id(id, receiver_id.clone(), claim_number, kind, None);
+      // The DAO has returned the bond of the expired or rejected proposal to the contract,
+      // the bond of a resubmitted proposal is passed on to the account that paid it
+      let bond_payer = self.proposal_bonds
+        .remove(&(validators_dao.account_id.clone(), stuck_proposal.id));
+      let is_bond_returned = stuck_proposal.status != DaoProposalStatus::Removed &&
+        validators_dao.dao_kind.adapter().finalize_proposal_call(U64(stuck_proposal.id)).is_some();
+      if let Some((bond_payer_id, paid_bond)) = bond_payer {
+        if is_bond_returned {
+          Self::internal_return_proposal_bond(bond_payer_id, paid_bond);
+        }
+      }
+    } else if proposal_id.is_some() {
+      return Err("The proposal must be checked before resubmitting".to_string());
+    }
+
+    let (method_name, args, gas) = Self::internal_get_proposal_action(
+      id,
+      &bounty,
+      &validators_dao,
+      kind,
+      &receiver_id,
+      claim_number
+    );
+    Ok(Self::internal_add_proposal(
+      &validators_dao,
+      claim.description,
+      method_name,
+      args,
+      gas,
+      Self::ext(env::current_account_id())
+        .with_static_gas(GAS_FOR_AFTER_RESUBMIT_PROPOSAL)
+        .after_resubmit_proposal(id, receiver_id, claim_number, kind, payer_id, bond),
+    ))
+  }
+
+  /// Returns the proposal bond attached to resubmit_dao_proposal
+  pub(crate) fn internal_return_proposal_bond(
+    payer_id: AccountId,
+    bond: U128,
+  ) -> PromiseOrValue<()> {
+    if bond.0 > 0 {
+      Promise::new(payer_id).transfer(bond.0).into()
+    } else {
+      PromiseOrValue::Value(())
+    }
+  }
+
+  /// Decodes the proposal returned by the validators DAO of the bounty
+  pub(crate) fn internal_get_dao_proposal(
+    &self,
+    id: BountyIndex,
+    result: Result<serde_json::Value, PromiseError>,
+    proposal_id: U64,
+  ) -> DaoProposal {
+    let bounty = self.internal_get_bounty(id);
+    let validators_dao = match bounty.reviewers {
+      Some(Reviewers::ValidatorsDao { validators_dao }) => validators_dao,
+      _ => env::panic_str("Validators DAO are not used"),
+    };
+    let proposal = result
+      .map_err(|_| "Error checking proposal status".to_string())
+      .and_then(|value| validators_dao.dao_kind.adapter().parse_proposal(value))
+      .unwrap_or_else(|e| panic!("{}", e));
+    assert_eq!(proposal.id, proposal_id.0);
+    assert_eq!(proposal.proposer, env::current_account_id());
+    proposal
+  }
+
   pub(crate) fn internal_create_dispute(
     &self,
     id: BountyIndex,
@@ -1328,27 +2287,39 @@ impl BountiesContract {
     }
   }
 
-  pub(crate) fn get_configuration_dictionary(
-    &mut self,
-    dict: ReferenceType,
+  pub(crate) fn get_configuration_dictionary(&mut self, dict: ReferenceType) -> &mut Vec<String> {
+    let config = self.config.to_config_mut();
+    match dict {
+      ReferenceType::Categories => config.categories.as_mut(),
+      ReferenceType::Tags => config.tags.as_mut(),
+      _ => config.currencies.as_mut(),
+    }
+  }
+
+  pub(crate) fn get_configuration_dictionary_entries(
+    dict: &ReferenceType,
     entry: Option<String>,
     entries: Option<Vec<String>>
-  ) -> (&mut Vec<String>, Vec<String>) {
-    let config = self.config.to_config_mut();
-    let (name_entry, name_entries, reference) = match dict {
-      ReferenceType::Categories => { ("category", "categories", config.categories.as_mut()) },
-      ReferenceType::Tags => { ("tag", "tags", config.tags.as_mut()) },
-      _ => { ("currency", "currencies", config.currencies.as_mut()) },
+  ) -> Vec<String> {
+    let (name_entry, name_entries) = match dict {
+      ReferenceType::Categories => ("category", "categories"),
+      ReferenceType::Tags => ("tag", "tags"),
+      _ => ("currency", "currencies"),
     };
 
-    let entries = if let Some(entries) = entries {
+    if let Some(entries) = entries {
       entries
     } else {
       assert!(entry.is_some(), "Expected either {} or {}", name_entry, name_entries);
       vec![entry.unwrap()]
-    };
+    }
+  }
 
-    (reference, entries)
+  pub(crate) fn internal_get_bounty_claim(&self, id: ClaimIndex) -> BountyClaim {
+    self.claims
+      .get(&id)
+      .unwrap_or_else(|| panic!("No claim found with ID {}", id))
+      .into()
   }
 
   pub(crate) fn internal_get_one_page_of_claims(
@@ -1358,7 +2329,7 @@ impl BountiesContract {
     limit: usize
   ) -> Vec<(ClaimIndex, BountyClaim)> {
     (from_index..std::cmp::min(from_index + limit, claims.len()))
-      .map(|c| (claims[c], self.get_bounty_claim(claims[c])))
+      .map(|c| (claims[c], self.internal_get_bounty_claim(claims[c])))
       .collect()
   }
 
@@ -1367,7 +2338,7 @@ impl BountiesContract {
       .get(&id)
       .unwrap_or_default()
       .into_iter()
-      .map(|c| (c, self.get_bounty_claim(c)))
+      .map(|c| (c, self.internal_get_bounty_claim(c)))
       .collect()
   }
 
@@ -1567,6 +2538,7 @@ impl BountiesContract {
     claim: &mut BountyClaim,
     is_kyc_delayed: Option<DefermentOfKYC>,
   ) {
+    let old_keys = bounty.get_index_keys();
     let approved_status = if bounty.bounty_flow == BountyFlow::SimpleBounty {
       ClaimStatus::Completed
     } else {
@@ -1700,7 +2672,7 @@ impl BountiesContract {
       }
     }
 
-    self.internal_update_bounty(&id, bounty.clone());
+    self.internal_update_bounty(&id, bounty.clone(), &old_keys);
     if claim.status == approved_status &&
       (bounty.status == BountyStatus::ManyClaimed || bounty.status == BountyStatus::Claimed)
     {
@@ -1831,6 +2803,7 @@ impl BountiesContract {
     claim_number: Option<u8>,
     deadline: Option<U64>,
     description: String,
+    team: Option<Vec<TeamMember>>,
     proposal_id: Option<U64>,
     slot: Option<usize>,
     bounty_and_claim: Option<(Bounty, Option<(ClaimIndex, BountyClaim)>)>,
@@ -1846,6 +2819,7 @@ impl BountiesContract {
       )
     };
 
+    let initial_storage = env::storage_usage();
     let created_at = U64::from(env::block_timestamp());
     let bond = self.config.clone().to_config().bounty_claim_bond;
     let mut bounty_claim = BountyClaim {
@@ -1869,6 +2843,12 @@ impl BountiesContract {
       slot,
       bond: Some(bond),
       claim_number,
+      team,
+      paid_team_members: None,
+      handover_to: None,
+      handover_accepted: false,
+      payout_target: None,
+      reviewer_votes: None,
     };
 
     if !self.is_approval_required(&bounty, &receiver_id) {
@@ -1882,6 +2862,7 @@ impl BountiesContract {
       self.claims.insert(&claim_id, &bounty_claim.into());
     }
     self.locked_amount += bond.0;
+    self.internal_record_storage_usage(&receiver_id, initial_storage);
 
     self.internal_update_statistic(
       Some(receiver_id.clone()),
@@ -1898,14 +2879,26 @@ impl BountiesContract {
     receiver_id: AccountId,
     slot: Option<usize>,
   ) -> (Bounty, Option<(ClaimIndex, BountyClaim)>, Option<u8>) {
-    let bounty = self.get_bounty(id.clone());
+    self.try_check_if_allowed_to_create_claim_by_status(id, receiver_id, slot)
+      .unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  pub(crate) fn try_check_if_allowed_to_create_claim_by_status(
+    &self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    slot: Option<usize>,
+  ) -> Result<(Bounty, Option<ClaimEntry>, Option<u8>), String> {
+    let bounty = self.internal_get_bounty(id.clone());
     let bounty_statuses: Vec<BountyStatus>;
     let claim_statuses: Vec<ClaimStatus>;
     let claim_message: &str;
     let mut claim_number: Option<u8> = None;
 
     if bounty.multitasking.is_none() {
-      assert!(slot.is_none(), "The slot parameter is not used for this mode");
+      if slot.is_some() {
+        return Err("The slot parameter is not used for this mode".to_string());
+      }
 
       bounty_statuses = vec![BountyStatus::New];
       claim_statuses = vec![ClaimStatus::New];
@@ -1913,9 +2906,11 @@ impl BountiesContract {
 
     } else {
       if bounty.multitasking.clone().unwrap().is_different_tasks_mode() {
-        assert!(slot.is_some(), "The slot parameter is required for this mode");
-      } else {
-        assert!(slot.is_none(), "The slot parameter is not used for this mode");
+        if slot.is_none() {
+          return Err("The slot parameter is required for this mode".to_string());
+        }
+      } else if slot.is_some() {
+        return Err("The slot parameter is not used for this mode".to_string());
       }
 
       match bounty.multitasking.clone().unwrap() {
@@ -1948,10 +2943,9 @@ impl BountiesContract {
         },
 
         Multitasking::DifferentTasks { subtasks, .. } => {
-          assert!(
-            slot.unwrap() < subtasks.len(),
-            "The slot parameter cannot exceed the number of bounty tasks"
-          );
+          if slot.unwrap() >= subtasks.len() {
+            return Err("The slot parameter cannot exceed the number of bounty tasks".to_string());
+          }
 
           bounty_statuses = vec![BountyStatus::New, BountyStatus::ManyClaimed];
           claim_statuses = vec![
@@ -1969,7 +2963,7 @@ impl BountiesContract {
       }
     }
 
-    let (_, claim, claims) = self.internal_get_and_check_bounty_and_claim(
+    let (_, claim, claims) = self.try_get_and_check_bounty_and_claim(
       id.clone(),
       receiver_id.clone(),
       None,
@@ -1978,21 +2972,22 @@ impl BountiesContract {
       true,
       "Bounty status does not allow to submit a claim",
       claim_message
-    );
+    )?;
 
     if bounty.multitasking.is_some() {
-      assert!(
-        bounty.multitasking.clone().unwrap().is_allowed_to_create_or_approve_claims(slot),
-        "It is no longer possible to create new claims"
-      );
+      if !bounty.multitasking.clone().unwrap().is_allowed_to_create_or_approve_claims(slot) {
+        return Err("It is no longer possible to create new claims".to_string());
+      }
       if bounty.allow_creating_many_claims {
         let claims_amount = claims.len();
-        assert!(claims_amount < 255, "One account can create no more than 255 claims");
+        if claims_amount >= 255 {
+          return Err("One account can create no more than 255 claims".to_string());
+        }
         claim_number = Some(claims_amount as u8);
       }
     }
 
-    (bounty, claim, claim_number)
+    Ok((bounty, claim, claim_number))
   }
 
   pub(crate) fn check_if_allowed_to_approve_claim_by_status(
@@ -2001,14 +2996,20 @@ impl BountiesContract {
     receiver_id: AccountId,
     claim_number: Option<u8>,
   ) -> (Bounty, ClaimIndex, BountyClaim) {
-    let bounty = self.get_bounty(id.clone());
+    self.try_check_if_allowed_to_approve_claim_by_status(id, receiver_id, claim_number)
+      .unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  pub(crate) fn try_check_if_allowed_to_approve_claim_by_status(
+    &self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim), String> {
+    let bounty = self.internal_get_bounty(id.clone());
     let bounty_statuses: Vec<BountyStatus>;
 
-    assert_eq!(
-      claim_number.is_some(),
-      bounty.allow_creating_many_claims,
-      "Invalid claim_number value"
-    );
+    bounty.try_claim_number(claim_number)?;
 
     if bounty.multitasking.is_none() {
       bounty_statuses = vec![BountyStatus::New];
@@ -2031,7 +3032,7 @@ impl BountiesContract {
       }
     }
 
-    let (_, claim, _) = self.internal_get_and_check_bounty_and_claim(
+    let (_, claim, _) = self.try_get_and_check_bounty_and_claim(
       id.clone(),
       receiver_id.clone(),
       claim_number,
@@ -2040,20 +3041,587 @@ impl BountiesContract {
       false,
       "Bounty status does not allow to make a decision on a claim",
       "Claim status does not allow a decision to be made"
-    );
+    )?;
     let (claim_id, bounty_claim) = claim.unwrap();
 
-    if bounty.multitasking.is_some() {
-      assert!(
-        bounty.multitasking
-          .clone()
-          .unwrap()
-          .is_allowed_to_create_or_approve_claims(bounty_claim.slot.clone()),
-        "It is no longer possible to create new claims"
-      );
+    if bounty.multitasking.is_some() &&
+      !bounty.multitasking
+        .clone()
+        .unwrap()
+        .is_allowed_to_create_or_approve_claims(bounty_claim.slot.clone())
+    {
+      return Err("It is no longer possible to create new claims".to_string());
+    }
+
+    Ok((bounty, claim_id, bounty_claim))
+  }
+
+  /// The conditions of the bounty_claim method that do not depend on the claim arguments.
+  pub(crate) fn try_check_if_allowed_to_create_claim(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    slot: Option<usize>,
+  ) -> Result<(Bounty, Option<ClaimEntry>, Option<u8>), String> {
+    let (bounty, claim, claim_number) =
+      self.try_check_if_allowed_to_create_claim_by_status(id, account_id.clone(), slot)?;
+    bounty.try_not_frozen()?;
+    bounty.try_account_is_not_owner_or_reviewer(account_id)?;
+    if bounty.claimant_approval.not_allowed_to_create_claim(account_id) {
+      return Err(format!("{} is not whitelisted", account_id));
+    }
+    Ok((bounty, claim, claim_number))
+  }
+
+  /// Returns the team claim waiting for the team members if the account is one of them.
+  pub(crate) fn try_get_pending_team_claim(
+    &self,
+    id: BountyIndex,
+    claimant_id: &AccountId,
+    account_id: &AccountId,
+  ) -> Result<PendingTeamClaim, String> {
+    let team_claim = self.pending_team_claims
+      .get(&(id, claimant_id.clone()))
+      .ok_or_else(|| "No team claim is waiting for the team members".to_string())?;
+    if !team_claim.team.iter().any(|member| &member.account_id == account_id) {
+      return Err("The account is not a member of the team".to_string());
+    }
+    Ok(team_claim)
+  }
+
+  /// The conditions of the accept_claimant and decline_claimant methods.
+  pub(crate) fn try_check_if_allowed_to_decide_on_claimant(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim), String> {
+    let (bounty, claim_id, bounty_claim) =
+      self.try_check_if_allowed_to_approve_claim_by_status(id, receiver_id, claim_number)?;
+    bounty.try_not_frozen()?;
+    bounty.try_access_rights(account_id)?;
+    if !matches!(bounty.bounty_flow, BountyFlow::AdvancedFlow) {
+      return Err("This operation is not supported for simple bounty flow".to_string());
+    }
+    Ok((bounty, claim_id, bounty_claim))
+  }
+
+  /// The conditions of the bounty_done method.
+  pub(crate) fn try_check_if_allowed_to_complete_claim(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim), String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    if !matches!(bounty.bounty_flow, BountyFlow::AdvancedFlow) {
+      return Err("This operation is not supported for simple bounty flow".to_string());
+    }
+    if !(bounty.status == BountyStatus::Claimed ||
+      bounty.status == BountyStatus::ManyClaimed ||
+      bounty.status == BountyStatus::Completed && bounty.is_contest_or_hackathon())
+    {
+      return Err("Bounty status does not allow to completion".to_string());
+    }
+    bounty.try_claim_number(claim_number)?;
+
+    let (claim_id, bounty_claim) = self.try_get_claim(id, account_id.clone(), claim_number)?;
+    if bounty_claim.status != ClaimStatus::InProgress && bounty_claim.status != ClaimStatus::Competes {
+      return Err("The claim status does not allow to complete the bounty".to_string());
+    }
+    Ok((bounty, claim_id, bounty_claim))
+  }
+
+  /// The conditions of the bounty_give_up method.
+  pub(crate) fn try_check_if_allowed_to_give_up(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim), String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    bounty.try_claim_number(claim_number)?;
+
+    let (claim_id, bounty_claim) = self.try_get_claim(id, account_id.clone(), claim_number)?;
+    if !(bounty_claim.status == ClaimStatus::New ||
+      bounty_claim.status == ClaimStatus::InProgress ||
+      bounty_claim.status == ClaimStatus::Competes ||
+      bounty_claim.status == ClaimStatus::ReadyToStart ||
+      bounty_claim.status == ClaimStatus::Completed &&
+        bounty.is_contest_or_hackathon() &&
+        (bounty.status == BountyStatus::New || bounty.status == BountyStatus::Canceled))
+    {
+      return Err("The claim status does not allow to give up the bounty".to_string());
+    }
+    Ok((bounty, claim_id, bounty_claim))
+  }
+
+  /// The conditions of the propose_claim_handover method.
+  pub(crate) fn try_check_if_allowed_to_propose_handover(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim), String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    bounty.try_claim_number(claim_number)?;
+
+    let (claim_id, bounty_claim) = self.try_get_claim(id, account_id.clone(), claim_number)?;
+    if bounty_claim.status != ClaimStatus::InProgress {
+      return Err("The claim status does not allow to hand over the claim".to_string());
+    }
+    if bounty_claim.team.is_some() {
+      return Err("A team claim cannot be handed over".to_string());
+    }
+    Ok((bounty, claim_id, bounty_claim))
+  }
+
+  /// The conditions of the accept_claim_handover and decline_claim_handover methods.
+  pub(crate) fn try_check_if_allowed_to_decide_on_handover(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim, AccountId), String> {
+    let (bounty, claim_id, bounty_claim, successor_id) =
+      self.try_get_claim_handover(id, receiver_id.clone(), claim_number)?;
+    bounty.try_not_frozen()?;
+    bounty.try_access_rights(account_id)?;
+    Ok((bounty, claim_id, bounty_claim, successor_id))
+  }
+
+  /// The conditions of the accept_claim_handover method.
+  pub(crate) fn try_check_if_allowed_to_accept_handover(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim, AccountId), String> {
+    let result = self.try_check_if_allowed_to_decide_on_handover(id, account_id, receiver_id, claim_number)?;
+    if result.2.handover_accepted {
+      return Err("The claim handover has already been accepted".to_string());
+    }
+    Ok(result)
+  }
+
+  /// The conditions of the take_over_claim method.
+  /// Returns the claim number that the successor will get as the last element.
+  pub(crate) fn try_check_if_allowed_to_take_over_claim(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim, Option<u8>), String> {
+    let (bounty, claim_id, bounty_claim, successor_id) =
+      self.try_get_claim_handover(id, receiver_id, claim_number)?;
+    bounty.try_not_frozen()?;
+    if &successor_id != account_id {
+      return Err("Only the proposed successor can take over the claim".to_string());
+    }
+    if !bounty_claim.handover_accepted {
+      return Err("The claim handover has not been accepted yet".to_string());
+    }
+    let successor_claim_number = self.try_check_claim_successor(id, &bounty, &successor_id)?;
+    Ok((bounty, claim_id, bounty_claim, successor_claim_number))
+  }
+
+  /// Returns the claim with the proposed handover and the successor.
+  pub(crate) fn try_get_claim_handover(
+    &self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim, AccountId), String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_claim_number(claim_number)?;
+
+    let (claim_id, bounty_claim) = self.try_get_claim(id, receiver_id, claim_number)?;
+    if bounty_claim.status != ClaimStatus::InProgress {
+      return Err("The claim status does not allow to hand over the claim".to_string());
+    }
+    let successor_id = bounty_claim.handover_to.clone()
+      .ok_or_else(|| "No claim handover has been proposed".to_string())?;
+    Ok((bounty, claim_id, bounty_claim, successor_id))
+  }
+
+  /// Checks that the account can take over a claim of the bounty.
+  /// Returns the claim number that the successor will get.
+  pub(crate) fn try_check_claim_successor(
+    &self,
+    id: BountyIndex,
+    bounty: &Bounty,
+    successor_id: &AccountId,
+  ) -> Result<Option<u8>, String> {
+    if bounty.try_account_is_not_owner_or_reviewer(successor_id).is_err() {
+      return Err("The successor cannot be a bounty owner or reviewer".to_string());
+    }
+    if bounty.claimant_approval.not_allowed_to_create_claim(successor_id) {
+      return Err(format!("{} is not whitelisted", successor_id));
+    }
+
+    let claims = self.internal_get_claims_by_account_id_and_bounty_id(&id, successor_id, true);
+    if !bounty.allow_creating_many_claims {
+      if !claims.is_empty() {
+        return Err("The successor already has a claim for this bounty".to_string());
+      }
+      return Ok(None);
+    }
+    if claims.iter().any(|claim_id| !Self::is_claim_closed(&self.internal_get_bounty_claim(*claim_id))) {
+      return Err("The successor already has an active claim for this bounty".to_string());
+    }
+    if claims.len() >= 255 {
+      return Err("One account can create no more than 255 claims".to_string());
+    }
+    Ok(Some(claims.len() as u8))
+  }
+
+  /// The conditions of the set_claim_payout_target method.
+  pub(crate) fn try_check_if_allowed_to_set_payout_target(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    claim_number: Option<u8>,
+    payout_target: &Option<PayoutTarget>,
+  ) -> Result<(ClaimIndex, BountyClaim), String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    bounty.try_claim_number(claim_number)?;
+    if bounty.is_payment_outside_contract() {
+      return Err("The reward for this bounty is paid outside the contract".to_string());
+    }
+
+    let (claim_id, bounty_claim) = self.try_get_claim(id, account_id.clone(), claim_number)?;
+    let is_before_approval = matches!(
+      bounty_claim.status,
+      ClaimStatus::New | ClaimStatus::ReadyToStart | ClaimStatus::InProgress |
+        ClaimStatus::Competes | ClaimStatus::Completed
+    );
+    if !is_before_approval || bounty_claim.bounty_payout_proposal_id.is_some() {
+      return Err("The payout target can no longer be changed".to_string());
+    }
+    if bounty_claim.team.is_some() {
+      return Err("A payout target cannot be set for a team claim".to_string());
+    }
+    if payout_target
+      .as_ref()
+      .map_or(false, |target| target.receiver_id == env::current_account_id())
+    {
+      return Err("The contract cannot be a payout receiver".to_string());
+    }
+    Ok((claim_id, bounty_claim))
+  }
+
+  /// The conditions of the bounty_cancel method.
+  pub(crate) fn try_check_if_allowed_to_cancel(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+  ) -> Result<Bounty, String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    if bounty.status != BountyStatus::New && bounty.status != BountyStatus::AwaitingClaims {
+      return Err("Bounty status does not allow cancellation".to_string());
+    }
+    if &bounty.owner != account_id {
+      return Err("Only the owner of the bounty can call this method".to_string());
+    }
+    Ok(bounty)
+  }
+
+  /// The conditions of the bounty_approve and bounty_reject methods.
+  pub(crate) fn try_check_if_allowed_to_make_verdict(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, ClaimIndex, BountyClaim), String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    if bounty.status != BountyStatus::Claimed && bounty.status != BountyStatus::ManyClaimed {
+      return Err("Bounty status does not allow approval of the execution result".to_string());
+    }
+    bounty.try_access_rights(account_id)?;
+    bounty.try_claim_number(claim_number)?;
+
+    let (claim_id, bounty_claim) = self.try_get_claim(id, receiver_id, claim_number)?;
+    if !matches!(bounty_claim.status, ClaimStatus::Completed) {
+      return Err("The claim status does not allow approval of the execution result".to_string());
+    }
+    Ok((bounty, claim_id, bounty_claim))
+  }
+
+  /// The bounty status condition of the bounty_finalize method.
+  pub(crate) fn try_check_if_allowed_to_finalize(bounty: &Bounty) -> Result<(), String> {
+    if bounty.status != BountyStatus::Claimed && bounty.status != BountyStatus::ManyClaimed {
+      return Err("Bounty status does not allow approval of the execution result".to_string());
+    }
+    Ok(())
+  }
+
+  /// The conditions of the open_dispute method.
+  pub(crate) fn try_check_if_allowed_to_open_dispute(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, BountyClaim), String> {
+    if self.dispute_contract.is_none() {
+      return Err("Opening a dispute is not supported by this contract".to_string());
+    }
+
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    if bounty.status != BountyStatus::Claimed && bounty.status != BountyStatus::ManyClaimed {
+      return Err("Bounty status does not allow opening a dispute".to_string());
+    }
+    bounty.try_claim_number(claim_number)?;
+
+    let (_, bounty_claim) = self.try_get_claim(id, account_id.clone(), claim_number)?;
+    if !matches!(bounty_claim.status, ClaimStatus::Rejected) {
+      return Err("The claim status does not allow opening a dispute".to_string());
+    }
+    if self.is_deadline_for_opening_dispute_expired(&bounty_claim) {
+      return Err("The period for opening a dispute has expired".to_string());
+    }
+    Ok((bounty, bounty_claim))
+  }
+
+  /// The conditions of the withdraw method. Returns the slot of the claim.
+  pub(crate) fn try_check_if_allowed_to_withdraw(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+    claim_number: Option<u8>,
+  ) -> Result<(Bounty, usize), String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    if !bounty.is_different_tasks() {
+      return Err("This action is only available for the DifferentTasks mode".to_string());
+    }
+    if !matches!(bounty.status, BountyStatus::Completed) {
+      return Err("Bounty status does not allow this action".to_string());
+    }
+    bounty.try_claim_number(claim_number)?;
+
+    let (_, bounty_claim) = self.try_get_claim(id, account_id.clone(), claim_number)?;
+    if bounty_claim.status != ClaimStatus::Completed &&
+      bounty_claim.status != ClaimStatus::CompletedWithDispute
+    {
+      return Err("The claim status does not allow this action".to_string());
+    }
+
+    let slot = bounty_claim.slot.clone().unwrap();
+    let slot_env = bounty.multitasking.clone().unwrap().get_slot_env(slot);
+    match slot_env {
+      None => Err("The slot is not occupied".to_string()),
+      Some(slot_env) if &slot_env.participant != account_id =>
+        Err("This slot belongs to another account".to_string()),
+      _ => Ok((bounty, slot)),
+    }
+  }
+
+  /// The conditions of the bounty_update method that do not depend on the changes.
+  pub(crate) fn try_check_if_allowed_to_update(
+    &self,
+    id: BountyIndex,
+    account_id: &AccountId,
+  ) -> Result<Bounty, String> {
+    let bounty = self.internal_get_bounty(id);
+    bounty.try_not_frozen()?;
+    if !(bounty.status == BountyStatus::New ||
+      bounty.status == BountyStatus::Claimed ||
+      bounty.status == BountyStatus::ManyClaimed ||
+      bounty.status == BountyStatus::AwaitingClaims)
+    {
+      return Err("Bounty status does not allow updating".to_string());
+    }
+    if &bounty.owner != account_id {
+      return Err("Only the owner of the bounty can call this method".to_string());
     }
+    Ok(bounty)
+  }
+
+  /// Returns Ok if any of the checks is passed, otherwise the first error.
+  fn internal_any_check_passed(
+    checks: impl Iterator<Item = Result<(), String>>,
+    default_error: &str,
+  ) -> Result<(), String> {
+    let mut error = None;
+    for check in checks {
+      match check {
+        Ok(()) => return Ok(()),
+        Err(e) => { error.get_or_insert(e); },
+      }
+    }
+    Err(error.unwrap_or(default_error.to_string()))
+  }
+
+  /// Checks the bounty methods for the account using the same conditions as the methods themselves.
+  pub(crate) fn internal_get_available_actions(
+    &self,
+    id: BountyIndex,
+    account_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Vec<AvailableAction> {
+    let bounty = self.internal_get_bounty(id);
+    let claims = self.internal_get_claims_by_bounty_id(id);
+    let claims_with_status = |status: ClaimStatus| {
+      claims
+        .clone()
+        .into_iter()
+        .filter(move |(_, c)| c.status == status)
+        .map(|(_, c)| (c.owner, c.claim_number))
+    };
+    let slots: Vec<Option<usize>> = match bounty.multitasking.clone() {
+      Some(Multitasking::DifferentTasks { subtasks, .. }) => (0..subtasks.len()).map(Some).collect(),
+      _ => vec![None],
+    };
+    let no_claims = "There are no claims awaiting this action";
+
+    let checks: Vec<(&str, Option<PauseCategory>, Result<(), String>)> = vec![
+      (
+        "bounty_claim",
+        Some(PauseCategory::ClaimCreation),
+        Self::internal_any_check_passed(
+          slots
+            .into_iter()
+            .map(|slot| self.try_check_if_allowed_to_create_claim(id, &account_id, slot).map(|_| ())),
+          "It is no longer possible to create new claims"
+        ),
+      ),
+      (
+        "accept_claimant",
+        None,
+        Self::internal_any_check_passed(
+          claims_with_status(ClaimStatus::New).map(|(receiver_id, number)| {
+            self.try_check_if_allowed_to_decide_on_claimant(id, &account_id, receiver_id, number)
+              .map(|_| ())
+          }),
+          no_claims
+        ),
+      ),
+      (
+        "decline_claimant",
+        None,
+        Self::internal_any_check_passed(
+          claims_with_status(ClaimStatus::New).map(|(receiver_id, number)| {
+            self.try_check_if_allowed_to_decide_on_claimant(id, &account_id, receiver_id, number)
+              .map(|_| ())
+          }),
+          no_claims
+        ),
+      ),
+      (
+        "bounty_done",
+        None,
+        self.try_check_if_allowed_to_complete_claim(id, &account_id, claim_number).map(|_| ()),
+      ),
+      (
+        "bounty_give_up",
+        None,
+        self.try_check_if_allowed_to_give_up(id, &account_id, claim_number).map(|_| ()),
+      ),
+      (
+        "propose_claim_handover",
+        None,
+        self.try_check_if_allowed_to_propose_handover(id, &account_id, claim_number).map(|_| ()),
+      ),
+      (
+        "set_claim_payout_target",
+        None,
+        self.try_check_if_allowed_to_set_payout_target(id, &account_id, claim_number, &None).map(|_| ()),
+      ),
+      (
+        "accept_claim_handover",
+        None,
+        Self::internal_any_check_passed(
+          claims_with_status(ClaimStatus::InProgress).map(|(receiver_id, number)| {
+            self.try_check_if_allowed_to_accept_handover(id, &account_id, receiver_id, number)
+              .map(|_| ())
+          }),
+          no_claims
+        ),
+      ),
+      (
+        "take_over_claim",
+        Some(PauseCategory::ClaimCreation),
+        Self::internal_any_check_passed(
+          claims_with_status(ClaimStatus::InProgress).map(|(receiver_id, number)| {
+            self.try_check_if_allowed_to_take_over_claim(id, &account_id, receiver_id, number)
+              .map(|_| ())
+          }),
+          no_claims
+        ),
+      ),
+      (
+        "bounty_approve",
+        Some(PauseCategory::Payouts),
+        Self::internal_any_check_passed(
+          claims_with_status(ClaimStatus::Completed).map(|(receiver_id, number)| {
+            self.try_check_if_allowed_to_make_verdict(id, &account_id, receiver_id, number)
+              .map(|_| ())
+          }),
+          no_claims
+        ),
+      ),
+      (
+        "bounty_reject",
+        None,
+        Self::internal_any_check_passed(
+          claims_with_status(ClaimStatus::Completed).map(|(receiver_id, number)| {
+            self.try_check_if_allowed_to_make_verdict(id, &account_id, receiver_id, number)
+              .map(|_| ())
+          }),
+          no_claims
+        ),
+      ),
+      (
+        "bounty_finalize",
+        None,
+        bounty.try_not_frozen().and_then(|_| Self::try_check_if_allowed_to_finalize(&bounty)),
+      ),
+      (
+        "open_dispute",
+        Some(PauseCategory::Disputes),
+        self.try_check_if_allowed_to_open_dispute(id, &account_id, claim_number).map(|_| ()),
+      ),
+      (
+        "bounty_cancel",
+        Some(PauseCategory::Payouts),
+        self.try_check_if_allowed_to_cancel(id, &account_id).map(|_| ()),
+      ),
+      (
+        "withdraw",
+        Some(PauseCategory::Payouts),
+        self.try_check_if_allowed_to_withdraw(id, &account_id, claim_number).map(|_| ()),
+      ),
+      (
+        "bounty_update",
+        None,
+        self.try_check_if_allowed_to_update(id, &account_id).map(|_| ()),
+      ),
+    ];
 
-    (bounty, claim_id, bounty_claim)
+    checks
+      .into_iter()
+      .map(|(method, pause_category, check)| {
+        let result = self.try_live()
+          .and_then(|_| pause_category.map_or(Ok(()), |c| self.try_not_paused(c)))
+          .and(check);
+        AvailableAction {
+          method: method.to_string(),
+          blocked_reason: result.err(),
+        }
+      })
+      .collect()
   }
 
   pub(crate) fn check_if_claimant_in_kyc_whitelist(
@@ -2064,11 +3632,78 @@ impl BountiesContract {
     place_of_check: PlaceOfCheckKYC,
     slot: Option<usize>,
   ) -> PromiseOrValue<()> {
+    // All members of a team claim must pass KYC
+    let accounts = match place_of_check.clone() {
+      PlaceOfCheckKYC::CreatingClaim { team: Some(team), .. } =>
+        team.into_iter().map(|member| member.account_id).collect(),
+      PlaceOfCheckKYC::CreatingClaim { .. } => vec![receiver_id.clone()],
+      PlaceOfCheckKYC::ClaimHandover { successor_id } => vec![successor_id],
+      _ => self.try_get_claim(id, receiver_id.clone(), claim_number)
+        .map(|(_, claim)| claim.get_members())
+        .unwrap_or_else(|_| vec![receiver_id.clone()]),
+    };
+    accounts
+      .into_iter()
+      .map(|account_id| {
+        Promise::new(self.kyc_whitelist_contract.clone().unwrap())
+          .function_call(
+            "is_whitelisted".to_string(),
+            json!({
+              "account_id": account_id,
+            })
+              .to_string()
+              .into_bytes(),
+            NO_DEPOSIT,
+            GAS_FOR_CHECK_IF_WHITELISTED,
+          )
+      })
+      .reduce(|promise, next| promise.and(next))
+      .unwrap()
+      .then(
+        Self::ext(env::current_account_id())
+          .with_static_gas(GAS_FOR_AFTER_CHECK_IF_WHITELISTED)
+          .after_check_if_whitelisted(id, receiver_id, claim_number, place_of_check, slot)
+      )
+      .into()
+  }
+
+  /// Bounties whose amount exceeds the KYB threshold of the token can only be created
+  /// by owners who have passed KYB.
+  pub(crate) fn internal_is_kyb_required(
+    &self,
+    bounty_create: &BountyCreate,
+    token_id: &AccountId,
+    amount: U128,
+  ) -> bool {
+    let min_amount_for_kyb = self.tokens.get(token_id).and_then(|t| t.min_amount_for_kyb);
+    min_amount_for_kyb.map_or(false, |min| {
+      let dao_details = self.internal_get_validators_dao_details(bounty_create);
+      let bounty = bounty_create.to_bounty(
+        &env::current_account_id(),
+        Some(token_id.clone()),
+        amount,
+        self.config.clone().to_config(),
+        None,
+        dao_details.as_ref()
+      );
+      bounty.amount.0 > min.0
+    })
+  }
+
+  pub(crate) fn check_if_owner_in_kyb_whitelist(
+    &self,
+    bounty_create: BountyCreate,
+    owner_id: AccountId,
+    token_id: AccountId,
+    amount: U128,
+  ) -> Promise {
+    assert!(self.kyc_whitelist_contract.is_some(), "KYC whitelist contract is not set");
     Promise::new(self.kyc_whitelist_contract.clone().unwrap())
       .function_call(
         "is_whitelisted".to_string(),
         json!({
-          "account_id": receiver_id.clone(),
+          "account_id": owner_id,
+          "verification_type": "KYB",
         })
           .to_string()
           .into_bytes(),
@@ -2077,10 +3712,9 @@ impl BountiesContract {
       )
       .then(
         Self::ext(env::current_account_id())
-          .with_static_gas(GAS_FOR_AFTER_CHECK_IF_WHITELISTED)
-          .after_check_if_whitelisted(id, receiver_id, claim_number, place_of_check, slot)
+          .with_static_gas(GAS_FOR_AFTER_CHECK_IF_OWNER_VERIFIED)
+          .after_check_if_owner_verified(bounty_create, owner_id, token_id, amount)
       )
-      .into()
   }
 
   pub(crate) fn internal_add_proposal_and_create_claim(
@@ -2092,11 +3726,11 @@ impl BountiesContract {
     slot: Option<usize>,
     bounty_and_claim: Option<(Bounty, Option<(ClaimIndex, BountyClaim)>)>,
   ) -> PromiseOrValue<()> {
-    let ( deadline, description ) = match place_of_check {
-      PlaceOfCheckKYC::CreatingClaim { deadline, description } => (deadline, description),
+    let ( deadline, description, team ) = match place_of_check {
+      PlaceOfCheckKYC::CreatingClaim { deadline, description, team } => (deadline, description, team),
       _ => unreachable!(),
     };
-    let bounty = self.get_bounty(id.clone());
+    let bounty = self.internal_get_bounty(id.clone());
     if bounty.is_validators_dao_used() &&
       self.is_approval_required(&bounty, &receiver_id)
     {
@@ -2108,6 +3742,7 @@ impl BountiesContract {
         deadline,
         description,
         slot,
+        team,
       )
     } else {
       self.internal_create_claim(
@@ -2116,6 +3751,7 @@ impl BountiesContract {
         claim_number,
         deadline,
         description,
+        team,
         None,
         slot,
         bounty_and_claim
@@ -2175,7 +3811,7 @@ impl BountiesContract {
     claim_number: Option<u8>,
     place_of_check: PlaceOfCheckKYC,
   ) -> PromiseOrValue<()> {
-    let bounty = self.get_bounty(id.clone());
+    let bounty = self.internal_get_bounty(id.clone());
     if bounty.is_validators_dao_used() &&
       !bounty.is_different_tasks() &&
       !bounty.is_contest_or_hackathon()
@@ -2211,7 +3847,7 @@ impl BountiesContract {
     claim_number: Option<u8>,
     proposal_id: Option<U64>
   ) {
-    let (mut bounty, claim, _) = self.internal_get_and_check_bounty_and_claim(
+    let (mut bounty, claim, _) = self.try_get_and_check_bounty_and_claim(
       id.clone(),
       receiver_id.clone(),
       claim_number,
@@ -2220,7 +3856,8 @@ impl BountiesContract {
       false,
       "Bounty status does not allow to completion",
       "The claim status does not allow to complete the bounty"
-    );
+    ).unwrap_or_else(|e| panic!("{}", e));
+    let old_keys = bounty.get_index_keys();
     let (claim_id, mut bounty_claim) = claim.unwrap();
 
     bounty_claim.status = ClaimStatus::Completed;
@@ -2229,7 +3866,7 @@ impl BountiesContract {
       if proposal_id.is_some() {
         self.internal_set_bounty_payout_proposal_id(&mut bounty, proposal_id);
       }
-      self.internal_update_bounty(&id, bounty);
+      self.internal_update_bounty(&id, bounty, &old_keys);
     } else {
       bounty_claim.bounty_payout_proposal_id = proposal_id;
     }
@@ -2244,8 +3881,10 @@ impl BountiesContract {
     amounts: (U128, U128, U128),
     place: Option<usize>,
   ) {
+    let old_keys = bounty.get_index_keys();
     let action_kind;
     let bond: Option<U128>;
+    let mut team_claim: Option<(BountyClaim, bool)> = None;
     let (receiver_id, claim_number) = if claimant.is_some() {
       let claimant = claimant.clone().unwrap();
       (Some(claimant.0), claimant.1)
@@ -2269,7 +3908,7 @@ impl BountiesContract {
 
     } else {
       assert!(claimant.is_some(), "The claimant must be established");
-      let (_, claim, _) = self.internal_get_and_check_bounty_and_claim(
+      let (_, claim, _) = self.try_get_and_check_bounty_and_claim(
         id.clone(),
         receiver_id.clone().unwrap(),
         claim_number,
@@ -2278,13 +3917,14 @@ impl BountiesContract {
         false,
         "Bounty status does not allow to payout",
         "The claim status does not allow to payout"
-      );
+      ).unwrap_or_else(|e| panic!("{}", e));
       let (claim_id, mut bounty_claim) = claim.unwrap();
 
       bond = bounty_claim.bond;
       let with_dispute = bounty_claim.status == ClaimStatus::Disputed;
       bounty_claim.status = ClaimStatus::Approved;
-      self.claims.insert(&claim_id, &bounty_claim.into());
+      self.claims.insert(&claim_id, &bounty_claim.clone().into());
+      team_claim = Some((bounty_claim, with_dispute));
 
       action_kind = if bounty.is_one_bounty_for_many_claimants() {
         ReputationActionKind::SuccessfulClaim { with_dispute }
@@ -2335,18 +3975,21 @@ impl BountiesContract {
       }
     }
 
-    self.internal_update_bounty(&id, bounty.clone());
+    self.internal_update_bounty(&id, bounty.clone(), &old_keys);
     self.internal_update_statistic(
       receiver_id.clone(),
       Some(bounty.owner.clone()),
       action_kind,
     );
+    if let Some((bounty_claim, with_dispute)) = team_claim {
+      self.internal_update_team_statistic(&bounty_claim, with_dispute);
+    }
     if receiver_id.is_some() {
       self.internal_return_bonds(&receiver_id.unwrap(), bond);
     }
   }
 
-  pub(crate) fn internal_get_and_check_bounty_and_claim(
+  pub(crate) fn try_get_and_check_bounty_and_claim(
     &self,
     id: BountyIndex,
     receiver_id: AccountId,
@@ -2356,17 +3999,19 @@ impl BountiesContract {
     no_claim_found: bool,
     bounty_message: &str,
     claim_message: &str,
-  ) -> (Bounty, Option<(ClaimIndex, BountyClaim)>, Vec<ClaimIndex>) {
-    let bounty = self.get_bounty(id.clone());
+  ) -> Result<(Bounty, Option<ClaimEntry>, Vec<ClaimIndex>), String> {
+    let bounty = self.internal_get_bounty(id.clone());
     if !bounty_statuses.contains(&bounty.status) {
-      env::panic_str(bounty_message);
+      return Err(bounty_message.to_string());
     }
 
     let claims = self.internal_get_claims_by_account_id_and_bounty_id(&id, &receiver_id, true);
 
     if !bounty.allow_creating_many_claims || !no_claim_found {
       let claim = self.internal_find_claim(&claims, id, receiver_id, claim_number);
-      assert!(no_claim_found || claim.is_some(), "No bounty claim found");
+      if !no_claim_found && claim.is_none() {
+        return Err("No bounty claim found".to_string());
+      }
 
       let claim_found = claim.is_some() &&
         claim_statuses
@@ -2374,47 +4019,58 @@ impl BountiesContract {
           .find(|s| claim.clone().unwrap().1.status == s.clone())
           .is_some();
       if no_claim_found == claim_found {
-        env::panic_str(claim_message);
+        return Err(claim_message.to_string());
       }
 
-      (bounty, claim, claims)
+      Ok((bounty, claim, claims))
 
     } else {
-      (bounty, None, claims)
+      Ok((bounty, None, claims))
     }
   }
 
-  pub(crate) fn check_bounty(&self, bounty: &Bounty) {
-    bounty.assert_valid();
-    self.assert_bounty_category_is_correct(bounty.metadata.category.clone());
+  pub(crate) fn internal_get_min_amount_for_kyc(&self, token_id: &Option<AccountId>) -> Option<U128> {
+    token_id
+      .as_ref()
+      .and_then(|token_id| self.tokens.get(token_id))
+      .and_then(|token_details| token_details.min_amount_for_kyc)
+  }
+
+  /// The KYC setting of the bounty becomes stricter if the amount has reached the token threshold.
+  pub(crate) fn check_bounty(&self, bounty: &mut Bounty) {
+    bounty.apply_min_amount_for_kyc(self.internal_get_min_amount_for_kyc(&bounty.token));
+    self.try_check_bounty(bounty).unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  pub(crate) fn try_check_bounty(&self, bounty: &Bounty) -> Result<(), String> {
+    bounty.try_valid()?;
+    self.try_bounty_category_is_correct(bounty.metadata.category.clone())?;
     if bounty.metadata.tags.is_some() {
-      self.assert_bounty_tags_are_correct(bounty.metadata.tags.clone().unwrap());
+      self.try_bounty_tags_are_correct(bounty.metadata.tags.clone().unwrap())?;
     }
     if bounty.postpaid.is_some() {
       match bounty.postpaid.clone().unwrap() {
         Postpaid::PaymentOutsideContract { currency, .. } =>
-          self.assert_bounty_currency_is_correct(currency),
+          self.try_bounty_currency_is_correct(currency)?,
         _ => unreachable!()
       }
     }
-    match bounty.kyc_config {
-      KycConfig::KycRequired { .. } => {
-        assert!(
-          self.kyc_whitelist_contract.is_some(),
-          "KYC whitelist contract is not set"
-        );
-      },
-      _ => {}
+    if matches!(bounty.kyc_config, KycConfig::KycRequired { .. }) &&
+      self.kyc_whitelist_contract.is_none()
+    {
+      return Err("KYC whitelist contract is not set".to_string());
+    }
+    if let Some(token_details) = bounty.token.as_ref().and_then(|token_id| self.tokens.get(token_id)) {
+      token_details.try_bounty_amount_within_limits(bounty)?;
     }
     if self.config.clone().to_config().max_due_date.is_some() &&
-      bounty.deadline.get_deadline_type() == 1
+      bounty.deadline.get_deadline_type() == 1 &&
+      bounty.deadline.get_deadline_value().0 >
+        env::block_timestamp() + self.config.clone().to_config().max_due_date.unwrap().0
     {
-      assert!(
-        bounty.deadline.get_deadline_value().0 <=
-          env::block_timestamp() + self.config.clone().to_config().max_due_date.unwrap().0,
-        "Incorrect due date"
-      );
+      return Err("Incorrect due date".to_string());
     }
+    Ok(())
   }
 
   pub(crate) fn internal_finalize_active_claim(
@@ -2534,6 +4190,7 @@ impl BountiesContract {
       receiver_id.clone(),
       claim_number
     );
+    let old_keys = bounty.get_index_keys();
 
     if bounty.multitasking.is_some() {
       let result = self.internal_finalize_active_claim(
@@ -2597,7 +4254,7 @@ impl BountiesContract {
         },
         _ => unreachable!(),
       }
-      self.internal_update_bounty(&id, bounty.clone());
+      self.internal_update_bounty(&id, bounty.clone(), &old_keys);
       let new_status = if bounty.status == BountyStatus::Canceled {
         Some(ClaimStatus::Canceled)
       } else {
@@ -2620,9 +4277,647 @@ impl BountiesContract {
   }
 
   pub(crate) fn assert_live(&self) {
+    self.try_live().unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  pub(crate) fn try_live(&self) -> Result<(), String> {
+    if !matches!(self.status, ContractStatus::Live) {
+      return Err("The contract status is not Live".to_string());
+    }
+    Ok(())
+  }
+
+  /// Applies the change immediately if the timelock delay is not set, otherwise queues it.
+  pub(crate) fn internal_queue_change(&mut self, action: TimelockedAction) {
+    let timelock_delay = self.config.clone().to_config().timelock_delay.0;
+    if timelock_delay == 0 {
+      self.internal_apply_change(action);
+      return;
+    }
+
+    let change_id = self.last_queued_change_id;
+    let queued_at = env::block_timestamp();
+    self.queued_changes.insert(&change_id, &QueuedChange {
+      action,
+      proposer: env::predecessor_account_id(),
+      queued_at: U64(queued_at),
+      executable_at: U64(queued_at + timelock_delay),
+    });
+    self.last_queued_change_id += 1;
+    env::log_str(format!("Change {} is queued", change_id).as_str());
+  }
+
+  pub(crate) fn internal_apply_change(&mut self, action: TimelockedAction) {
+    match action {
+      TimelockedAction::ChangeConfig { config_create } => {
+        let config = config_create.to_config(self.config.clone().to_config());
+        self
+          .try_admin_approval_threshold(config.admin_approval_threshold, self.admins_whitelist.len())
+          .unwrap_or_else(|e| panic!("{}", e));
+        self.config = config.into();
+      },
+      TimelockedAction::UpdateDisputeContract { dispute_contract } => {
+        self.dispute_contract = Some(dispute_contract);
+      },
+      TimelockedAction::UpdateReputationContract { reputation_contract } => {
+        self.reputation_contract = Some(reputation_contract);
+      },
+      TimelockedAction::UpdateKycWhitelistContract { kyc_whitelist_contract } => {
+        self.kyc_whitelist_contract = kyc_whitelist_contract;
+      },
+      TimelockedAction::ChangeRecipientOfPlatformFee { recipient_of_platform_fee } => {
+        self.recipient_of_platform_fee = Some(recipient_of_platform_fee);
+      },
+      TimelockedAction::ChangePlatformFeeRecipients { recipients } => {
+        self.platform_fee_recipients = recipients;
+      },
+    }
+  }
+
+  /// If the threshold exceeds the number of admins, no admin action can be confirmed anymore.
+  pub(crate) fn try_admin_approval_threshold(
+    &self,
+    threshold: u16,
+    admins_count: u64,
+  ) -> Result<(), String> {
+    if threshold as u64 > admins_count {
+      return Err("The admin approval threshold cannot exceed the number of admins".to_string());
+    }
+    Ok(())
+  }
+
+  pub(crate) fn has_admin_permission(&self, role: Option<Role>, account_id: &AccountId) -> bool {
+    match role {
+      Some(role) => self.has_role(role, account_id),
+      None => self.admins_whitelist.contains(account_id),
+    }
+  }
+
+  /// Executes the action immediately if the approval threshold does not exceed one,
+  /// otherwise saves it until enough admins confirm it.
+  pub(crate) fn internal_propose_admin_action(&mut self, action: AdminAction) -> PromiseOrValue<()> {
+    let threshold = self.config.clone().to_config().admin_approval_threshold;
+    if threshold <= 1 {
+      return self.internal_execute_admin_action(action);
+    }
+
+    let proposer = env::predecessor_account_id();
+    let confirmations = if self.admins_whitelist.contains(&proposer) {
+      vec![proposer.clone()]
+    } else {
+      vec![]
+    };
+    let action_id = self.last_admin_action_id;
+    self.pending_admin_actions.insert(&action_id, &PendingAdminAction {
+      action,
+      proposer,
+      proposed_at: U64(env::block_timestamp()),
+      confirmations,
+    });
+    self.last_admin_action_id += 1;
+    env::log_str(format!("Admin action {} is waiting for confirmations", action_id).as_str());
+    PromiseOrValue::Value(())
+  }
+
+  pub(crate) fn internal_execute_admin_action(&mut self, action: AdminAction) -> PromiseOrValue<()> {
+    match action {
+      AdminAction::AddToWhitelist { account_ids, whitelist_type } => {
+        for account_id in &account_ids {
+          match whitelist_type {
+            WhitelistType::AdministratorsWhitelist => { self.admins_whitelist.insert(account_id); },
+            WhitelistType::OwnersWhitelist => { self.owners_whitelist.insert(account_id); },
+            WhitelistType::PostpaidSubscribersWhitelist => {
+              self.postpaid_subscribers_whitelist.insert(account_id);
+            },
+          }
+        }
+        PromiseOrValue::Value(())
+      },
+      AdminAction::RemoveFromWhitelist { account_ids, whitelist_type } => {
+        for account_id in &account_ids {
+          match whitelist_type {
+            WhitelistType::AdministratorsWhitelist => { self.admins_whitelist.remove(account_id); },
+            WhitelistType::OwnersWhitelist => { self.owners_whitelist.remove(account_id); },
+            WhitelistType::PostpaidSubscribersWhitelist => {
+              self.postpaid_subscribers_whitelist.remove(account_id);
+            },
+          }
+        }
+        if matches!(whitelist_type, WhitelistType::AdministratorsWhitelist) {
+          assert!(
+            !self.admins_whitelist.is_empty(),
+            "Cannot remove all accounts from admin whitelist",
+          );
+          let threshold = self.config.clone().to_config().admin_approval_threshold;
+          self
+            .try_admin_approval_threshold(threshold, self.admins_whitelist.len())
+            .unwrap_or_else(|e| panic!("{}", e));
+        }
+        PromiseOrValue::Value(())
+      },
+      AdminAction::GrantRole { role, account_id } => {
+        let mut holders = self.roles.get(&role).unwrap_or_default();
+        assert!(!holders.contains(&account_id), "The account already has this role");
+        holders.push(account_id);
+        self.roles.insert(&role, &holders);
+        PromiseOrValue::Value(())
+      },
+      AdminAction::RevokeRole { role, account_id } => {
+        let mut holders = self.roles.get(&role).unwrap_or_default();
+        let index = holders
+          .iter()
+          .position(|a| a == &account_id)
+          .expect("The account does not have this role");
+        holders.remove(index);
+        self.roles.insert(&role, &holders);
+        PromiseOrValue::Value(())
+      },
+      AdminAction::AddToken { token_id, min_amount_for_kyc } => {
+        assert!(
+          self.tokens.get(&token_id).is_none(),
+          "The token already exists"
+        );
+        self.internal_get_ft_metadata(token_id, min_amount_for_kyc)
+      },
+      AdminAction::UpdateToken { token_id, token_details } => {
+        let current_details = self.tokens.get(&token_id).expect("No token found");
+        // The metadata is only taken from the token contract
+        self.tokens.insert(&token_id, &TokenDetails {
+          symbol: current_details.symbol,
+          decimals: current_details.decimals,
+          ..token_details
+        });
+        PromiseOrValue::Value(())
+      },
+      AdminAction::AddValidatorsDao { dao_account_id, dao_details } => {
+        assert!(
+          self.validators_daos.get(&dao_account_id).is_none(),
+          "The validators DAO is already registered"
+        );
+        self.validators_daos.insert(&dao_account_id, &dao_details);
+        PromiseOrValue::Value(())
+      },
+      AdminAction::UpdateValidatorsDao { dao_account_id, dao_details } => {
+        assert!(
+          self.validators_daos.get(&dao_account_id).is_some(),
+          "The validators DAO is not registered"
+        );
+        self.validators_daos.insert(&dao_account_id, &dao_details);
+        PromiseOrValue::Value(())
+      },
+      AdminAction::RemoveValidatorsDao { dao_account_id } => {
+        self.validators_daos.remove(&dao_account_id);
+        PromiseOrValue::Value(())
+      },
+      AdminAction::ChangeConfig { config_create } => {
+        self.internal_queue_change(TimelockedAction::ChangeConfig { config_create });
+        PromiseOrValue::Value(())
+      },
+      AdminAction::ChangePlatformFeeRecipients { recipients } => {
+        self.internal_queue_change(TimelockedAction::ChangePlatformFeeRecipients { recipients });
+        PromiseOrValue::Value(())
+      },
+      AdminAction::UpdateConfigurationDictionaryEntries { dict, entries } => {
+        let reference = self.get_configuration_dictionary(dict);
+        for entry in entries {
+          reference.push(entry);
+        }
+        PromiseOrValue::Value(())
+      },
+      AdminAction::RemoveConfigurationDictionaryEntries { dict, entries } => {
+        let reference = self.get_configuration_dictionary(dict);
+        for entry in entries {
+          if let Some(index) = reference.iter().position(|e| e.clone() == entry) {
+            reference.remove(index);
+          }
+        }
+        PromiseOrValue::Value(())
+      },
+      AdminAction::WithdrawPlatformFee { token_id } => {
+        self.assert_not_paused(PauseCategory::Payouts);
+        let receiver_id = self.recipient_of_platform_fee
+          .clone().expect("The recipient of the platform fee is not specified");
+        let balance = self.internal_get_unlocked_platform_fee_amount(token_id.clone());
+        assert!(balance.0 > 0, "The available balance of commission is zero");
+
+        Self::internal_fees_payout(
+          token_id,
+          balance,
+          receiver_id,
+          "Bounties platform fee transfer",
+          true,
+        )
+      },
+      AdminAction::DistributePlatformFee { token_ids } => {
+        self.assert_not_paused(PauseCategory::Payouts);
+        self.internal_distribute_platform_fee_of_tokens(token_ids)
+      },
+      AdminAction::WithdrawNonRefundedBonds => {
+        self.assert_not_paused(PauseCategory::Payouts);
+        let receiver_id = self.recipient_of_platform_fee
+          .clone().expect("The recipient of the non-refundable bonds is not specified");
+        assert!(self.unlocked_amount > 0, "The amount of non-refunded bonds is now zero");
+
+        let amount = self.unlocked_amount;
+        self.unlocked_amount = 0;
+        Promise::new(receiver_id).transfer(amount).into()
+      },
+      AdminAction::WithdrawStorageSubsidyPool { amount } => {
+        self.assert_not_paused(PauseCategory::Payouts);
+        let receiver_id = self.recipient_of_platform_fee
+          .clone().expect("The recipient of the platform fee is not specified");
+        assert!(
+          amount.0 > 0 && amount.0 <= self.storage_subsidy_pool,
+          "The amount exceeds the storage subsidy pool"
+        );
+
+        self.storage_subsidy_pool -= amount.0;
+        Promise::new(receiver_id).transfer(amount.0).into()
+      },
+      AdminAction::StageCode { code_hash } => {
+        let timelock_delay = self.config.clone().to_config().timelock_delay.0;
+        contract_upgrade::stage_code(code_hash, env::predecessor_account_id(), timelock_delay);
+        PromiseOrValue::Value(())
+      },
+      AdminAction::DeployStagedCode { code_hash, migrate_args } => {
+        contract_upgrade::deploy_staged_code(code_hash, migrate_args).into()
+      },
+      AdminAction::MigrateBounties { limit } => {
+        self.internal_migrate_bounties(limit);
+        PromiseOrValue::Value(())
+      },
+      AdminAction::ReroutePendingPayout { account_id, token_id, new_account_id } => {
+        let pending_payout = self
+          .internal_find_pending_payout(&account_id, &token_id)
+          .expect("No pending payout found");
+        assert!(!pending_payout.in_progress, "The transfer of the payout is already in progress");
+        let amount = self.internal_take_pending_payout(&account_id, &token_id);
+        self.internal_add_pending_payout(&new_account_id, &token_id, amount);
+        PromiseOrValue::Value(())
+      },
+    }
+  }
+
+  /// Migrates the stored bounties to the current version from the saved cursor.
+  /// Missing entries of the bounty indexes are added along the way.
+  pub(crate) fn internal_migrate_bounties(&mut self, limit: u64) {
+    let from_id = self.migration_cursor.expect("There is no migration in progress");
+    let to_id = std::cmp::min(from_id + limit, self.last_bounty_id);
+
+    for id in from_id..to_id {
+      if let Some(bounty) = self.bounties.get(&id) {
+        let bounty = bounty.to_bounty();
+        self.internal_update_bounty_indexes(id, None, Some(&bounty.get_index_keys()));
+        self.bounties.insert(&id, &bounty.into());
+      }
+    }
+
+    self.migration_cursor = if to_id < self.last_bounty_id { Some(to_id) } else { None };
+    env::log_str(format!("Bounties from {} to {} are migrated", from_id, to_id).as_str());
+  }
+
+  pub(crate) fn internal_distribute_platform_fee_of_tokens(
+    &mut self,
+    token_ids: Option<Vec<AccountId>>
+  ) -> PromiseOrValue<()> {
+    let token_ids = token_ids.unwrap_or_else(|| self.tokens.keys_as_vector().to_vec());
+    let mut payouts: Option<Promise> = None;
+    let mut payouts_count = 0;
+    for token_id in token_ids {
+      self.internal_distribute_platform_fee(&token_id);
+      for share in self.platform_fee_shares.get(&token_id).unwrap_or_default() {
+        if share.in_progress || share.amount.0 == 0 {
+          continue;
+        }
+        if payouts_count == MAX_PLATFORM_FEE_PAYOUTS {
+          // The remaining shares and tokens are handled by the next call
+          env::log_str("The limit of transfers per call is reached, call the method again for the rest");
+          return payouts.unwrap().into();
+        }
+        let payout = self.internal_platform_fee_share_payout(token_id.clone(), share.account_id);
+        payouts = Some(match payouts {
+          Some(promise) => promise.and(payout),
+          None => payout,
+        });
+        payouts_count += 1;
+      }
+    }
+
+    match payouts {
+      Some(promise) => promise.into(),
+      None => env::panic_str("There is no platform fee to distribute"),
+    }
+  }
+
+  pub(crate) fn internal_get_available_storage_balance(&self, storage: &AccountStorage) -> Balance {
+    let reserved = Balance::from(ACCOUNT_STORAGE_BYTES + storage.used_bytes) * env::storage_byte_cost();
+    storage.deposit.saturating_sub(reserved)
+  }
+
+  /// Checks that the account can pay for the given number of bytes of the contract storage.
+  pub(crate) fn assert_storage_available(&self, account_id: &AccountId, bytes: StorageUsage) {
+    let storage = self.storage_balances.get(account_id).unwrap_or_else(|| env::panic_str(
+      format!("The account {} is not registered, storage deposit is required", account_id).as_str()
+    ));
+    let required = Balance::from(bytes) * env::storage_byte_cost();
+    let available = self.internal_get_available_storage_balance(&storage);
     assert!(
-      matches!(self.status, ContractStatus::Live),
-      "The contract status is not Live"
+      available >= required,
+      "Not enough storage balance, {} yoctoNEAR more required",
+      required - available
+    );
+  }
+
+  /// Attributes the storage change since `initial_storage` to the account.
+  /// The freed storage is released from the storage balance of the account.
+  pub(crate) fn internal_record_storage_usage(
+    &mut self,
+    account_id: &AccountId,
+    initial_storage: StorageUsage,
+  ) {
+    let current_storage = env::storage_usage();
+    if current_storage > initial_storage {
+      let mut storage = self.storage_balances.get(account_id).unwrap_or_default();
+      storage.used_bytes += current_storage - initial_storage;
+      self.storage_balances.insert(account_id, &storage);
+    } else if let Some(mut storage) = self.storage_balances.get(account_id) {
+      storage.used_bytes = storage.used_bytes.saturating_sub(initial_storage - current_storage);
+      self.storage_balances.insert(account_id, &storage);
+    }
+  }
+
+  /// Records the decision of one of the reviewers if the bounty requires the votes of several of them.
+  /// Returns true if the decision takes effect. The decisions of the bounty owner take effect at once.
+  /// Only the votes of the current reviewers are counted.
+  /// The storage of the votes is paid by the bounty owner, so the vote fails with the storage error
+  /// if the storage balance of the owner is not enough, until the owner tops it up.
+  pub(crate) fn internal_add_reviewer_vote(
+    &mut self,
+    bounty: &Bounty,
+    claim_id: ClaimIndex,
+    bounty_claim: &mut BountyClaim,
+    kind: PendingReviewKind,
+    approve: bool,
+    place: Option<usize>,
+  ) -> bool {
+    let reviewer = env::predecessor_account_id();
+    let (reviewers, threshold) = match bounty.reviewers.clone() {
+      Some(Reviewers::MoreReviewers { more_reviewers, threshold }) if reviewer != bounty.owner =>
+        (more_reviewers, threshold as usize),
+      _ => (vec![], 1),
+    };
+    let has_votes = bounty_claim.reviewer_votes
+      .as_ref()
+      .map_or(false, |votes| votes.iter().any(|v| v.kind == kind));
+    if threshold <= 1 && !has_votes {
+      return true;
+    }
+
+    let initial_storage = env::storage_usage();
+    let is_decision_made = if threshold > 1 {
+      let votes = bounty_claim.add_reviewer_vote(ReviewerVote {
+        reviewer: reviewer.clone(),
+        kind,
+        approve,
+        place,
+      }, &reviewers);
+      if votes < threshold {
+        log!("The vote of {} is recorded, {} of {} votes", reviewer, votes, threshold);
+      }
+      votes >= threshold
+    } else {
+      true
+    };
+    if is_decision_made {
+      bounty_claim.clear_reviewer_votes(kind);
+    }
+    self.claims.insert(&claim_id, &bounty_claim.clone().into());
+    self.internal_charge_storage(&bounty.owner, initial_storage);
+    is_decision_made
+  }
+
+  /// Charges the account for the storage used since `initial_storage`.
+  pub(crate) fn internal_charge_storage(&mut self, account_id: &AccountId, initial_storage: StorageUsage) {
+    let current_storage = env::storage_usage();
+    if current_storage > initial_storage {
+      self.assert_storage_available(account_id, current_storage - initial_storage);
+    }
+    self.internal_record_storage_usage(account_id, initial_storage);
+  }
+
+  pub(crate) fn assert_not_paused(&self, category: PauseCategory) {
+    self.try_not_paused(category).unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  pub(crate) fn try_not_paused(&self, category: PauseCategory) -> Result<(), String> {
+    if self.paused_categories.contains(&category) {
+      return Err(format!("The {:?} operations are paused", category));
+    }
+    Ok(())
+  }
+
+  pub(crate) fn is_claim_closed(claim: &BountyClaim) -> bool {
+    [
+      ClaimStatus::Approved,
+      ClaimStatus::Canceled,
+      ClaimStatus::Expired,
+      ClaimStatus::NotCompleted,
+      ClaimStatus::NotHired,
+      ClaimStatus::CompletedWithDispute,
+      ClaimStatus::HandedOver,
+    ].contains(&claim.status)
+  }
+
+  /// The bounty can be archived if it was finalized earlier than the retention period
+  /// and all its claims are closed.
+  pub(crate) fn is_bounty_archivable(&self, id: BountyIndex, bounty: &Bounty) -> bool {
+    if !bounty.is_finalized() {
+      return false;
+    }
+    let retention_period = self.config.clone().to_config().archive_retention_period.0;
+    let finalized_at = bounty.finalized_at.unwrap_or(bounty.created_at).0;
+    finalized_at + retention_period <= env::block_timestamp() &&
+      self.internal_get_claims_by_bounty_id(id)
+        .iter()
+        .all(|(_, claim)| Self::is_claim_closed(claim))
+  }
+
+  /// Replaces the bounty and its claims with the summaries. The freed storage is released
+  /// from the storage balances of the accounts that paid for it.
+  pub(crate) fn internal_archive_bounty(&mut self, id: BountyIndex, bounty: Bounty) {
+    let archived_at = U64::from(env::block_timestamp());
+    let claim_ids = self.bounty_claims.get(&id).unwrap_or_default();
+
+    for claim_id in claim_ids.iter() {
+      let versioned_claim = self.claims.get(claim_id).unwrap();
+      let claim_hash = env::sha256_array(&versioned_claim.try_to_vec().unwrap());
+      let claim: BountyClaim = versioned_claim.into();
+
+      let initial_storage = env::storage_usage();
+      self.claims.remove(claim_id);
+      let mut account_claims = self.bounty_claimants.get(&claim.owner).unwrap_or_default();
+      account_claims.retain(|c| c != claim_id);
+      if account_claims.is_empty() {
+        self.bounty_claimants.remove(&claim.owner);
+      } else {
+        self.bounty_claimants.insert(&claim.owner, &account_claims);
+      }
+      self.internal_record_storage_usage(&claim.owner, initial_storage);
+
+      self.archived_claims.insert(claim_id, &ClaimSummary {
+        claim_hash: claim_hash.into(),
+        owner: claim.owner,
+        bounty_id: id,
+        status: claim.status,
+        bond: claim.bond,
+        created_at: claim.created_at,
+      });
+    }
+
+    let versioned_bounty = self.bounties.get(&id).unwrap();
+    let bounty_hash = env::sha256_array(&versioned_bounty.try_to_vec().unwrap());
+
+    let initial_storage = env::storage_usage();
+    self.internal_update_bounty_indexes(id, Some(&bounty.get_index_keys()), None);
+    self.bounties.remove(&id);
+    self.bounty_claims.remove(&id);
+    self.old_bounty_claimant_accounts.remove(&id);
+    let mut indices = self.account_bounties.get(&bounty.owner).unwrap_or_default();
+    indices.retain(|i| *i != id);
+    self.internal_save_account_bounties(&bounty.owner, indices);
+    self.internal_record_storage_usage(&bounty.owner, initial_storage);
+
+    self.archived_bounties.insert(&id, &BountySummary {
+      bounty_hash: bounty_hash.into(),
+      owner: bounty.owner,
+      token: bounty.token,
+      amount: bounty.amount,
+      platform_fee: bounty.platform_fee,
+      dao_fee: bounty.dao_fee,
+      status: bounty.status,
+      created_at: bounty.created_at,
+      finalized_at: bounty.finalized_at,
+      archived_at,
+      claims: claim_ids,
+    });
+    log!("Bounty {} is archived", id);
+  }
+
+  pub(crate) fn get_quote_payouts(bounty: &Bounty) -> Vec<U128> {
+    match bounty.multitasking.clone() {
+      Some(Multitasking::OneForAll { number_of_slots, .. }) if number_of_slots > 0 => {
+        let one_slot_amount = bounty.amount.0 / number_of_slots as u128;
+        let mut payouts = vec![U128(one_slot_amount); number_of_slots as usize - 1];
+        // There may be rounding errors for the last slot
+        payouts.push(U128(bounty.amount.0 - one_slot_amount * (number_of_slots as u128 - 1)));
+        payouts
+      },
+      Some(Multitasking::DifferentTasks { subtasks, .. }) if !subtasks.is_empty() => {
+        let mut payouts: Vec<U128> = subtasks
+          .iter()
+          .map(|subtask| U128(bounty.amount.0 * subtask.subtask_percent as u128 / 100_000))
+          .collect();
+        let other_slots: u128 = payouts[..payouts.len() - 1].iter().map(|p| p.0).sum();
+        *payouts.last_mut().unwrap() = U128(bounty.amount.0.saturating_sub(other_slots));
+        payouts
+      },
+      Some(Multitasking::ContestOrHackathon { prize_places: Some(places), .. }) =>
+        places.into_iter().map(|place| place.place_amount).collect(),
+      _ => vec![bounty.amount],
+    }
+  }
+
+  pub(crate) fn internal_get_bounty_quote(
+    &self,
+    bounty_create: BountyCreate,
+    token_id: Option<AccountId>,
+    amount: Option<U128>,
+  ) -> BountyQuote {
+    let config = self.config.clone().to_config();
+    let payer_id = env::current_account_id();
+    let min_amount_for_kyc = self.internal_get_min_amount_for_kyc(&token_id);
+    // The bounty cannot be built without the registry entry of its validators DAO
+    let dao_details = match self.try_get_validators_dao_details(&bounty_create) {
+      Ok(dao_details) => dao_details,
+      Err(e) => return BountyQuote {
+        total_amount: U128(0),
+        amount: U128(0),
+        platform_fee: U128(0),
+        dao_fee: U128(0),
+        payouts: vec![],
+        error: Some(e),
+      },
+    };
+    let to_bounty = |total_amount: u128| bounty_create.to_bounty(
+      &payer_id,
+      token_id.clone(),
+      U128(total_amount),
+      config.clone(),
+      min_amount_for_kyc,
+      dao_details.as_ref()
     );
+
+    // One for all bounties are checked against the cost of all slots including the platform fee,
+    // contests with prize places must have the bounty amount equal to the sum of the places
+    let (target, with_platform_fee) = match (amount, bounty_create.multitasking.clone()) {
+      (Some(amount), _) => (Some(amount.0), false),
+      (None, Some(Multitasking::OneForAll { number_of_slots, amount_per_slot, .. })) =>
+        (amount_per_slot.0.checked_mul(number_of_slots as u128), true),
+      (None, Some(Multitasking::ContestOrHackathon { prize_places: Some(places), .. })) =>
+        (places.iter().try_fold(0u128, |sum, place| sum.checked_add(place.place_amount.0)), false),
+      _ => (None, false),
+    };
+    let target_value = |bounty: &Bounty| if with_platform_fee {
+      bounty.amount.0 + bounty.platform_fee.0
+    } else {
+      bounty.amount.0
+    };
+
+    let (percentage_platform, percentage_dao) = if bounty_create.postpaid.is_none() {
+      Bounty::get_percentage_of_commissions(config.clone(), dao_details.as_ref())
+    } else {
+      (0, 0)
+    };
+    let denominator = if with_platform_fee {
+      100_000
+    } else {
+      100_000u128.saturating_sub(percentage_platform).max(1)
+    };
+    let estimate = target.and_then(|target| target.checked_mul(100_000 + percentage_dao))
+      .map(|value| value / denominator);
+
+    let (total_amount, error) = match (target, estimate) {
+      (None, _) => (0, Some("The bounty amount is not specified".to_string())),
+      (Some(_), None) => (0, Some("The bounty amount is too large".to_string())),
+      (Some(target), Some(mut total_amount)) => {
+        // The fees are rounded down, so the estimate can differ from the exact amount by a few tokens
+        while target_value(&to_bounty(total_amount)) < target {
+          total_amount += 1;
+        }
+        while total_amount > 0 && target_value(&to_bounty(total_amount - 1)) >= target {
+          total_amount -= 1;
+        }
+        (total_amount, None)
+      },
+    };
+
+    let mut bounty = to_bounty(total_amount);
+    self.internal_tag_filter(&mut bounty);
+    let error = error.or_else(|| {
+      token_id
+        .as_ref()
+        .map_or(Ok(()), |token_id| self.try_that_token_is_allowed(token_id))
+        .and_then(|_| self.try_check_bounty(&bounty))
+        .err()
+    });
+
+    BountyQuote {
+      total_amount: U128(total_amount),
+      amount: bounty.amount,
+      platform_fee: bounty.platform_fee,
+      dao_fee: bounty.dao_fee,
+      payouts: Self::get_quote_payouts(&bounty),
+      error,
+    }
   }
 }
@@ -1,18 +1,23 @@
 use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::{LookupMap, UnorderedMap, UnorderedSet};
-use near_sdk::json_types::{Base64VecU8, U128, U64};
+use near_sdk::json_types::{Base58CryptoHash, Base64VecU8, U128, U64};
 use near_sdk::serde_json::json;
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{assert_one_yocto, env, is_promise_success, log, near_bindgen, serde_json, AccountId,
-               Balance, Gas, ONE_YOCTO, PanicOnDefault, Promise, PromiseError, PromiseOrValue};
+               Balance, Gas, ONE_YOCTO, PanicOnDefault, Promise, PromiseError, PromiseOrValue, PromiseResult,
+               StorageUsage};
 
+pub use crate::dao::*;
 pub use crate::types::*;
 use crate::upgrade::OldVersionedBountyClaim;
+use contract_upgrade::StagedCode;
 
 pub mod callbacks;
+pub mod dao;
 pub mod internal;
 pub mod receiver;
+pub mod storage;
 pub mod types;
 pub mod view;
 mod upgrade;
@@ -44,11 +49,10 @@ pub struct BountiesContract {
   /// Amount of non-refunded bonds.
   pub unlocked_amount: Balance,
 
-  /// account ids that can perform all actions:
+  /// account ids of super-admins that can perform all actions:
   /// - manage admins_whitelist
-  /// - add new ft-token types
-  /// - change contract config
-  /// - etc.
+  /// - grant and revoke roles
+  /// - perform the actions of any role
   pub admins_whitelist: UnorderedSet<AccountId>,
 
   /// Bounty contract configuration.
@@ -92,6 +96,72 @@ pub struct BountiesContract {
 
   /// Bounty claim IDs map per bounty ID.
   pub bounty_claims: LookupMap<BountyIndex, Vec<ClaimIndex>>,
+
+  /// Weighted list of accounts between which the platform fee is distributed
+  pub platform_fee_recipients: Vec<PlatformFeeRecipient>,
+
+  /// Distributed but not yet transferred platform fee shares per token ID
+  pub platform_fee_shares: LookupMap<AccountId, Vec<PlatformFeeShare>>,
+
+  /// Accounts that are granted administrative roles
+  pub roles: LookupMap<Role, Vec<AccountId>>,
+
+  /// Groups of operations paused while the contract is Live
+  pub paused_categories: Vec<PauseCategory>,
+
+  /// Last available id for the queued change.
+  pub last_queued_change_id: u64,
+
+  /// Changes waiting for the timelock delay to expire
+  pub queued_changes: UnorderedMap<u64, QueuedChange>,
+
+  /// Last available id for the admin action.
+  pub last_admin_action_id: u64,
+
+  /// Critical admin actions waiting for confirmations
+  pub pending_admin_actions: UnorderedMap<u64, PendingAdminAction>,
+
+  /// The next bounty ID to be migrated to the current version after the contract upgrade.
+  pub migration_cursor: Option<BountyIndex>,
+
+  /// NEP-145 storage balances of bounty owners and claimants
+  pub storage_balances: LookupMap<AccountId, AccountStorage>,
+
+  /// Summaries of the archived bounties
+  pub archived_bounties: LookupMap<BountyIndex, BountySummary>,
+
+  /// Summaries of the archived claims
+  pub archived_claims: LookupMap<ClaimIndex, ClaimSummary>,
+
+  /// Bounty indexes map per bounty status.
+  pub bounties_by_status: LookupMap<BountyStatus, UnorderedSet<BountyIndex>>,
+
+  /// Bounty indexes map per category.
+  pub bounties_by_category: LookupMap<String, UnorderedSet<BountyIndex>>,
+
+  /// Bounty indexes map per tag.
+  pub bounties_by_tag: LookupMap<String, UnorderedSet<BountyIndex>>,
+
+  /// Bounty indexes map per token ID.
+  pub bounties_by_token: LookupMap<AccountId, UnorderedSet<BountyIndex>>,
+
+  /// Bounty indexes map per reviewer or validators DAO account.
+  pub bounties_by_reviewer: LookupMap<AccountId, UnorderedSet<BountyIndex>>,
+
+  /// Rewards whose transfer has failed per receiver account
+  pub pending_payouts: UnorderedMap<AccountId, Vec<PendingPayout>>,
+
+  /// NEAR amount for registering the reward receivers on the token contracts
+  pub storage_subsidy_pool: Balance,
+
+  /// Registry of the validators DAOs approved by the admins
+  pub validators_daos: UnorderedMap<AccountId, ValidatorsDaoDetails>,
+
+  /// Team claims waiting for the team members to accept their membership
+  pub pending_team_claims: LookupMap<(BountyIndex, AccountId), PendingTeamClaim>,
+
+  /// Payers and amounts of the bonds of the resubmitted proposals per DAO and proposal ID
+  pub proposal_bonds: LookupMap<(AccountId, u64), (AccountId, U128)>,
 }
 
 #[near_bindgen]
@@ -141,6 +211,28 @@ impl BountiesContract {
       claims: LookupMap::new(StorageKey::Claims),
       bounty_claimants: LookupMap::new(StorageKey::BountyClaimants),
       bounty_claims: LookupMap::new(StorageKey::BountyClaims),
+      platform_fee_recipients: vec![],
+      platform_fee_shares: LookupMap::new(StorageKey::PlatformFeeShares),
+      roles: LookupMap::new(StorageKey::Roles),
+      paused_categories: vec![],
+      last_queued_change_id: 0,
+      queued_changes: UnorderedMap::new(StorageKey::QueuedChanges),
+      last_admin_action_id: 0,
+      pending_admin_actions: UnorderedMap::new(StorageKey::PendingAdminActions),
+      migration_cursor: None,
+      storage_balances: LookupMap::new(StorageKey::StorageBalances),
+      archived_bounties: LookupMap::new(StorageKey::ArchivedBounties),
+      archived_claims: LookupMap::new(StorageKey::ArchivedClaims),
+      bounties_by_status: LookupMap::new(StorageKey::BountiesByStatus),
+      bounties_by_category: LookupMap::new(StorageKey::BountiesByCategory),
+      bounties_by_tag: LookupMap::new(StorageKey::BountiesByTag),
+      bounties_by_token: LookupMap::new(StorageKey::BountiesByToken),
+      bounties_by_reviewer: LookupMap::new(StorageKey::BountiesByReviewer),
+      pending_payouts: UnorderedMap::new(StorageKey::PendingPayouts),
+      storage_subsidy_pool: 0,
+      validators_daos: UnorderedMap::new(StorageKey::ValidatorsDaos),
+      pending_team_claims: LookupMap::new(StorageKey::PendingTeamClaims),
+      proposal_bonds: LookupMap::new(StorageKey::ProposalBonds),
     }
   }
 
@@ -151,7 +243,7 @@ impl BountiesContract {
     whitelist_type: WhitelistType,
   ) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_whitelist_manager(&env::predecessor_account_id(), &whitelist_type);
 
     let account_ids = if let Some(account_ids) = account_ids {
       account_ids
@@ -159,15 +251,7 @@ impl BountiesContract {
       vec![account_id.expect("Expected either account_id or account_ids")]
     };
 
-    for account_id in &account_ids {
-      match whitelist_type {
-        WhitelistType::AdministratorsWhitelist => { self.admins_whitelist.insert(account_id); },
-        WhitelistType::OwnersWhitelist => { self.owners_whitelist.insert(account_id); },
-        WhitelistType::PostpaidSubscribersWhitelist => {
-          self.postpaid_subscribers_whitelist.insert(account_id);
-        },
-      }
-    }
+    self.internal_propose_admin_action(AdminAction::AddToWhitelist { account_ids, whitelist_type });
   }
 
   pub fn remove_from_some_whitelist(
@@ -177,28 +261,29 @@ impl BountiesContract {
     whitelist_type: WhitelistType,
   ) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_whitelist_manager(&env::predecessor_account_id(), &whitelist_type);
 
     let account_ids = if let Some(account_ids) = account_ids {
       account_ids
     } else {
       vec![account_id.expect("Expected either account_id or account_ids")]
     };
-
-    for account_id in &account_ids {
-      match whitelist_type {
-        WhitelistType::AdministratorsWhitelist => { self.admins_whitelist.remove(&account_id); },
-        WhitelistType::OwnersWhitelist => { self.owners_whitelist.remove(&account_id); },
-        WhitelistType::PostpaidSubscribersWhitelist => {
-          self.postpaid_subscribers_whitelist.remove(&account_id);
-        },
-      }
+    if matches!(whitelist_type, WhitelistType::AdministratorsWhitelist) {
+      let mut removed_admins: Vec<&AccountId> = account_ids
+        .iter()
+        .filter(|a| self.admins_whitelist.contains(a))
+        .collect();
+      removed_admins.sort();
+      removed_admins.dedup();
+      let admins_count = self.admins_whitelist.len() - removed_admins.len() as u64;
+      assert!(admins_count > 0, "Cannot remove all accounts from admin whitelist");
+      let threshold = self.config.clone().to_config().admin_approval_threshold;
+      self
+        .try_admin_approval_threshold(threshold, admins_count)
+        .unwrap_or_else(|e| panic!("{}", e));
     }
-    assert!(
-      !matches!(whitelist_type, WhitelistType::AdministratorsWhitelist) ||
-        !self.admins_whitelist.is_empty(),
-      "Cannot remove all accounts from admin whitelist",
-    );
+
+    self.internal_propose_admin_action(AdminAction::RemoveFromWhitelist { account_ids, whitelist_type });
   }
 
   pub fn add_token_id(
@@ -207,66 +292,136 @@ impl BountiesContract {
     min_amount_for_kyc: Option<U128>
   ) -> PromiseOrValue<()> {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_role(Role::TokenManager, &env::predecessor_account_id());
 
     assert!(
       self.tokens.get(&token_id).is_none(),
       "The token already exists"
     );
 
-    self.internal_get_ft_metadata(token_id, min_amount_for_kyc)
+    self.internal_propose_admin_action(AdminAction::AddToken { token_id, min_amount_for_kyc })
   }
 
+  /// The cached symbol and decimals are not changed, use refresh_token_metadata for them.
   pub fn update_token(&mut self, token_id: AccountId, token_details: TokenDetails) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_role(Role::TokenManager, &env::predecessor_account_id());
+
+    assert!(
+      self.tokens.get(&token_id).is_some(),
+      "No token found"
+    );
+    token_details.try_valid().unwrap_or_else(|e| panic!("{}", e));
+
+    self.internal_propose_admin_action(AdminAction::UpdateToken { token_id, token_details });
+  }
+
+  /// Reloads the symbol and decimals of the token from its metadata.
+  pub fn refresh_token_metadata(&mut self, token_id: AccountId) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_role(Role::TokenManager, &env::predecessor_account_id());
 
     assert!(
       self.tokens.get(&token_id).is_some(),
       "No token found"
     );
 
-    self.tokens.insert(&token_id, &token_details);
+    self.internal_get_ft_metadata(token_id, None)
+  }
+
+  pub fn add_validators_dao(
+    &mut self,
+    dao_account_id: AccountId,
+    dao_details: ValidatorsDaoDetailsParams,
+  ) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
+
+    assert!(
+      self.validators_daos.get(&dao_account_id).is_none(),
+      "The validators DAO is already registered"
+    );
+    let dao_details = dao_details.to_validators_dao_details(&self.config.clone().to_config());
+    self.try_validators_dao_details_are_correct(&dao_details).unwrap_or_else(|e| panic!("{}", e));
+
+    self.internal_propose_admin_action(AdminAction::AddValidatorsDao { dao_account_id, dao_details })
+  }
+
+  /// The changes only apply to new bounties, the created bounties keep their DAO settings.
+  pub fn update_validators_dao(
+    &mut self,
+    dao_account_id: AccountId,
+    dao_details: ValidatorsDaoDetailsParams,
+  ) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
+
+    assert!(
+      self.validators_daos.get(&dao_account_id).is_some(),
+      "The validators DAO is not registered"
+    );
+    let dao_details = dao_details.to_validators_dao_details(&self.config.clone().to_config());
+    self.try_validators_dao_details_are_correct(&dao_details).unwrap_or_else(|e| panic!("{}", e));
+
+    self.internal_propose_admin_action(AdminAction::UpdateValidatorsDao { dao_account_id, dao_details })
+  }
+
+  /// The created bounties continue to use the removed DAO.
+  pub fn remove_validators_dao(&mut self, dao_account_id: AccountId) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
+
+    assert!(
+      self.validators_daos.get(&dao_account_id).is_some(),
+      "The validators DAO is not registered"
+    );
+
+    self.internal_propose_admin_action(AdminAction::RemoveValidatorsDao { dao_account_id })
   }
 
   pub fn update_kyc_whitelist_contract(&mut self, kyc_whitelist_contract: Option<AccountId>) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
 
-    self.kyc_whitelist_contract = kyc_whitelist_contract;
+    self.internal_queue_change(TimelockedAction::UpdateKycWhitelistContract { kyc_whitelist_contract });
   }
 
   /// Can be used only during migrations when updating contract versions
   pub fn update_reputation_contract(&mut self, reputation_contract: AccountId) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
 
     assert!(
       self.reputation_contract.is_some(),
       "The reputation contract is not used",
     );
 
-    self.reputation_contract = Some(reputation_contract);
+    self.internal_queue_change(TimelockedAction::UpdateReputationContract { reputation_contract });
   }
 
   /// Can be used only during migrations when updating contract versions
   pub fn update_dispute_contract(&mut self, dispute_contract: AccountId) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
 
     assert!(
       self.dispute_contract.is_some(),
       "The dispute contract is not used",
     );
 
-    self.dispute_contract = Some(dispute_contract);
+    self.internal_queue_change(TimelockedAction::UpdateDisputeContract { dispute_contract });
   }
 
   pub fn change_config(&mut self, config_create: ConfigCreate) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
+    if let Some(threshold) = config_create.admin_approval_threshold {
+      self
+        .try_admin_approval_threshold(threshold, self.admins_whitelist.len())
+        .unwrap_or_else(|e| panic!("{}", e));
+    }
 
-    self.config = config_create.to_config(self.config.clone().to_config()).into();
+    self.internal_propose_admin_action(AdminAction::ChangeConfig { config_create });
   }
 
   pub fn update_configuration_dictionary_entries(
@@ -276,12 +431,12 @@ impl BountiesContract {
     entries: Option<Vec<String>>
   ) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
-    let (reference, entries) = self.get_configuration_dictionary(dict, entry, entries);
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
+    let entries = Self::get_configuration_dictionary_entries(&dict, entry, entries);
 
-    for entry in entries {
-      reference.push(entry);
-    }
+    self.internal_propose_admin_action(
+      AdminAction::UpdateConfigurationDictionaryEntries { dict, entries }
+    );
   }
 
   pub fn remove_configuration_dictionary_entries(
@@ -291,26 +446,64 @@ impl BountiesContract {
     entries: Option<Vec<String>>
   ) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
-    let (reference, entries) = self.get_configuration_dictionary(dict, entry, entries);
+    self.assert_role(Role::ConfigManager, &env::predecessor_account_id());
+    let entries = Self::get_configuration_dictionary_entries(&dict, entry, entries);
 
-    for entry in entries {
-      let index = reference.iter().position(|e| e.clone() == entry);
-      if index.is_some() {
-        reference.remove(index.unwrap());
-      }
-    }
+    self.internal_propose_admin_action(
+      AdminAction::RemoveConfigurationDictionaryEntries { dict, entries }
+    );
   }
 
   pub fn change_recipient_of_platform_fee(&mut self, recipient_of_platform_fee: AccountId) {
     self.assert_live();
-    self.assert_admins_whitelist(&env::predecessor_account_id());
+    self.assert_role(Role::FeeManager, &env::predecessor_account_id());
+
+    self.internal_queue_change(
+      TimelockedAction::ChangeRecipientOfPlatformFee { recipient_of_platform_fee }
+    );
+  }
+
+  /// Applies the queued change after the timelock delay has expired. Available to any account.
+  pub fn execute_queued_change(&mut self, change_id: u64) {
+    self.assert_live();
+    let change = self.queued_changes.get(&change_id).expect("Queued change not found");
+    assert!(
+      env::block_timestamp() >= change.executable_at.0,
+      "The timelock delay has not expired yet"
+    );
+
+    self.queued_changes.remove(&change_id);
+    self.internal_apply_change(change.action);
+  }
+
+  pub fn cancel_queued_change(&mut self, change_id: u64) {
+    self.assert_live();
+    let change = self.queued_changes.get(&change_id).expect("Queued change not found");
+    self.assert_role(change.action.get_role(), &env::predecessor_account_id());
+
+    self.queued_changes.remove(&change_id);
+  }
+
+  pub fn change_platform_fee_recipients(&mut self, recipients: Vec<PlatformFeeRecipient>) {
+    self.assert_live();
+    self.assert_role(Role::FeeManager, &env::predecessor_account_id());
+
+    for (index, recipient) in recipients.iter().enumerate() {
+      assert!(recipient.weight > 0, "The weight of the recipient must be greater than zero");
+      assert!(
+        recipients.iter().position(|r| r.account_id == recipient.account_id).unwrap() == index,
+        "The recipient {} is specified more than once",
+        recipient.account_id
+      );
+    }
 
-    self.recipient_of_platform_fee = Some(recipient_of_platform_fee);
+    self.internal_propose_admin_action(AdminAction::ChangePlatformFeeRecipients { recipients });
   }
 
   /// Claim given bounty by caller with given expected duration to execute.
   /// Bond must be attached to the claim.
+  /// The claim can be made by a team, then the reward is split between the members by their shares.
+  /// The team claim is created only after all members accept their membership.
   #[payable]
   pub fn bounty_claim(
     &mut self,
@@ -318,8 +511,10 @@ impl BountiesContract {
     deadline: Option<U64>,
     description: String,
     slot: Option<usize>,
+    team: Option<Vec<TeamMember>>,
   ) -> PromiseOrValue<()> {
     self.assert_live();
+    self.assert_not_paused(PauseCategory::ClaimCreation);
     assert_eq!(
       env::attached_deposit(),
       self.config.clone().to_config().bounty_claim_bond.0,
@@ -331,24 +526,57 @@ impl BountiesContract {
     );
 
     let sender_id = env::predecessor_account_id();
-    let (bounty, claim, claim_number) = self.check_if_allowed_to_create_claim_by_status(
-      id,
-      sender_id.clone(),
-      slot.clone()
-    );
+    let (bounty, claim, claim_number) = self
+      .try_check_if_allowed_to_create_claim(id, &sender_id, slot.clone())
+      .unwrap_or_else(|e| panic!("{}", e));
 
     assert!(
       bounty.is_claim_deadline_correct(deadline),
       "Bounty wrong deadline"
     );
-    bounty.assert_account_is_not_owner_or_reviewer(sender_id.clone());
-    assert!(
-      !bounty.claimant_approval.not_allowed_to_create_claim(&sender_id),
-      "{} is not whitelisted",
-      sender_id
+    let team_size = if let Some(team) = team.as_ref() {
+      BountyClaim::try_valid_team(&sender_id, team).unwrap_or_else(|e| panic!("{}", e));
+      for member in team {
+        assert!(
+          bounty.try_account_is_not_owner_or_reviewer(&member.account_id).is_ok(),
+          "The team member {} cannot be a bounty owner or reviewer",
+          member.account_id
+        );
+      }
+      team.len()
+    } else {
+      0
+    };
+
+    self.assert_storage_available(
+      &sender_id,
+      CLAIM_STORAGE_BYTES + description.len() as StorageUsage +
+        TEAM_MEMBER_STORAGE_BYTES * team_size as StorageUsage
     );
 
-    let place_of_check = PlaceOfCheckKYC::CreatingClaim { deadline, description };
+    if let Some(team) = team {
+      assert!(
+        self.pending_team_claims.get(&(id, sender_id.clone())).is_none(),
+        "The team claim is already waiting for the team members"
+      );
+      let initial_storage = env::storage_usage();
+      self.pending_team_claims.insert(
+        &(id, sender_id.clone()),
+        &PendingTeamClaim {
+          deadline,
+          description,
+          slot,
+          team,
+          accepted_members: vec![sender_id.clone()],
+          bond: U128(env::attached_deposit()),
+        }
+      );
+      self.internal_charge_storage(&sender_id, initial_storage);
+      log!("The team claim of {} for bounty {} is waiting for the team members", sender_id, id);
+      return PromiseOrValue::Value(());
+    }
+
+    let place_of_check = PlaceOfCheckKYC::CreatingClaim { deadline, description, team: None };
     if self.is_kyc_check_required(bounty.clone(), None, None, place_of_check.clone()) {
       self.check_if_claimant_in_kyc_whitelist(id, sender_id, claim_number, place_of_check, slot)
 
@@ -364,32 +592,119 @@ impl BountiesContract {
     }
   }
 
+  /// Accept the membership in the team claim. When all members have accepted, the claim is created
+  /// as if it had just been made by the claim owner. If the bounty requires KYC, all members must pass it.
   #[payable]
-  pub fn accept_claimant(
+  pub fn accept_team_membership(
     &mut self,
     id: BountyIndex,
-    receiver_id: AccountId,
-    claim_number: Option<u8>,
-    kyc_postponed: Option<DefermentOfKYC>,
+    claimant_id: AccountId,
   ) -> PromiseOrValue<()> {
     self.assert_live();
+    self.assert_not_paused(PauseCategory::ClaimCreation);
     assert_one_yocto();
 
-    let (bounty, claim_id, bounty_claim) = self.check_if_allowed_to_approve_claim_by_status(
-      id,
-      receiver_id.clone(),
-      claim_number
+    let sender_id = env::predecessor_account_id();
+    let mut team_claim = self
+      .try_get_pending_team_claim(id, &claimant_id, &sender_id)
+      .unwrap_or_else(|e| panic!("{}", e));
+    assert!(
+      !team_claim.accepted_members.contains(&sender_id),
+      "The membership has already been accepted"
     );
 
-    bounty.check_access_rights();
+    let initial_storage = env::storage_usage();
+    team_claim.accepted_members.push(sender_id);
+    if !team_claim.is_accepted() {
+      self.pending_team_claims.insert(&(id, claimant_id.clone()), &team_claim);
+      self.internal_record_storage_usage(&claimant_id, initial_storage);
+      return PromiseOrValue::Value(());
+    }
+
+    self.pending_team_claims.remove(&(id, claimant_id.clone()));
+    self.internal_record_storage_usage(&claimant_id, initial_storage);
+    let (bounty, claim, claim_number) = self
+      .try_check_if_allowed_to_create_claim(id, &claimant_id, team_claim.slot)
+      .unwrap_or_else(|e| panic!("{}", e));
     assert!(
-      matches!(bounty.bounty_flow, BountyFlow::AdvancedFlow),
-      "This operation is not supported for simple bounty flow"
+      bounty.is_claim_deadline_correct(team_claim.deadline),
+      "Bounty wrong deadline"
     );
+
+    let place_of_check = PlaceOfCheckKYC::CreatingClaim {
+      deadline: team_claim.deadline,
+      description: team_claim.description,
+      team: Some(team_claim.team),
+    };
+    if self.is_kyc_check_required(bounty.clone(), None, None, place_of_check.clone()) {
+      self.check_if_claimant_in_kyc_whitelist(id, claimant_id, claim_number, place_of_check, team_claim.slot)
+    } else {
+      self.internal_add_proposal_and_create_claim(
+        id,
+        claimant_id,
+        claim_number,
+        place_of_check,
+        team_claim.slot,
+        Some((bounty, claim)),
+      )
+    }
+  }
+
+  /// Decline the membership in the team claim or withdraw the team claim. The bond is returned
+  /// to the claim owner. Any team member can call this method.
+  #[payable]
+  pub fn decline_team_membership(
+    &mut self,
+    id: BountyIndex,
+    claimant_id: AccountId,
+  ) -> PromiseOrValue<()> {
+    self.assert_live();
+    assert_one_yocto();
+
+    let team_claim = self
+      .try_get_pending_team_claim(id, &claimant_id, &env::predecessor_account_id())
+      .unwrap_or_else(|e| panic!("{}", e));
+
+    let initial_storage = env::storage_usage();
+    self.pending_team_claims.remove(&(id, claimant_id.clone()));
+    self.internal_record_storage_usage(&claimant_id, initial_storage);
+    log!("The team claim of {} for bounty {} is declined", claimant_id, id);
+    Promise::new(claimant_id).transfer(team_claim.bond.0).into()
+  }
+
+  #[payable]
+  pub fn accept_claimant(
+    &mut self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+    kyc_postponed: Option<DefermentOfKYC>,
+  ) -> PromiseOrValue<()> {
+    self.assert_live();
+    assert_one_yocto();
+
+    let (bounty, claim_id, mut bounty_claim) = self
+      .try_check_if_allowed_to_decide_on_claimant(
+        id,
+        &env::predecessor_account_id(),
+        receiver_id.clone(),
+        claim_number
+      )
+      .unwrap_or_else(|e| panic!("{}", e));
     assert!(
       bounty.is_claim_deadline_correct(bounty_claim.deadline),
       "The claim deadline is no longer correct"
     );
+    if !self.internal_add_reviewer_vote(
+      &bounty,
+      claim_id,
+      &mut bounty_claim,
+      PendingReviewKind::ClaimantApproval,
+      true,
+      None
+    ) {
+      return PromiseOrValue::Value(());
+    }
 
     let place_of_check = PlaceOfCheckKYC::DecisionOnClaim {
       is_kyc_delayed: kyc_postponed.clone()
@@ -410,17 +725,24 @@ impl BountiesContract {
   ) -> PromiseOrValue<()> {
     self.assert_live();
 
-    let (bounty, claim_id, bounty_claim) = self.check_if_allowed_to_approve_claim_by_status(
-      id,
-      receiver_id.clone(),
-      claim_number
-    );
-
-    bounty.check_access_rights();
-    assert!(
-      matches!(bounty.bounty_flow, BountyFlow::AdvancedFlow),
-      "This operation is not supported for simple bounty flow"
-    );
+    let (bounty, claim_id, mut bounty_claim) = self
+      .try_check_if_allowed_to_decide_on_claimant(
+        id,
+        &env::predecessor_account_id(),
+        receiver_id,
+        claim_number
+      )
+      .unwrap_or_else(|e| panic!("{}", e));
+    if !self.internal_add_reviewer_vote(
+      &bounty,
+      claim_id,
+      &mut bounty_claim,
+      PendingReviewKind::ClaimantApproval,
+      false,
+      None
+    ) {
+      return PromiseOrValue::Value(());
+    }
 
     self.internal_rejection_and_save_claim(None, Some((bounty, claim_id, bounty_claim)))
   }
@@ -436,34 +758,11 @@ impl BountiesContract {
   ) -> PromiseOrValue<()> {
     self.assert_live();
 
-    let mut bounty = self.get_bounty(id.clone());
-    assert!(
-      matches!(bounty.bounty_flow, BountyFlow::AdvancedFlow),
-      "This operation is not supported for simple bounty flow"
-    );
-    assert!(
-      bounty.status == BountyStatus::Claimed ||
-        bounty.status == BountyStatus::ManyClaimed ||
-        bounty.status == BountyStatus::Completed && bounty.is_contest_or_hackathon(),
-      "Bounty status does not allow to completion"
-    );
-    assert_eq!(
-      claim_number.is_some(),
-      bounty.allow_creating_many_claims,
-      "Invalid claim_number value"
-    );
-
     let sender_id = env::predecessor_account_id();
-    let (claim_id, mut bounty_claim) = self.internal_get_claim(
-      id,
-      sender_id.clone(),
-      claim_number
-    );
-    assert!(
-      bounty_claim.status == ClaimStatus::InProgress ||
-        bounty_claim.status == ClaimStatus::Competes,
-      "The claim status does not allow to complete the bounty"
-    );
+    let (mut bounty, claim_id, mut bounty_claim) = self
+      .try_check_if_allowed_to_complete_claim(id, &sender_id, claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
+    let old_keys = bounty.get_index_keys();
 
     if bounty_claim.is_claim_expired(&bounty) {
       return self.internal_set_claim_expiry_status(id, &sender_id, &mut bounty, claim_id, &mut bounty_claim);
@@ -471,7 +770,7 @@ impl BountiesContract {
 
     if bounty.status == BountyStatus::Completed && bounty_claim.status == ClaimStatus::Competes {
       self.internal_participants_decrement(&mut bounty);
-      self.internal_update_bounty(&id, bounty.clone());
+      self.internal_update_bounty(&id, bounty.clone(), &old_keys);
       return self.internal_claim_closure(
         &sender_id,
         bounty.owner,
@@ -506,30 +805,11 @@ impl BountiesContract {
   ) -> PromiseOrValue<()> {
     self.assert_live();
 
-    let mut bounty = self.get_bounty(id.clone());
     let sender_id = env::predecessor_account_id();
-
-    assert_eq!(
-      claim_number.is_some(),
-      bounty.allow_creating_many_claims,
-      "Invalid claim_number value"
-    );
-
-    let (claim_id, mut bounty_claim) = self.internal_get_claim(
-      id,
-      sender_id.clone(),
-      claim_number
-    );
-    assert!(
-      bounty_claim.status == ClaimStatus::New ||
-        bounty_claim.status == ClaimStatus::InProgress ||
-        bounty_claim.status == ClaimStatus::Competes ||
-        bounty_claim.status == ClaimStatus::ReadyToStart ||
-        bounty_claim.status == ClaimStatus::Completed &&
-          bounty.is_contest_or_hackathon() &&
-          (bounty.status == BountyStatus::New || bounty.status == BountyStatus::Canceled),
-      "The claim status does not allow to give up the bounty"
-    );
+    let (mut bounty, claim_id, mut bounty_claim) = self
+      .try_check_if_allowed_to_give_up(id, &sender_id, claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
+    let old_keys = bounty.get_index_keys();
 
     if bounty_claim.status == ClaimStatus::InProgress ||
       bounty_claim.status == ClaimStatus::Competes &&
@@ -561,7 +841,7 @@ impl BountiesContract {
           self.internal_reset_slot(&mut bounty, slot);
         }
       }
-      self.internal_update_bounty(&id, bounty.clone());
+      self.internal_update_bounty(&id, bounty.clone(), &old_keys);
       self.internal_claim_closure(
         &sender_id,
         bounty.owner,
@@ -574,63 +854,195 @@ impl BountiesContract {
     }
   }
 
-  /// Cancel the bounty and return the funds to the owner.
-  /// Only the owner of the bounty can call this method.
-  pub fn bounty_cancel(&mut self, id: BountyIndex) -> PromiseOrValue<()> {
+  /// Propose another freelancer to take over the claim that is in progress.
+  /// Only the claimant can call this method, the storage of the proposal is paid by the claimant.
+  #[payable]
+  pub fn propose_claim_handover(
+    &mut self,
+    id: BountyIndex,
+    claim_number: Option<u8>,
+    successor_id: AccountId,
+  ) {
     self.assert_live();
-
-    let bounty = self.get_bounty(id.clone());
-    assert!(
-      bounty.status == BountyStatus::New || bounty.status == BountyStatus::AwaitingClaims,
-      "Bounty status does not allow cancellation"
-    );
+    assert_one_yocto();
 
     let sender_id = env::predecessor_account_id();
-    assert_eq!(bounty.owner, sender_id, "Only the owner of the bounty can call this method");
-
-    self.internal_refund_bounty_amount(id, bounty)
+    let (bounty, claim_id, mut bounty_claim) = self
+      .try_check_if_allowed_to_propose_handover(id, &sender_id, claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
+    self.try_check_claim_successor(id, &bounty, &successor_id).unwrap_or_else(|e| panic!("{}", e));
+
+    let initial_storage = env::storage_usage();
+    bounty_claim.handover_to = Some(successor_id.clone());
+    bounty_claim.handover_accepted = false;
+    self.claims.insert(&claim_id, &bounty_claim.into());
+    self.internal_charge_storage(&sender_id, initial_storage);
+    log!("Claimant {} proposed {} to take over the claim for bounty {}", sender_id, successor_id, id);
   }
 
+  /// Accept the proposed claim handover. After that, the successor can take over the claim.
+  /// Only the bounty owner or reviewers can call this method.
   #[payable]
-  pub fn bounty_approve(
+  pub fn accept_claim_handover(
     &mut self,
     id: BountyIndex,
     receiver_id: AccountId,
     claim_number: Option<u8>,
-    prize_place: Option<usize>,
-  ) -> PromiseOrValue<()> {
+  ) {
     self.assert_live();
     assert_one_yocto();
 
-    let bounty = self.get_bounty(id.clone());
-    assert!(
-      bounty.status == BountyStatus::Claimed ||
-        bounty.status == BountyStatus::ManyClaimed,
-      "Bounty status does not allow approval of the execution result"
-    );
+    let (_, claim_id, mut bounty_claim, _) = self
+      .try_check_if_allowed_to_accept_handover(
+        id,
+        &env::predecessor_account_id(),
+        receiver_id,
+        claim_number
+      )
+      .unwrap_or_else(|e| panic!("{}", e));
 
-    bounty.check_access_rights();
+    bounty_claim.handover_accepted = true;
+    self.claims.insert(&claim_id, &bounty_claim.into());
+  }
 
-    assert_eq!(
-      claim_number.is_some(),
-      bounty.allow_creating_many_claims,
-      "Invalid claim_number value"
-    );
+  /// Take over the claim whose handover has been accepted. The successor gets the claim with
+  /// the same start time, deadline and bond, and pays for its storage. If the bounty requires KYC,
+  /// the successor must pass it. Only the proposed successor can call this method.
+  #[payable]
+  pub fn take_over_claim(
+    &mut self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::ClaimCreation);
+    assert_one_yocto();
 
-    let (_, bounty_claim) = self.internal_get_claim(
-      id,
-      receiver_id.clone(),
-      claim_number
-    );
-    assert!(
-      matches!(bounty_claim.status, ClaimStatus::Completed),
-      "The claim status does not allow approval of the execution result"
+    let successor_id = env::predecessor_account_id();
+    let (bounty, _, bounty_claim, _) = self
+      .try_check_if_allowed_to_take_over_claim(id, &successor_id, receiver_id.clone(), claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
+    self.assert_storage_available(
+      &successor_id,
+      CLAIM_STORAGE_BYTES + bounty_claim.description.len() as StorageUsage
     );
 
+    if matches!(bounty.kyc_config, KycConfig::KycRequired { .. }) {
+      assert!(self.kyc_whitelist_contract.is_some(), "KYC whitelist contract is not set");
+      self.check_if_claimant_in_kyc_whitelist(
+        id,
+        receiver_id,
+        claim_number,
+        PlaceOfCheckKYC::ClaimHandover { successor_id },
+        None
+      )
+    } else {
+      self.internal_hand_over_claim(id, receiver_id, claim_number);
+      PromiseOrValue::Value(())
+    }
+  }
+
+  /// Decline the proposed claim handover. The storage of the proposal is released to the claimant.
+  /// The claimant, the successor, the bounty owner or reviewers can call this method.
+  #[payable]
+  pub fn decline_claim_handover(
+    &mut self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+  ) {
+    self.assert_live();
+    assert_one_yocto();
+
+    let sender_id = env::predecessor_account_id();
+    let (_, claim_id, mut bounty_claim, successor_id) =
+      self.try_get_claim_handover(id, receiver_id.clone(), claim_number).unwrap_or_else(|e| panic!("{}", e));
+    if sender_id != receiver_id && sender_id != successor_id {
+      self
+        .try_check_if_allowed_to_decide_on_handover(id, &sender_id, receiver_id.clone(), claim_number)
+        .unwrap_or_else(|e| panic!("{}", e));
+    }
+
+    let initial_storage = env::storage_usage();
+    bounty_claim.handover_to = None;
+    bounty_claim.handover_accepted = false;
+    self.claims.insert(&claim_id, &bounty_claim.into());
+    self.internal_record_storage_usage(&receiver_id, initial_storage);
+  }
+
+  /// Set the account that receives the reward instead of the claimant. If a message is specified,
+  /// the reward is transferred with ft_transfer_call, e.g. to a vault or staking contract.
+  /// Only the claimant can call this method, and only before the claim is approved.
+  #[payable]
+  pub fn set_claim_payout_target(
+    &mut self,
+    id: BountyIndex,
+    claim_number: Option<u8>,
+    payout_target: Option<PayoutTarget>,
+  ) {
+    self.assert_live();
+    assert_one_yocto();
+
+    let sender_id = env::predecessor_account_id();
+    let (claim_id, mut bounty_claim) = self
+      .try_check_if_allowed_to_set_payout_target(id, &sender_id, claim_number, &payout_target)
+      .unwrap_or_else(|e| panic!("{}", e));
+
+    let initial_storage = env::storage_usage();
+    bounty_claim.payout_target = payout_target;
+    self.claims.insert(&claim_id, &bounty_claim.into());
+    self.internal_charge_storage(&sender_id, initial_storage);
+  }
+
+  /// Cancel the bounty and return the funds to the owner.
+  /// Only the owner of the bounty can call this method.
+  pub fn bounty_cancel(&mut self, id: BountyIndex) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
+
+    let bounty = self
+      .try_check_if_allowed_to_cancel(id, &env::predecessor_account_id())
+      .unwrap_or_else(|e| panic!("{}", e));
+
+    self.internal_refund_bounty_amount(id, bounty)
+  }
+
+  #[payable]
+  pub fn bounty_approve(
+    &mut self,
+    id: BountyIndex,
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
+    prize_place: Option<usize>,
+  ) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
+    assert_one_yocto();
+
+    let (bounty, claim_id, mut bounty_claim) = self
+      .try_check_if_allowed_to_make_verdict(
+        id,
+        &env::predecessor_account_id(),
+        receiver_id.clone(),
+        claim_number
+      )
+      .unwrap_or_else(|e| panic!("{}", e));
+
     let place = Self::internal_get_real_prize_place(prize_place, &bounty);
 
     Self::assert_postpaid_is_ready(&bounty, &bounty_claim, true);
     self.assert_multitasking_requirements(id, &bounty, &receiver_id, claim_number, place);
+    if !self.internal_add_reviewer_vote(
+      &bounty,
+      claim_id,
+      &mut bounty_claim,
+      PendingReviewKind::ResultVerdict,
+      true,
+      place
+    ) {
+      return PromiseOrValue::Value(());
+    }
 
     self.internal_bounty_payout(id, Some((receiver_id, claim_number)), place)
   }
@@ -643,32 +1055,26 @@ impl BountiesContract {
   ) -> PromiseOrValue<()> {
     self.assert_live();
 
-    let mut bounty = self.get_bounty(id.clone());
-    assert!(
-      bounty.status == BountyStatus::Claimed ||
-        bounty.status == BountyStatus::ManyClaimed,
-      "Bounty status does not allow approval of the execution result"
-    );
-
-    bounty.check_access_rights();
-
-    assert_eq!(
-      claim_number.is_some(),
-      bounty.allow_creating_many_claims,
-      "Invalid claim_number value"
-    );
-
-    let (claim_id, mut bounty_claim) = self.internal_get_claim(
-      id,
-      receiver_id.clone(),
-      claim_number
-    );
-    assert!(
-      matches!(bounty_claim.status, ClaimStatus::Completed),
-      "The claim status does not allow approval of the execution result"
-    );
+    let (mut bounty, claim_id, mut bounty_claim) = self
+      .try_check_if_allowed_to_make_verdict(
+        id,
+        &env::predecessor_account_id(),
+        receiver_id.clone(),
+        claim_number
+      )
+      .unwrap_or_else(|e| panic!("{}", e));
 
     Self::assert_postpaid_is_ready(&bounty, &bounty_claim, false);
+    if !self.internal_add_reviewer_vote(
+      &bounty,
+      claim_id,
+      &mut bounty_claim,
+      PendingReviewKind::ResultVerdict,
+      false,
+      None
+    ) {
+      return PromiseOrValue::Value(());
+    }
 
     self.internal_reject_claim(id, receiver_id, &mut bounty, claim_id, &mut bounty_claim)
   }
@@ -676,9 +1082,11 @@ impl BountiesContract {
   #[payable]
   pub fn bounty_approve_of_several(&mut self, id: BountyIndex) -> PromiseOrValue<()> {
     self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
     assert_one_yocto();
 
-    let bounty = self.get_bounty(id.clone());
+    let bounty = self.internal_get_bounty(id.clone());
+    bounty.assert_not_frozen();
     assert!(
       bounty.status == BountyStatus::Claimed ||
         bounty.status == BountyStatus::ManyClaimed,
@@ -712,14 +1120,11 @@ impl BountiesContract {
   ) -> PromiseOrValue<()> {
     self.assert_live();
 
-    let mut bounty = self.get_bounty(id.clone());
+    let mut bounty = self.internal_get_bounty(id.clone());
+    bounty.assert_not_frozen();
 
     if claimant.is_none() {
-      assert!(
-        bounty.status == BountyStatus::Claimed ||
-          bounty.status == BountyStatus::ManyClaimed,
-        "Bounty status does not allow approval of the execution result"
-      );
+      Self::try_check_if_allowed_to_finalize(&bounty).unwrap_or_else(|e| panic!("{}", e));
 
       if bounty.multitasking.is_none() || bounty.is_different_tasks() {
         let active_claim = if bounty.multitasking.is_none() {
@@ -750,17 +1155,11 @@ impl BountiesContract {
   pub fn bounty_update(&mut self, id: BountyIndex, bounty_update: BountyUpdate) {
     self.assert_live();
 
-    let mut bounty = self.get_bounty(id.clone());
-    assert!(
-      bounty.status == BountyStatus::New ||
-        bounty.status == BountyStatus::Claimed ||
-        bounty.status == BountyStatus::ManyClaimed ||
-        bounty.status == BountyStatus::AwaitingClaims,
-      "Bounty status does not allow updating"
-    );
-
     let sender_id = env::predecessor_account_id();
-    assert_eq!(bounty.owner, sender_id, "Only the owner of the bounty can call this method");
+    let mut bounty = self
+      .try_check_if_allowed_to_update(id, &sender_id)
+      .unwrap_or_else(|e| panic!("{}", e));
+    let old_keys = bounty.get_index_keys();
 
     let claims = self.internal_get_claims_by_bounty_id(id.clone());
     let found_claim = claims
@@ -871,16 +1270,18 @@ impl BountiesContract {
         "It is not possible to start using Validators DAO after creating a bounty"
       );
       let more_reviewers = bounty_update.reviewers.clone().unwrap().get_more_reviewers();
-      if bounty.reviewers.is_some() {
-        let prev_more_reviewers = bounty.reviewers.clone().unwrap().get_more_reviewers();
-        assert_ne!(
-          more_reviewers,
-          prev_more_reviewers,
+      let reviewers = bounty_update.reviewers.unwrap().to_reviewers(None);
+      if let (
+        Some(Reviewers::MoreReviewers { more_reviewers: prev_more_reviewers, threshold: prev_threshold }),
+        Reviewers::MoreReviewers { threshold, .. },
+      ) = (bounty.reviewers.clone(), &reviewers) {
+        assert!(
+          more_reviewers != prev_more_reviewers || *threshold != prev_threshold,
           "The list of reviewers has not changed"
         );
       }
       bounty.reviewers = if more_reviewers.len() > 0 {
-        Some(bounty_update.reviewers.unwrap().to_reviewers())
+        Some(reviewers)
       } else {
         None
       };
@@ -904,8 +1305,10 @@ impl BountiesContract {
     }
 
     assert!(changed, "No changes found");
-    self.check_bounty(&bounty);
-    self.internal_update_bounty(&id, bounty);
+    self.check_bounty(&mut bounty);
+    let initial_storage = env::storage_usage();
+    self.internal_update_bounty(&id, bounty, &old_keys);
+    self.internal_charge_storage(&sender_id, initial_storage);
   }
 
   pub fn extend_claim_deadline(
@@ -917,7 +1320,8 @@ impl BountiesContract {
   ) {
     self.assert_live();
 
-    let bounty = self.get_bounty(id.clone());
+    let bounty = self.internal_get_bounty(id.clone());
+    bounty.assert_not_frozen();
     assert!(
       matches!(bounty.bounty_flow, BountyFlow::AdvancedFlow),
       "This operation is not supported for simple bounty flow"
@@ -968,6 +1372,7 @@ impl BountiesContract {
     amount: U128
   ) {
     self.assert_live();
+    self.assert_not_paused(PauseCategory::BountyCreation);
     assert_one_yocto();
 
     let sender_id = env::predecessor_account_id();
@@ -993,7 +1398,9 @@ impl BountiesContract {
     self.assert_live();
 
     let sender_id = env::predecessor_account_id();
-    let mut bounty = self.get_bounty(id);
+    let mut bounty = self.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    bounty.assert_not_frozen();
 
     assert!(
       bounty.is_payment_outside_contract(),
@@ -1038,7 +1445,7 @@ impl BountiesContract {
         bounty.multitasking = Some(
           multitasking.set_competition_winner(place.unwrap(), (receiver_id.unwrap(), claim_number))
         );
-        self.internal_update_bounty(&id, bounty);
+        self.internal_update_bounty(&id, bounty, &old_keys);
       }
 
       bounty_claim.set_payment_at(Some(U64::from(env::block_timestamp())));
@@ -1048,7 +1455,7 @@ impl BountiesContract {
       bounty.postpaid = Some(
         bounty.postpaid.clone().unwrap().set_payment_at(Some(U64::from(env::block_timestamp())))
       );
-      self.internal_update_bounty(&id, bounty);
+      self.internal_update_bounty(&id, bounty, &old_keys);
     }
   }
 
@@ -1056,7 +1463,9 @@ impl BountiesContract {
     self.assert_live();
 
     let sender_id = env::predecessor_account_id();
-    let mut bounty = self.get_bounty(id);
+    let mut bounty = self.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    bounty.assert_not_frozen();
 
     assert!(
       bounty.is_payment_outside_contract(),
@@ -1110,7 +1519,7 @@ impl BountiesContract {
 
       if bounty.is_different_tasks() {
         self.internal_confirm_slot(&mut bounty, bounty_claim.slot.clone().unwrap());
-        self.internal_update_bounty(&id, bounty);
+        self.internal_update_bounty(&id, bounty, &old_keys);
       }
 
     } else {
@@ -1119,7 +1528,7 @@ impl BountiesContract {
           Some(U64::from(env::block_timestamp()))
         )
       );
-      self.internal_update_bounty(&id, bounty);
+      self.internal_update_bounty(&id, bounty, &old_keys);
     }
   }
 
@@ -1127,7 +1536,8 @@ impl BountiesContract {
     self.assert_live();
 
     let sender_id = env::predecessor_account_id();
-    let mut bounty = self.get_bounty(id);
+    let mut bounty = self.internal_get_bounty(id);
+    bounty.assert_not_frozen();
 
     assert!(
       matches!(bounty.status, BountyStatus::New),
@@ -1176,28 +1586,131 @@ impl BountiesContract {
   #[payable]
   pub fn withdraw_platform_fee(&mut self, token_id: AccountId) -> PromiseOrValue<()> {
     self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
     assert_one_yocto();
-    assert_eq!(
+    let sender_id = env::predecessor_account_id();
+    assert!(
       self.recipient_of_platform_fee
-        .clone().expect("The recipient of the platform fee is not specified"),
-      env::predecessor_account_id(),
+        .clone().expect("The recipient of the platform fee is not specified") == sender_id ||
+        self.has_role(Role::FeeManager, &sender_id),
       "This account does not have permission to perform this action"
     );
     let balance = self.internal_get_unlocked_platform_fee_amount(token_id.clone());
     assert!(balance.0 > 0, "The available balance of commission is zero");
 
-    Self::internal_fees_payout(
-      token_id,
-      balance,
-      env::predecessor_account_id(),
-      "Bounties platform fee transfer",
-      true,
+    self.internal_propose_admin_action(AdminAction::WithdrawPlatformFee { token_id })
+  }
+
+  /// Distributes the available platform fee of the given tokens (or of all tokens)
+  /// between the platform fee recipients according to their weights.
+  /// Shares whose transfer failed earlier are sent again.
+  #[payable]
+  pub fn distribute_platform_fee(&mut self, token_ids: Option<Vec<AccountId>>) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
+    assert_one_yocto();
+    let sender_id = env::predecessor_account_id();
+    assert!(
+      self.has_role(Role::FeeManager, &sender_id) ||
+        self.platform_fee_recipients.iter().any(|r| r.account_id == sender_id),
+      "This account does not have permission to perform this action"
+    );
+
+    self.internal_propose_admin_action(AdminAction::DistributePlatformFee { token_ids })
+  }
+
+  /// Retries the transfer of the platform fee share that is allocated to the caller.
+  #[payable]
+  pub fn withdraw_platform_fee_share(&mut self, token_id: AccountId) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
+    assert_one_yocto();
+    let account_id = env::predecessor_account_id();
+    let share = self.platform_fee_shares
+      .get(&token_id)
+      .unwrap_or_default()
+      .into_iter()
+      .find(|s| s.account_id == account_id)
+      .expect("No platform fee share found");
+    assert!(!share.in_progress, "The transfer of the share is already in progress");
+    assert!(share.amount.0 > 0, "The available balance of commission is zero");
+
+    self.internal_platform_fee_share_payout(token_id, account_id).into()
+  }
+
+  /// Tops up the pool used to register the reward receivers on the token contracts.
+  /// Available to any account, e.g. the platform or a bounty owner.
+  #[payable]
+  pub fn fund_storage_subsidy_pool(&mut self) {
+    self.assert_live();
+    let amount = env::attached_deposit();
+    assert!(amount > 0, "Expected a deposit to fund the storage subsidy pool");
+    self.storage_subsidy_pool += amount;
+    log!("{} added {} to the storage subsidy pool", env::predecessor_account_id(), amount);
+  }
+
+  /// Withdraws the unused part of the storage subsidy pool (or the whole pool)
+  /// to the recipient of the platform fee.
+  #[payable]
+  pub fn withdraw_storage_subsidy_pool(&mut self, amount: Option<U128>) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
+    assert_one_yocto();
+    let sender_id = env::predecessor_account_id();
+    assert!(
+      self.recipient_of_platform_fee
+        .clone().expect("The recipient of the platform fee is not specified") == sender_id ||
+        self.has_role(Role::FeeManager, &sender_id),
+      "This account does not have permission to perform this action"
+    );
+    let amount = amount.unwrap_or(U128(self.storage_subsidy_pool));
+    assert!(
+      amount.0 > 0 && amount.0 <= self.storage_subsidy_pool,
+      "The amount exceeds the storage subsidy pool"
+    );
+
+    self.internal_propose_admin_action(AdminAction::WithdrawStorageSubsidyPool { amount })
+  }
+
+  /// Retries the transfer of the reward whose payout has failed earlier.
+  /// Available to any account, the reward is transferred to the account it is recorded for.
+  #[payable]
+  pub fn withdraw_pending_payout(&mut self, account_id: AccountId, token_id: AccountId) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
+    assert_one_yocto();
+    let pending_payout = self
+      .internal_find_pending_payout(&account_id, &token_id)
+      .expect("No pending payout found");
+    assert!(!pending_payout.in_progress, "The transfer of the payout is already in progress");
+
+    self.internal_pending_payout_transfer(account_id, token_id).into()
+  }
+
+  /// Moves the pending payout to another account, e.g. if the receiver cannot accept the token.
+  /// Only super-admins can call this method.
+  pub fn reroute_pending_payout(
+    &mut self,
+    account_id: AccountId,
+    token_id: AccountId,
+    new_account_id: AccountId,
+  ) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_admins_whitelist(&env::predecessor_account_id());
+    assert!(
+      self.internal_find_pending_payout(&account_id, &token_id).is_some(),
+      "No pending payout found"
+    );
+
+    self.internal_propose_admin_action(
+      AdminAction::ReroutePendingPayout { account_id, token_id, new_account_id }
     )
   }
 
   #[payable]
   pub fn withdraw_validators_dao_fee(&mut self, token_id: AccountId) -> PromiseOrValue<()> {
     self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
     assert_one_yocto();
     let dao_account_id = env::predecessor_account_id();
     let balance = self.internal_get_unlocked_validators_dao_fee_amount(
@@ -1218,46 +1731,22 @@ impl BountiesContract {
   #[payable]
   pub fn withdraw(&mut self, id: BountyIndex, claim_number: Option<u8>) -> PromiseOrValue<()> {
     self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
     assert_one_yocto();
 
     let receiver_id = env::predecessor_account_id();
-    let bounty = self.get_bounty(id);
-
-    assert!(
-      bounty.is_different_tasks(),
-      "This action is only available for the DifferentTasks mode"
-    );
-    assert!(
-      matches!(bounty.status, BountyStatus::Completed),
-      "Bounty status does not allow this action"
-    );
-    assert_eq!(
-      claim_number.is_some(),
-      bounty.allow_creating_many_claims,
-      "Invalid claim_number value"
-    );
-
-    let (_, bounty_claim) = self.internal_get_claim(id, receiver_id.clone(), claim_number);
-    assert!(
-      bounty_claim.status == ClaimStatus::Completed ||
-        bounty_claim.status == ClaimStatus::CompletedWithDispute,
-      "The claim status does not allow this action"
-    );
-
-    let slot = bounty_claim.slot.clone().unwrap();
-    let slot_env = bounty.multitasking.clone().unwrap().get_slot_env(slot);
-    assert_eq!(
-      slot_env.expect("The slot is not occupied").participant,
-      receiver_id,
-      "This slot belongs to another account"
-    );
+    let (bounty, slot) = self
+      .try_check_if_allowed_to_withdraw(id, &receiver_id, claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
 
     self.internal_bounty_withdraw(id, bounty, receiver_id, claim_number, slot)
   }
 
   pub fn update_validators_dao_params(&mut self, id: BountyIndex, dao_params: ValidatorsDaoParams) {
     self.assert_live();
-    let mut bounty = self.get_bounty(id.clone());
+    let mut bounty = self.internal_get_bounty(id.clone());
+    let old_keys = bounty.get_index_keys();
+    bounty.assert_not_frozen();
 
     assert_eq!(
       bounty.owner,
@@ -1269,46 +1758,77 @@ impl BountiesContract {
       "DAO validators are not used for this bounty"
     );
     bounty.assert_validators_dao_account_id(dao_params.clone().account_id);
+    let dao_details = self.validators_daos
+      .get(&dao_params.account_id)
+      .expect("The validators DAO is not registered");
 
-    bounty.reviewers = Some(Reviewers::ValidatorsDao {validators_dao: dao_params.to_validators_dao()});
-    self.internal_update_bounty(&id, bounty);
+    bounty.reviewers = Some(Reviewers::ValidatorsDao {
+      validators_dao: dao_params.to_validators_dao(&dao_details)
+    });
+    self.internal_update_bounty(&id, bounty, &old_keys);
   }
 
-  pub fn open_dispute(
+  /// Checks the proposal of the validators DAO that the claim is waiting for, and if it has expired,
+  /// has been removed, or its rejection cannot be applied, creates a new proposal instead.
+  /// If the previous proposal has already been dropped, the new one is created immediately.
+  /// Only the bounty owner or the claimant can call this method, attaching the proposal bond
+  /// of the validators DAO. The bond is returned if the proposal cannot be resubmitted.
+  #[payable]
+  pub fn resubmit_dao_proposal(
     &mut self,
     id: BountyIndex,
-    description: String,
-    claim_number: Option<u8>
+    receiver_id: AccountId,
+    claim_number: Option<u8>,
   ) -> PromiseOrValue<()> {
     self.assert_live();
 
+    let sender_id = env::predecessor_account_id();
+    let (bounty, claim, _, proposal_id) = self
+      .try_get_pending_proposal(id, receiver_id.clone(), claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
     assert!(
-      self.dispute_contract.is_some(),
-      "Opening a dispute is not supported by this contract"
-    );
-
-    let bounty = self.get_bounty(id.clone());
-    assert!(
-      bounty.status == BountyStatus::Claimed || bounty.status == BountyStatus::ManyClaimed,
-      "Bounty status does not allow opening a dispute"
+      bounty.owner == sender_id || claim.owner == sender_id,
+      "Only the bounty owner or the claimant can call this method"
     );
+    let validators_dao = match bounty.reviewers {
+      Some(Reviewers::ValidatorsDao { validators_dao }) => validators_dao,
+      _ => unreachable!(),
+    };
+    let bond = validators_dao.add_proposal_bond;
     assert_eq!(
-      claim_number.is_some(),
-      bounty.allow_creating_many_claims,
-      "Invalid claim_number value"
-    );
+      env::attached_deposit(),
+      bond.0,
+      "The attached deposit must be equal to the proposal bond of the validators DAO"
+    );
+
+    match proposal_id {
+      Some(proposal_id) =>
+        Self::internal_finalize_and_check_proposal(
+          &validators_dao,
+          proposal_id,
+          Self::ext(env::current_account_id())
+            .with_static_gas(GAS_FOR_AFTER_CHECK_STUCK_PROPOSAL)
+            .after_check_stuck_proposal(id, receiver_id, claim_number, proposal_id, sender_id, bond)
+        ),
+      None => self
+        .internal_resubmit_proposal(id, receiver_id, claim_number, None, sender_id, bond)
+        .unwrap_or_else(|e| panic!("{}", e)),
+    }
+  }
 
-    let sender_id = env::predecessor_account_id();
-    let (_, bounty_claim) = self.internal_get_claim(id, sender_id.clone(), claim_number);
-    assert!(
-      matches!(bounty_claim.status, ClaimStatus::Rejected),
-      "The claim status does not allow opening a dispute"
-    );
+  pub fn open_dispute(
+    &mut self,
+    id: BountyIndex,
+    description: String,
+    claim_number: Option<u8>
+  ) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Disputes);
 
-    assert!(
-      !self.is_deadline_for_opening_dispute_expired(&bounty_claim),
-      "The period for opening a dispute has expired"
-    );
+    let sender_id = env::predecessor_account_id();
+    let (bounty, _) = self
+      .try_check_if_allowed_to_open_dispute(id, &sender_id, claim_number)
+      .unwrap_or_else(|e| panic!("{}", e));
 
     self.internal_create_dispute(id, &sender_id, claim_number, bounty, description)
   }
@@ -1333,7 +1853,7 @@ impl BountiesContract {
       "This method can only be called by a dispute contract"
     );
 
-    let mut bounty = self.get_bounty(id.clone());
+    let mut bounty = self.internal_get_bounty(id.clone());
     assert!(
       bounty.status == BountyStatus::Claimed || bounty.status == BountyStatus::ManyClaimed,
       "Bounty status does not allow sending the result of the dispute"
@@ -1373,45 +1893,236 @@ impl BountiesContract {
     }
   }
 
-  #[payable]
-  pub fn withdraw_non_refunded_bonds(&mut self) -> Promise {
+  /// Hides the bounty from the lists of bounties and/or freezes it.
+  /// Available to accounts with the Moderator role.
+  pub fn moderate_bounty(&mut self, id: BountyIndex, hidden: bool, frozen: bool, reason: String) {
     self.assert_live();
-    assert_one_yocto();
-    let receiver_id = env::predecessor_account_id();
-
-    assert_eq!(
-      self.recipient_of_platform_fee
-        .clone().expect("The recipient of the non-refundable bonds is not specified"),
-      receiver_id,
-      "This account does not have permission to perform this action"
-    );
-    assert!(self.unlocked_amount > 0, "The amount of non-refunded bonds is now zero");
-
-    let amount = self.unlocked_amount;
-    self.unlocked_amount = 0;
-    Promise::new(receiver_id).transfer(amount)
+    let moderator = env::predecessor_account_id();
+    self.assert_role(Role::Moderator, &moderator);
+    assert!(!reason.is_empty(), "The reason cannot be empty");
+
+    let mut bounty = self.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    bounty.moderation = Some(BountyModeration {
+      moderator,
+      reason,
+      hidden,
+      frozen,
+      updated_at: U64::from(env::block_timestamp()),
+    });
+    self.internal_update_bounty(&id, bounty, &old_keys);
   }
 
-  #[private]
-  pub fn set_status(&mut self, status: ContractStatus) {
+  /// Cancels the bounty regardless of its owner. The active claims are canceled and their bonds
+  /// are returned, the remaining bounty amount is returned to the owner.
+  /// If a claim is being disputed, the amount is kept until the result of the dispute,
+  /// after which the method can be called again. Available to accounts with the Moderator role.
+  pub fn force_cancel_bounty(&mut self, id: BountyIndex, reason: String) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
+    let moderator = env::predecessor_account_id();
+    self.assert_role(Role::Moderator, &moderator);
+    assert!(!reason.is_empty(), "The reason cannot be empty");
+
+    let mut bounty = self.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
     assert!(
-      !matches!(status, ContractStatus::Genesis),
-      "The status can't be set to Genesis"
+      [
+        BountyStatus::New,
+        BountyStatus::Claimed,
+        BountyStatus::ManyClaimed,
+        BountyStatus::AwaitingClaims,
+      ].contains(&bounty.status),
+      "Bounty status does not allow cancellation"
     );
-    self.status = status;
+
+    bounty.moderation = Some(BountyModeration {
+      moderator,
+      reason,
+      hidden: bounty.is_hidden(),
+      frozen: true,
+      updated_at: U64::from(env::block_timestamp()),
+    });
+    let all_claims_canceled = self.internal_force_cancel_claims(id, &mut bounty);
+    self.internal_update_bounty(&id, bounty.clone(), &old_keys);
+
+    if all_claims_canceled {
+      self.internal_refund_bounty_amount(id, bounty)
+    } else {
+      log!("The bounty amount is kept until the result of the dispute");
+      PromiseOrValue::Value(())
+    }
   }
-}
+
+  /// Replaces the finalized bounties and their closed claims with compact summaries
+  /// once the retention period has passed. Bounties that cannot be archived yet are skipped.
+  /// Available to any account. Returns IDs of the archived bounties.
+  pub fn archive_bounties(&mut self, ids: Vec<BountyIndex>) -> Vec<BountyIndex> {
+    self.assert_live();
+    ids
+      .into_iter()
+      .filter(|id| {
+        match self.bounties.get(id) {
+          Some(bounty) => {
+            let bounty: Bounty = bounty.into();
+            if self.is_bounty_archivable(*id, &bounty) {
+              self.internal_archive_bounty(*id, bounty);
+              true
+            } else {
+              false
+            }
+          },
+          None => false,
+        }
+      })
+      .collect()
+  }
+
+  #[payable]
+  pub fn withdraw_non_refunded_bonds(&mut self) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_not_paused(PauseCategory::Payouts);
+    assert_one_yocto();
+    let sender_id = env::predecessor_account_id();
+
+    assert!(
+      self.recipient_of_platform_fee
+        .clone().expect("The recipient of the non-refundable bonds is not specified") == sender_id ||
+        self.has_role(Role::FeeManager, &sender_id),
+      "This account does not have permission to perform this action"
+    );
+    assert!(self.unlocked_amount > 0, "The amount of non-refunded bonds is now zero");
+
+    self.internal_propose_admin_action(AdminAction::WithdrawNonRefundedBonds)
+  }
+
+  /// Confirms the pending admin action. The action is executed as soon as the number
+  /// of confirmations reaches the approval threshold specified in the configuration.
+  #[payable]
+  pub fn confirm_admin_action(&mut self, action_id: u64) -> PromiseOrValue<()> {
+    self.assert_live();
+    assert_one_yocto();
+    let sender_id = env::predecessor_account_id();
+    let mut pending_action = self.pending_admin_actions
+      .get(&action_id)
+      .expect("Admin action not found");
+    // Only super-admins confirm the actions, so that one admin cannot confirm with
+    // the accounts to which they have granted roles
+    self.assert_admins_whitelist(&sender_id);
+    assert!(
+      !pending_action.confirmations.contains(&sender_id),
+      "The action has already been confirmed by this account"
+    );
+
+    // Confirmations of accounts that have been removed from the admins whitelist are not counted
+    pending_action.confirmations.retain(|a| self.admins_whitelist.contains(a));
+    pending_action.confirmations.push(sender_id);
+
+    let threshold = self.config.clone().to_config().admin_approval_threshold;
+    if pending_action.confirmations.len() < threshold as usize {
+      self.pending_admin_actions.insert(&action_id, &pending_action);
+      return PromiseOrValue::Value(());
+    }
+
+    self.pending_admin_actions.remove(&action_id);
+    self.internal_execute_admin_action(pending_action.action)
+  }
+
+  /// Cancels the pending admin action. Available to the proposer and to the admins
+  /// who can confirm the action.
+  pub fn cancel_admin_action(&mut self, action_id: u64) {
+    self.assert_live();
+    let sender_id = env::predecessor_account_id();
+    let pending_action = self.pending_admin_actions
+      .get(&action_id)
+      .expect("Admin action not found");
+    assert!(
+      pending_action.proposer == sender_id ||
+        self.has_admin_permission(pending_action.action.get_role(), &sender_id),
+      "This account does not have permission to perform this action"
+    );
+
+    self.pending_admin_actions.remove(&action_id);
+  }
+
+  #[private]
+  pub fn set_status(&mut self, status: ContractStatus) {
+    assert!(
+      !matches!(status, ContractStatus::Genesis),
+      "The status can't be set to Genesis"
+    );
+    self.status = status;
+  }
+
+  /// Switches the contract between the Live and ReadOnly statuses.
+  /// The Genesis stage can only be completed by the contract account (see set_status).
+  pub fn change_contract_status(&mut self, status: ContractStatus) {
+    self.assert_role(Role::Pauser, &env::predecessor_account_id());
+    assert!(
+      !matches!(self.status, ContractStatus::Genesis),
+      "This action is not possible if the contract status is Genesis"
+    );
+    assert!(
+      !matches!(status, ContractStatus::Genesis),
+      "The status can't be set to Genesis"
+    );
+    self.status = status;
+  }
+
+  /// Pauses or resumes a group of operations. In-flight callbacks are not affected.
+  pub fn set_pause_flag(&mut self, category: PauseCategory, paused: bool) {
+    self.assert_role(Role::Pauser, &env::predecessor_account_id());
+
+    let index = self.paused_categories.iter().position(|c| c == &category);
+    if paused && index.is_none() {
+      self.paused_categories.push(category);
+    } else if !paused && index.is_some() {
+      self.paused_categories.remove(index.unwrap());
+    }
+  }
+
+  /// Grants the role to the account. Available only to super-admins.
+  pub fn grant_role(&mut self, role: Role, account_id: AccountId) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_admins_whitelist(&env::predecessor_account_id());
+
+    assert!(
+      !self.roles.get(&role).unwrap_or_default().contains(&account_id),
+      "The account already has this role"
+    );
+
+    self.internal_propose_admin_action(AdminAction::GrantRole { role, account_id })
+  }
+
+  /// Revokes the role from the account. Available only to super-admins.
+  pub fn revoke_role(&mut self, role: Role, account_id: AccountId) -> PromiseOrValue<()> {
+    self.assert_live();
+    self.assert_admins_whitelist(&env::predecessor_account_id());
+
+    assert!(
+      self.roles.get(&role).unwrap_or_default().contains(&account_id),
+      "The account does not have this role"
+    );
+
+    self.internal_propose_admin_action(AdminAction::RevokeRole { role, account_id })
+  }
+}
 
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(test)]
 mod tests {
-  use near_sdk::test_utils::{accounts, VMContextBuilder};
+  use near_sdk::test_utils::{accounts, get_logs, VMContextBuilder};
   use near_sdk::json_types::{U128, U64};
-  use near_sdk::{testing_env, AccountId, Balance};
-  use crate::{DEFAULT_BOUNTY_CLAIM_BOND, BountiesContract, Bounty, BountyClaim, BountyFlow,
-              BountyIndex, BountyMetadata, BountyStatus, BountyUpdate, ClaimantApproval,
-              ClaimStatus, Config, ConfigCreate, ContractStatus, Deadline, FeeStats, KycConfig,
-              Reviewers, TokenDetails, ValidatorsDao, ValidatorsDaoParams, WhitelistType};
+  use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
+  use near_contract_standards::storage_management::{StorageBalance, StorageManagement};
+  use near_sdk::{serde_json, testing_env, AccountId, Balance, PromiseOrValue, PromiseResult, RuntimeFeesConfig, VMConfig, VMContext, ONE_NEAR};
+  use crate::{DEFAULT_ARCHIVE_RETENTION_PERIOD, DEFAULT_BOUNTY_CLAIM_BOND, DEFAULT_TIMELOCK_DELAY, AccountStorage, AdminAction, BountiesContract, Bounty, BountyClaim, BountyFlow,
+              DaoAdapter, DaoKind, DaoProposalStatus, MultisigAdapter, SputnikV2Adapter, ValidatorsDaoV1,
+              BountyCreate, BountyIndex, BountyFilter, BountyMetadata, BountyRecord, BountyStatus, BountyUpdate, ClaimantApproval, ClaimRecord,
+              ClaimStatus, Config, ConfigCreate, ContractStatus, ContractStatusDetails, Deadline, Dispute, Experience, PendingReviewKind, ReviewerVote,
+              FeeStats, KycConfig, Multitasking, PauseCategory, PayoutTarget, PendingPayout, PlatformFeeRecipient, PlatformFeeShare, Reviewers, ReviewersParams, Role, ROLES, Subtask, TeamMember, TokenDetails,
+              ValidatorsDao, ValidatorsDaoDetails, ValidatorsDaoDetailsParams, ValidatorsDaoParams,
+              WhitelistType, GAS_FOR_AFTER_CHECK_DISPUTE, MAX_PLATFORM_FEE_PAYOUTS};
 
   pub const TOKEN_DECIMALS: u8 = 18;
   pub const MAX_DEADLINE: U64 = U64(1_000_000_000 * 60 * 60 * 24 * 7);
@@ -1435,6 +2146,7 @@ mod tests {
     allow_deadline_stretch: Option<bool>,
   ) -> BountyIndex {
     let bounty_index: BountyIndex = 0;
+    let dao_fee_percentage = validators_dao.as_ref().map(|_| Config::default().validators_dao_fee_percentage);
     let bounty = Bounty {
       token: Some(get_token_id()),
       amount: U128(d(2_000, TOKEN_DECIMALS)),
@@ -1468,8 +2180,13 @@ mod tests {
       allow_deadline_stretch: allow_deadline_stretch.unwrap_or_default(),
       bounty_flow: BountyFlow::default(),
       allow_creating_many_claims: false,
+      moderation: None,
+      finalized_at: None,
+      dao_fee_percentage,
     };
-    contract.internal_update_bounty(&bounty_index, bounty.clone());
+    register_storage(contract, owner);
+    contract.internal_update_bounty_indexes(bounty_index, None, Some(&bounty.get_index_keys()));
+    contract.bounties.insert(&bounty_index, &bounty.clone().into());
     contract.account_bounties.insert(owner, &vec![bounty_index]);
     contract.last_bounty_id = bounty_index.clone() + 1;
     add_token(contract);
@@ -1478,6 +2195,37 @@ mod tests {
     bounty_index
   }
 
+  fn bounty_create_params(reviewers: Option<ReviewersParams>) -> BountyCreate {
+    BountyCreate {
+      metadata: BountyMetadata {
+        title: "test".to_string(),
+        description: "test".to_string(),
+        category: "Other".to_string(),
+        attachments: None,
+        experience: None,
+        tags: None,
+        acceptance_criteria: None,
+        contact_details: None,
+      },
+      deadline: Deadline::MaxDeadline { max_deadline: MAX_DEADLINE },
+      claimant_approval: ClaimantApproval::WithoutApproval,
+      reviewers,
+      kyc_config: Some(KycConfig::KycNotRequired),
+      postpaid: None,
+      multitasking: None,
+      allow_deadline_stretch: None,
+      bounty_flow: None,
+      allow_creating_many_claims: None,
+    }
+  }
+
+  fn register_storage(contract: &mut BountiesContract, account_id: &AccountId) {
+    contract.storage_balances.insert(account_id, &AccountStorage {
+      deposit: ONE_NEAR,
+      used_bytes: 0,
+    });
+  }
+
   fn add_token(
     contract: &mut BountiesContract,
   ) {
@@ -1485,9 +2233,34 @@ mod tests {
     contract.tokens.insert(&get_token_id(), &TokenDetails {
       enabled: true,
       min_amount_for_kyc: None,
+      min_amount_for_kyb: None,
+      symbol: Some("TEST".to_string()),
+      decimals: Some(TOKEN_DECIMALS),
+      min_bounty_amount: None,
+      max_bounty_amount: None,
+      min_slot_amount: None,
     });
   }
 
+  fn add_validators_dao(
+    contract: &mut BountiesContract,
+    dao_account_id: &AccountId,
+    dao_kind: DaoKind,
+  ) -> ValidatorsDaoDetails {
+    let dao_details = ValidatorsDaoDetailsParams {
+      fee_percentage: None,
+      add_proposal_bond: U128(10u128.pow(24)),
+      gas_for_add_proposal: None,
+      gas_for_claim_approval: None,
+      gas_for_claimant_approval: None,
+      dao_kind: Some(dao_kind),
+      categories: None,
+    }
+      .to_validators_dao_details(&contract.config.clone().to_config());
+    contract.validators_daos.insert(dao_account_id, &dao_details);
+    dao_details
+  }
+
   fn bounty_claim(
     context: &mut VMContextBuilder,
     contract: &mut BountiesContract,
@@ -1503,7 +2276,8 @@ mod tests {
     let deadline = Some(U64(1_000_000_000 * 60 * 60 * 24 * 2));
     let description = "Test description".to_string();
 
-    contract.bounty_claim(id, deadline, description.clone(), None);
+    register_storage(contract, receiver_id);
+    contract.bounty_claim(id, deadline, description.clone(), None, None);
 
     let bounty = contract.bounties.get(&id).unwrap().to_bounty();
     if bounty.is_validators_dao_used() && contract.is_approval_required(&bounty, &receiver_id) {
@@ -1513,6 +2287,7 @@ mod tests {
         None,
         deadline,
         description,
+        None,
         Some(U64(1)),
         None,
         None,
@@ -1842,7 +2617,13 @@ mod tests {
           get_token_id(),
           TokenDetails {
             enabled: true,
-            min_amount_for_kyc: None
+            min_amount_for_kyc: None,
+            min_amount_for_kyb: None,
+            symbol: Some("TEST".to_string()),
+            decimals: Some(TOKEN_DECIMALS),
+            min_bounty_amount: None,
+            max_bounty_amount: None,
+            min_slot_amount: None,
           }
         )
       ]
@@ -1876,8 +2657,16 @@ mod tests {
       penalty_validators_dao_fee_percentage: 500,
       use_owners_whitelist: true,
       max_due_date: None,
+      timelock_delay: None,
+      admin_approval_threshold: None,
+      archive_retention_period: None,
+      ft_storage_deposit: None,
     };
     contract.change_config(config_create.clone());
+    assert_eq!(contract.get_config().bounty_claim_bond, Config::default().bounty_claim_bond);
+    assert_eq!(contract.get_queued_changes().len(), 1);
+
+    execute_last_queued_change(&mut context, &mut contract);
     let config = contract.get_config();
     assert_eq!(config_create, ConfigCreate {
       bounty_claim_bond: config.bounty_claim_bond,
@@ -1889,6 +2678,10 @@ mod tests {
       penalty_validators_dao_fee_percentage: config.penalty_validators_dao_fee_percentage,
       use_owners_whitelist: config.use_owners_whitelist,
       max_due_date: None,
+      timelock_delay: None,
+      admin_approval_threshold: None,
+      archive_retention_period: None,
+      ft_storage_deposit: None,
     });
   }
 
@@ -1928,6 +2721,12 @@ mod tests {
         slot: None,
         bond: Some(DEFAULT_BOUNTY_CLAIM_BOND),
         claim_number: None,
+        team: None,
+        paid_team_members: None,
+        handover_to: None,
+        handover_accepted: false,
+        payout_target: None,
+        reviewer_votes: None,
       }
     );
     assert_eq!(get_claim_by_bounty_id(&contract, &id).owner, receiver_id);
@@ -1958,6 +2757,7 @@ mod tests {
       id,
       Some(U64(1_000_000_000 * 60 * 60 * 24 * 2)),
       "Test description".to_string(),
+      None,
       None
     );
   }
@@ -1987,6 +2787,7 @@ mod tests {
       id,
       Some(U64(1_000_000_000 * 60 * 60 * 24 * 2)),
       "Test description".to_string(),
+      None,
       None
     );
   }
@@ -2015,6 +2816,7 @@ mod tests {
       id,
       Some(U64(MAX_DEADLINE.0 + 1)),
       "Test description".to_string(),
+      None,
       None
     );
   }
@@ -2039,7 +2841,7 @@ mod tests {
       .predecessor_account_id(accounts(2))
       .attached_deposit(Config::default().bounty_claim_bond.0)
       .build());
-    contract.bounty_claim(id, None, "Test description".to_string(), None);
+    contract.bounty_claim(id, None, "Test description".to_string(), None, None);
   }
 
   #[test]
@@ -2066,6 +2868,7 @@ mod tests {
       id + 1,
       Some(MAX_DEADLINE),
       "Test description".to_string(),
+      None,
       None
     );
   }
@@ -2111,6 +2914,7 @@ mod tests {
     let id = add_bounty(&mut contract, &accounts(1), None, Some(true));
 
     let receiver_id = accounts(2);
+    register_storage(&mut contract, &receiver_id);
     testing_env!(context
       .predecessor_account_id(receiver_id.clone())
       .attached_deposit(Config::default().bounty_claim_bond.0)
@@ -2119,6 +2923,7 @@ mod tests {
       id,
       None, // without deadline
       "Test description".to_string(),
+      None,
       None
     );
 
@@ -2222,6 +3027,7 @@ mod tests {
     let id = add_bounty(&mut contract, &accounts(1), None, Some(true));
 
     let receiver_id = accounts(2);
+    register_storage(&mut contract, &receiver_id);
     testing_env!(context
       .predecessor_account_id(receiver_id.clone())
       .attached_deposit(Config::default().bounty_claim_bond.0)
@@ -2230,6 +3036,7 @@ mod tests {
       id,
       None, // without deadline
       "Test description".to_string(),
+      None,
       None
     );
 
@@ -2262,6 +3069,7 @@ mod tests {
     let id = add_bounty(&mut contract, &owner, None, None);
 
     let receiver_id = accounts(2);
+    register_storage(&mut contract, &receiver_id);
     testing_env!(context
       .predecessor_account_id(receiver_id.clone())
       .attached_deposit(Config::default().bounty_claim_bond.0)
@@ -2270,6 +3078,7 @@ mod tests {
       id,
       Some(MAX_DEADLINE),
       "Test description".to_string(),
+      None,
       None
     );
 
@@ -2320,6 +3129,7 @@ mod tests {
     let id = add_bounty(&mut contract, &owner, None, Some(true));
 
     let receiver_id = accounts(2);
+    register_storage(&mut contract, &receiver_id);
     testing_env!(context
       .predecessor_account_id(receiver_id.clone())
       .attached_deposit(Config::default().bounty_claim_bond.0)
@@ -2328,6 +3138,7 @@ mod tests {
       id,
       None,
       "Test description".to_string(),
+      None,
       None
     );
 
@@ -2356,6 +3167,7 @@ mod tests {
     let id = add_bounty(&mut contract, &owner, None, None);
 
     let receiver_id = accounts(2);
+    register_storage(&mut contract, &receiver_id);
     testing_env!(context
       .predecessor_account_id(receiver_id.clone())
       .attached_deposit(Config::default().bounty_claim_bond.0)
@@ -2364,6 +3176,7 @@ mod tests {
       id,
       Some(U64(1_000_000)),
       "Test description".to_string(),
+      None,
       None
     );
 
@@ -2549,12 +3362,18 @@ mod tests {
     let project_owner = accounts(1);
     let dao_params = ValidatorsDaoParams {
       account_id: "dao".parse().unwrap(),
-      add_proposal_bond: U128(10u128.pow(24)),
+      add_proposal_bond: None,
       gas_for_add_proposal: None,
       gas_for_claim_approval: None,
       gas_for_claimant_approval: None,
     };
-    let id = add_bounty(&mut contract, &project_owner, Some(dao_params.to_validators_dao()), None);
+    let dao_details = add_validators_dao(&mut contract, &dao_params.account_id, DaoKind::SputnikV2);
+    let id = add_bounty(
+      &mut contract,
+      &project_owner,
+      Some(dao_params.to_validators_dao(&dao_details)),
+      None
+    );
     let receiver_id = accounts(2);
     bounty_claim(&mut context, &mut contract, id.clone(), &receiver_id);
     bounty_done(&mut context, &mut contract, id.clone(), &receiver_id);
@@ -2613,25 +3432,26 @@ mod tests {
     );
     contract.set_status(ContractStatus::Live);
     let project_owner = accounts(1);
+    let dao_details = add_validators_dao(&mut contract, &"dao".parse().unwrap(), DaoKind::SputnikV2);
     let id = add_bounty(
       &mut contract,
       &project_owner,
       Some(
         ValidatorsDaoParams {
           account_id: "dao".parse().unwrap(),
-          add_proposal_bond: U128(10u128.pow(24)),
+          add_proposal_bond: None,
           gas_for_add_proposal: None,
           gas_for_claim_approval: None,
           gas_for_claimant_approval: None,
         }
-          .to_validators_dao()
+          .to_validators_dao(&dao_details)
       ),
       None,
     );
 
     let new_dao_params = ValidatorsDaoParams {
       account_id: "dao".parse().unwrap(),
-      add_proposal_bond: U128(2 * 10u128.pow(24)),
+      add_proposal_bond: Some(U128(2 * 10u128.pow(24))),
       gas_for_add_proposal: Some(U64(50_000_000_000_000)),
       gas_for_claim_approval: Some(U64(50_000_000_000_000)),
       gas_for_claimant_approval: Some(U64(25_000_000_000_000)),
@@ -2642,15 +3462,77 @@ mod tests {
       .build());
     contract.update_validators_dao_params(id, new_dao_params.clone());
     assert_eq!(
-      contract.get_bounty(id.clone()).reviewers.unwrap(),
-      Reviewers::ValidatorsDao {validators_dao: new_dao_params.to_validators_dao()}
+      contract.internal_get_bounty(id.clone()).reviewers.unwrap(),
+      Reviewers::ValidatorsDao {validators_dao: new_dao_params.to_validators_dao(&dao_details)}
     );
   }
 
   #[test]
-  #[should_panic(expected = "Opening a dispute is not supported by this contract")]
-  fn test_open_dispute_without_dispute_support() {
-    let mut context = VMContextBuilder::new();
+  fn test_dao_adapters() {
+    let context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let current_account_id = accounts(0);
+
+    let (method, args) = SputnikV2Adapter.add_proposal_call(
+      "test".to_string(),
+      "bounty_approve",
+      b"{}".to_vec(),
+      U64(1),
+    );
+    assert_eq!(method, "add_proposal");
+    let args: serde_json::Value = serde_json::from_slice(&args).unwrap();
+    assert_eq!(
+      args["proposal"]["kind"]["FunctionCall"]["actions"][0]["method_name"],
+      "bounty_approve"
+    );
+    let proposal = SputnikV2Adapter.parse_proposal(serde_json::json!({
+      "id": 5,
+      "proposer": current_account_id,
+      "status": "Expired",
+    })).unwrap();
+    assert_eq!(proposal.id, 5);
+    assert_eq!(proposal.status, DaoProposalStatus::Expired);
+
+    let (method, args) = MultisigAdapter.add_proposal_call(
+      "test".to_string(),
+      "accept_claimant",
+      b"{}".to_vec(),
+      U64(1),
+    );
+    assert_eq!(method, "add_request");
+    let args: serde_json::Value = serde_json::from_slice(&args).unwrap();
+    assert_eq!(args["request"]["actions"][0]["type"], "FunctionCall");
+    assert_eq!(args["request"]["actions"][0]["method_name"], "accept_claimant");
+    assert_eq!(MultisigAdapter.get_proposal_call(U64(5)).0, "get_request");
+    let proposal = MultisigAdapter.parse_proposal(serde_json::json!({
+      "request_id": 5,
+      "proposer": current_account_id,
+      "status": "Executed",
+    })).unwrap();
+    assert_eq!(proposal.status, DaoProposalStatus::Approved);
+    assert!(MultisigAdapter.parse_proposal(serde_json::json!({"id": 5})).is_err());
+    assert!(MultisigAdapter.finalize_proposal_call(U64(5)).is_none());
+
+    let reviewers = Reviewers::ValidatorsDaoV1 {
+      validators_dao: ValidatorsDaoV1 {
+        account_id: "dao".parse().unwrap(),
+        add_proposal_bond: U128(1),
+        gas_for_add_proposal: U64(1),
+        gas_for_claim_approval: U64(1),
+        gas_for_claimant_approval: U64(1),
+      }
+    };
+    assert_eq!(reviewers.get_reviewer_accounts(), vec!["dao".parse::<AccountId>().unwrap()]);
+    match reviewers.upgrade_v1_to_v2() {
+      Reviewers::ValidatorsDao { validators_dao } =>
+        assert_eq!(validators_dao.dao_kind, DaoKind::SputnikV2),
+      _ => panic!("The reviewers were not upgraded"),
+    }
+  }
+
+  #[test]
+  fn test_access_rights_of_legacy_reviewers() {
+    let context = VMContextBuilder::new();
     testing_env!(context.build());
     let mut contract = BountiesContract::new(
       vec![accounts(0)],
@@ -2663,106 +3545,454 @@ mod tests {
     contract.set_status(ContractStatus::Live);
     let project_owner = accounts(1);
     let id = add_bounty(&mut contract, &project_owner, None, None);
+    let mut bounty = contract.internal_get_bounty(id);
+    bounty.reviewers = Some(Reviewers::MoreReviewersV1 { more_reviewers: vec![accounts(2)] });
+
+    assert!(bounty.try_access_rights(&accounts(2)).is_ok());
+    assert!(bounty.try_access_rights(&project_owner).is_ok());
+    assert_eq!(
+      bounty.try_access_rights(&accounts(3)),
+      Err("This method can only be called by one of the reviewers".to_string())
+    );
+  }
+
+  #[test]
+  fn test_multisig_validators_dao() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let dao_params = ValidatorsDaoParams {
+      account_id: "multisig".parse().unwrap(),
+      add_proposal_bond: Some(U128(0)),
+      gas_for_add_proposal: None,
+      gas_for_claim_approval: None,
+      gas_for_claimant_approval: None,
+    };
+    let dao_details = add_validators_dao(&mut contract, &dao_params.account_id, DaoKind::Multisig);
+    let id = add_bounty(
+      &mut contract,
+      &project_owner,
+      Some(dao_params.to_validators_dao(&dao_details)),
+      None
+    );
     let receiver_id = accounts(2);
     bounty_claim(&mut context, &mut contract, id.clone(), &receiver_id);
     bounty_done(&mut context, &mut contract, id.clone(), &receiver_id);
 
-    bounty_reject(
-      &mut context,
-      &mut contract,
-      id.clone(),
-      &project_owner,
-      &receiver_id,
-      None,
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(b"7".to_vec())]
+    );
+    assert!(contract.on_added_proposal_callback(Ok(7), id, receiver_id.clone(), None));
+    let (_, claim) = contract.internal_get_claims_by_bounty_id(id)[0].clone();
+    assert_eq!(claim.status, ClaimStatus::Completed);
+    assert_eq!(claim.bounty_payout_proposal_id, Some(U64(7)));
+
+    let request = serde_json::json!({
+      "request_id": 7,
+      "proposer": near_sdk::env::current_account_id(),
+      "status": "Rejected",
+    });
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(request.to_string().into_bytes())]
+    );
+    contract.after_check_bounty_payout_proposal(
+      Ok(request),
+      id,
+      Some((receiver_id.clone(), None)),
+      U64(7)
     );
+    let (_, claim) = contract.internal_get_claims_by_bounty_id(id)[0].clone();
+    // Without a dispute contract the rejected claim cannot be disputed
+    assert_eq!(claim.status, ClaimStatus::NotCompleted);
+  }
 
-    testing_env!(context
-      .predecessor_account_id(receiver_id)
-      .attached_deposit(1)
-      .build());
-    contract.open_dispute(id, "Test description".to_string(), None);
+  fn add_bounty_with_payout_proposal(
+    context: &mut VMContextBuilder,
+    contract: &mut BountiesContract,
+    project_owner: &AccountId,
+    receiver_id: &AccountId,
+  ) -> BountyIndex {
+    let dao_params = ValidatorsDaoParams {
+      account_id: "dao".parse().unwrap(),
+      add_proposal_bond: Some(U128(ONE_NEAR)),
+      gas_for_add_proposal: None,
+      gas_for_claim_approval: None,
+      gas_for_claimant_approval: None,
+    };
+    let dao_details = add_validators_dao(contract, &dao_params.account_id, DaoKind::SputnikV2);
+    let id = add_bounty(contract, project_owner, Some(dao_params.to_validators_dao(&dao_details)), None);
+    bounty_claim(context, contract, id.clone(), receiver_id);
+    bounty_done(context, contract, id.clone(), receiver_id);
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(b"7".to_vec())]
+    );
+    assert!(contract.on_added_proposal_callback(Ok(7), id, receiver_id.clone(), None));
+    id
+  }
+
+  fn check_stuck_proposal(
+    context: &mut VMContextBuilder,
+    contract: &mut BountiesContract,
+    id: BountyIndex,
+    receiver_id: &AccountId,
+    proposal_id: u64,
+    status: &str,
+  ) {
+    let proposal = serde_json::json!({
+      "id": proposal_id,
+      "proposer": near_sdk::env::current_account_id(),
+      "status": status,
+    });
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(proposal.to_string().into_bytes())]
+    );
+    contract.after_check_stuck_proposal(
+      Ok(proposal),
+      id,
+      receiver_id.clone(),
+      None,
+      U64(proposal_id),
+      receiver_id.clone(),
+      U128(ONE_NEAR)
+    );
   }
 
+
   #[test]
-  #[should_panic(expected = "Bounty status does not allow opening a dispute")]
-  fn test_open_dispute_with_incorrect_bounty_status() {
+  fn test_resubmit_expired_dao_proposal() {
     let mut context = VMContextBuilder::new();
     testing_env!(context.build());
     let mut contract = BountiesContract::new(
       vec![accounts(0)],
       None,
       None,
-      Some(get_disputes_contract()),
+      None,
       None,
       None
     );
     contract.set_status(ContractStatus::Live);
     let project_owner = accounts(1);
-    let id = add_bounty(&mut contract, &project_owner, None, None);
+    let receiver_id = accounts(2);
+    let id = add_bounty_with_payout_proposal(
+      &mut context,
+      &mut contract,
+      &project_owner,
+      &receiver_id
+    );
 
     testing_env!(context
-      .predecessor_account_id(accounts(2))
-      .attached_deposit(1)
+      .predecessor_account_id(receiver_id.clone())
+      .attached_deposit(ONE_NEAR)
       .build());
-    contract.open_dispute(id, "Test description".to_string(), None);
+    contract.resubmit_dao_proposal(id, receiver_id.clone(), None);
+
+    check_stuck_proposal(&mut context, &mut contract, id, &receiver_id, 7, "Expired");
+    let (_, claim) = contract.internal_get_claims_by_bounty_id(id)[0].clone();
+    assert_eq!(claim.status, ClaimStatus::Completed);
+    assert_eq!(claim.bounty_payout_proposal_id, None);
+
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(b"8".to_vec())]
+    );
+    assert!(contract.after_resubmit_proposal(
+      Ok(8),
+      id,
+      receiver_id.clone(),
+      None,
+      PendingReviewKind::ResultVerdict,
+      receiver_id.clone(),
+      U128(ONE_NEAR)
+    ));
+    let (_, claim) = contract.internal_get_claims_by_bounty_id(id)[0].clone();
+    assert_eq!(claim.bounty_payout_proposal_id, Some(U64(8)));
+    let dao_proposal = ("dao".parse().unwrap(), 8);
+    assert_eq!(contract.proposal_bonds.get(&dao_proposal), Some((receiver_id.clone(), U128(ONE_NEAR))));
+
+    // The bond of the expired resubmitted proposal is returned to its payer
+    check_stuck_proposal(&mut context, &mut contract, id, &receiver_id, 8, "Expired");
+    assert_eq!(contract.proposal_bonds.get(&dao_proposal), None);
+    let (_, claim) = contract.internal_get_claims_by_bounty_id(id)[0].clone();
+    assert_eq!(claim.bounty_payout_proposal_id, None);
   }
 
   #[test]
-  #[should_panic(expected = "No claimant found")]
-  fn test_open_dispute_by_other_user() {
+  fn test_after_resubmit_proposal_for_changed_claim() {
     let mut context = VMContextBuilder::new();
     testing_env!(context.build());
     let mut contract = BountiesContract::new(
       vec![accounts(0)],
       None,
       None,
-      Some(get_disputes_contract()),
+      None,
       None,
       None
     );
     contract.set_status(ContractStatus::Live);
     let project_owner = accounts(1);
-    let id = add_bounty(&mut contract, &project_owner, None, None);
     let receiver_id = accounts(2);
-    bounty_claim(&mut context, &mut contract, id.clone(), &receiver_id);
-    bounty_done(&mut context, &mut contract, id.clone(), &receiver_id);
-
-    bounty_reject(
+    let id = add_bounty_with_payout_proposal(
       &mut context,
       &mut contract,
-      id.clone(),
       &project_owner,
-      &receiver_id,
-      None,
+      &receiver_id
     );
-    assert_eq!(
-      get_claim_by_claimant_account_id(&contract, &receiver_id).status,
-      ClaimStatus::Rejected
+    check_stuck_proposal(&mut context, &mut contract, id, &receiver_id, 7, "Removed");
+    // Another proposal has been set while the resubmitted one was being created
+    contract.internal_set_proposal_id(
+      id,
+      receiver_id.clone(),
+      None,
+      PendingReviewKind::ResultVerdict,
+      Some(U64(9))
     );
 
-    testing_env!(context
-      .predecessor_account_id(accounts(3))
-      .attached_deposit(1)
-      .build());
-    contract.open_dispute(id, "Test description".to_string(), None);
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(b"8".to_vec())]
+    );
+    assert!(!contract.after_resubmit_proposal(
+      Ok(8),
+      id,
+      receiver_id.clone(),
+      None,
+      PendingReviewKind::ResultVerdict,
+      receiver_id.clone(),
+      U128(ONE_NEAR)
+    ));
+    let (_, claim) = contract.internal_get_claims_by_bounty_id(id)[0].clone();
+    assert_eq!(claim.bounty_payout_proposal_id, Some(U64(9)));
   }
 
   #[test]
-  #[should_panic(expected = "The claim status does not allow opening a dispute")]
-  fn test_open_dispute_with_incorrect_claim_status() {
+  #[should_panic(expected = "The attached deposit must be equal to the proposal bond of the validators DAO")]
+  fn test_resubmit_dao_proposal_without_bond() {
     let mut context = VMContextBuilder::new();
     testing_env!(context.build());
     let mut contract = BountiesContract::new(
       vec![accounts(0)],
       None,
       None,
-      Some(get_disputes_contract()),
+      None,
       None,
       None
     );
     contract.set_status(ContractStatus::Live);
     let project_owner = accounts(1);
-    let id = add_bounty(&mut contract, &project_owner, None, None);
+    let receiver_id = accounts(2);
+    let id = add_bounty_with_payout_proposal(
+      &mut context,
+      &mut contract,
+      &project_owner,
+      &receiver_id
+    );
+    testing_env!(context.predecessor_account_id(receiver_id.clone()).attached_deposit(0).build());
+    contract.resubmit_dao_proposal(id, receiver_id, None);
+  }
+
+  #[test]
+  fn test_resubmit_dao_proposal_in_progress() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let receiver_id = accounts(2);
+    let id = add_bounty_with_payout_proposal(
+      &mut context,
+      &mut contract,
+      &project_owner,
+      &receiver_id
+    );
+    check_stuck_proposal(&mut context, &mut contract, id, &receiver_id, 7, "InProgress");
+    assert!(get_logs().contains(
+      &"The proposal is not stuck, it can be processed by bounty_finalize".to_string()
+    ));
+    // The proposal is kept and the bond is returned to the caller
+    let (_, claim) = contract.internal_get_claims_by_bounty_id(id)[0].clone();
+    assert_eq!(claim.bounty_payout_proposal_id, Some(U64(7)));
+  }
+
+  #[test]
+  #[should_panic(expected = "Only the bounty owner or the claimant can call this method")]
+  fn test_resubmit_dao_proposal_by_other_account() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let receiver_id = accounts(2);
+    let id = add_bounty_with_payout_proposal(
+      &mut context,
+      &mut contract,
+      &project_owner,
+      &receiver_id
+    );
+    testing_env!(context.predecessor_account_id(accounts(3)).build());
+    contract.resubmit_dao_proposal(id, receiver_id, None);
+  }
+
+  #[test]
+  #[should_panic(expected = "Opening a dispute is not supported by this contract")]
+  fn test_open_dispute_without_dispute_support() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id.clone(), &receiver_id);
+    bounty_done(&mut context, &mut contract, id.clone(), &receiver_id);
+
+    bounty_reject(
+      &mut context,
+      &mut contract,
+      id.clone(),
+      &project_owner,
+      &receiver_id,
+      None,
+    );
+
+    testing_env!(context
+      .predecessor_account_id(receiver_id)
+      .attached_deposit(1)
+      .build());
+    contract.open_dispute(id, "Test description".to_string(), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "Bounty status does not allow opening a dispute")]
+  fn test_open_dispute_with_incorrect_bounty_status() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      Some(get_disputes_contract()),
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(1)
+      .build());
+    contract.open_dispute(id, "Test description".to_string(), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "No claimant found")]
+  fn test_open_dispute_by_other_user() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      Some(get_disputes_contract()),
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id.clone(), &receiver_id);
+    bounty_done(&mut context, &mut contract, id.clone(), &receiver_id);
+
+    bounty_reject(
+      &mut context,
+      &mut contract,
+      id.clone(),
+      &project_owner,
+      &receiver_id,
+      None,
+    );
+    assert_eq!(
+      get_claim_by_claimant_account_id(&contract, &receiver_id).status,
+      ClaimStatus::Rejected
+    );
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.open_dispute(id, "Test description".to_string(), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "The claim status does not allow opening a dispute")]
+  fn test_open_dispute_with_incorrect_claim_status() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      Some(get_disputes_contract()),
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
     let receiver_id = accounts(2);
     bounty_claim(&mut context, &mut contract, id.clone(), &receiver_id);
     bounty_done(&mut context, &mut contract, id.clone(), &receiver_id);
@@ -2804,7 +4034,12 @@ mod tests {
       penalty_validators_dao_fee_percentage: config.penalty_validators_dao_fee_percentage,
       use_owners_whitelist: config.use_owners_whitelist,
       max_due_date: None,
+      timelock_delay: None,
+      admin_approval_threshold: None,
+      archive_retention_period: None,
+      ft_storage_deposit: None,
     });
+    execute_last_queued_change(&mut context, &mut contract);
 
     let project_owner = accounts(1);
     let id = add_bounty(&mut contract, &project_owner, None, None);
@@ -2831,4 +4066,2497 @@ mod tests {
       .build());
     contract.open_dispute(id, "Test description".to_string(), None);
   }
+
+  #[test]
+  fn test_distribute_platform_fee() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .attached_deposit(1)
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let bounty = contract.internal_get_bounty(id);
+    contract.internal_total_fees_unlocking_funds(&bounty, None, None);
+
+    contract.change_platform_fee_recipients(vec![
+      PlatformFeeRecipient { account_id: accounts(3), weight: 1 },
+      PlatformFeeRecipient { account_id: accounts(4), weight: 3 },
+    ]);
+    assert!(contract.get_platform_fee_recipients().is_empty());
+    execute_last_queued_change(&mut context, &mut contract);
+    assert_eq!(contract.get_platform_fee_recipients().len(), 2);
+    contract.distribute_platform_fee(None);
+    assert_eq!(
+      contract.get_platform_fee_shares(get_token_id()),
+      [
+        PlatformFeeShare { account_id: accounts(3), amount: U128(d(50, TOKEN_DECIMALS)), in_progress: true },
+        PlatformFeeShare { account_id: accounts(4), amount: U128(d(150, TOKEN_DECIMALS)), in_progress: true },
+      ]
+    );
+    assert_eq!(contract.internal_get_unlocked_platform_fee_amount(get_token_id()).0, 0);
+
+    contract.internal_platform_fee_share_completion(
+      get_token_id(),
+      accounts(3),
+      U128(d(50, TOKEN_DECIMALS)),
+      true
+    );
+    contract.internal_platform_fee_share_completion(
+      get_token_id(),
+      accounts(4),
+      U128(d(150, TOKEN_DECIMALS)),
+      false
+    );
+    assert_eq!(
+      contract.get_platform_fee_shares(get_token_id()),
+      [
+        PlatformFeeShare { account_id: accounts(4), amount: U128(d(150, TOKEN_DECIMALS)), in_progress: false },
+      ]
+    );
+    let total_fees = contract.get_total_fees(get_token_id());
+    assert_eq!(total_fees.amount_withdrawn.0, d(50, TOKEN_DECIMALS));
+    assert_eq!(total_fees.balance.0, d(150, TOKEN_DECIMALS));
+  }
+
+  #[test]
+  fn test_distribute_platform_fee_in_several_calls() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .attached_deposit(1)
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let bounty = contract.internal_get_bounty(id);
+    contract.internal_total_fees_unlocking_funds(&bounty, None, None);
+
+    let recipients_count = MAX_PLATFORM_FEE_PAYOUTS + 2;
+    contract.change_platform_fee_recipients(
+      (0..recipients_count)
+        .map(|i| PlatformFeeRecipient {
+          account_id: format!("recipient{}.near", i).parse().unwrap(),
+          weight: 1
+        })
+        .collect()
+    );
+    execute_last_queued_change(&mut context, &mut contract);
+    let in_progress_count = |contract: &BountiesContract| contract
+      .get_platform_fee_shares(get_token_id())
+      .iter()
+      .filter(|s| s.in_progress)
+      .count();
+
+    // The transfers of one call fit into the default prepaid gas
+    testing_env!(context.build());
+    contract.distribute_platform_fee(None);
+    assert_eq!(contract.get_platform_fee_shares(get_token_id()).len(), recipients_count);
+    assert_eq!(in_progress_count(&contract), MAX_PLATFORM_FEE_PAYOUTS);
+    testing_env!(context.build());
+    contract.distribute_platform_fee(None);
+    assert_eq!(in_progress_count(&contract), recipients_count);
+  }
+
+  #[test]
+  fn test_grant_and_revoke_role() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    add_token(&mut contract);
+    contract.grant_role(Role::TokenManager, accounts(1));
+    assert_eq!(contract.get_role_holders(Role::TokenManager), [accounts(1)]);
+    assert_eq!(contract.get_account_roles(accounts(1)), [Role::TokenManager]);
+    assert_eq!(contract.get_account_roles(accounts(0)).len(), ROLES.len());
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .build());
+    contract.update_token(get_token_id(), TokenDetails {
+      enabled: false,
+      min_amount_for_kyc: None,
+      min_amount_for_kyb: None,
+      symbol: None,
+      decimals: None,
+      min_bounty_amount: None,
+      max_bounty_amount: None,
+      min_slot_amount: None,
+    });
+    assert!(!contract.tokens.get(&get_token_id()).unwrap().enabled);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    contract.revoke_role(Role::TokenManager, accounts(1));
+    assert!(contract.get_role_holders(Role::TokenManager).is_empty());
+  }
+
+  #[test]
+  #[should_panic(expected = "The account does not have the ConfigManager role")]
+  fn test_change_config_without_role() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    contract.grant_role(Role::TokenManager, accounts(1));
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .build());
+    contract.update_kyc_whitelist_contract(None);
+  }
+
+  #[test]
+  fn test_moderate_bounty() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    contract.grant_role(Role::Moderator, accounts(3));
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .build());
+    contract.moderate_bounty(id, true, false, "Spam".to_string());
+    assert!(contract.get_bounties(None, None).is_empty());
+    let bounty = contract.internal_get_bounty(id);
+    assert!(bounty.is_hidden());
+    assert_eq!(bounty.moderation.unwrap().reason, "Spam".to_string());
+
+    contract.moderate_bounty(id, false, false, "Resolved".to_string());
+    assert_eq!(contract.get_bounties(None, None).len(), 1);
+  }
+
+  #[test]
+  #[should_panic(expected = "The bounty is frozen by the moderator")]
+  fn test_bounty_claim_for_frozen_bounty() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    contract.moderate_bounty(id, false, true, "Under investigation".to_string());
+
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+  }
+
+  #[test]
+  fn test_force_cancel_bounty() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+    assert_eq!(contract.locked_amount, DEFAULT_BOUNTY_CLAIM_BOND.0);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .attached_deposit(0)
+      .build());
+    contract.force_cancel_bounty(id, "Fraud".to_string());
+
+    let claim = get_claim_by_claimant_account_id(&contract, &receiver_id);
+    assert_eq!(claim.status, ClaimStatus::Canceled);
+    assert_eq!(contract.locked_amount, 0);
+    let bounty = contract.internal_get_bounty(id);
+    assert!(bounty.is_frozen());
+    assert_eq!(bounty.status, BountyStatus::New);
+
+    // The bounty amount has been returned to the owner
+    contract.internal_bounty_cancellation(id, bounty);
+    assert_eq!(contract.internal_get_bounty(id).status, BountyStatus::Canceled);
+  }
+
+  #[test]
+  fn test_force_cancel_bounty_with_disputed_claim() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      Some(get_disputes_contract()),
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+    bounty_done(&mut context, &mut contract, id, &receiver_id);
+    bounty_reject(&mut context, &mut contract, id, &accounts(1), &receiver_id, None);
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(b"0".to_vec())]
+    );
+    assert!(contract.after_create_dispute(Ok(0), id, &receiver_id, None));
+
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .attached_deposit(0)
+      .build());
+    contract.force_cancel_bounty(id, "Fraud".to_string());
+    // The disputed claim is left to the dispute contract
+    let claim = get_claim_by_claimant_account_id(&contract, &receiver_id);
+    assert_eq!(claim.status, ClaimStatus::Disputed);
+    let bounty = contract.internal_get_bounty(id);
+    assert!(bounty.is_frozen());
+    assert_eq!(bounty.status, BountyStatus::Claimed);
+
+    testing_env!(context
+      .predecessor_account_id(get_disputes_contract())
+      .build());
+    contract.dispute_result(id, receiver_id.clone(), None, false);
+    let claim = get_claim_by_claimant_account_id(&contract, &receiver_id);
+    assert_eq!(claim.status, ClaimStatus::NotCompleted);
+    assert_eq!(contract.internal_get_bounty(id).status, BountyStatus::New);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    contract.force_cancel_bounty(id, "Fraud".to_string());
+    assert_eq!(contract.internal_get_bounty(id).status, BountyStatus::New);
+  }
+
+  #[test]
+  #[should_panic(expected = "The ClaimCreation operations are paused")]
+  fn test_bounty_claim_when_claim_creation_is_paused() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    contract.set_pause_flag(PauseCategory::ClaimCreation, true);
+    contract.set_pause_flag(PauseCategory::Disputes, true);
+    contract.set_pause_flag(PauseCategory::Disputes, false);
+    assert_eq!(contract.get_status(), ContractStatusDetails {
+      status: ContractStatus::Live,
+      paused_categories: vec![PauseCategory::ClaimCreation],
+    });
+
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+  }
+
+  #[test]
+  fn test_dispute_result_when_disputes_are_paused() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      Some(get_disputes_contract()),
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+    bounty_done(&mut context, &mut contract, id, &receiver_id);
+    bounty_reject(&mut context, &mut contract, id, &accounts(1), &receiver_id, None);
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(b"0".to_vec())]
+    );
+    assert!(contract.after_create_dispute(Ok(0), id, &receiver_id, None));
+    contract.set_pause_flag(PauseCategory::Disputes, true);
+
+    // The disputes that have already been opened are completed
+    testing_env!(context
+      .predecessor_account_id(get_disputes_contract())
+      .attached_deposit(0)
+      .build());
+    contract.dispute_result(id, receiver_id.clone(), None, false);
+    let claim = get_claim_by_claimant_account_id(&contract, &receiver_id);
+    assert_eq!(claim.status, ClaimStatus::NotCompleted);
+  }
+
+  #[test]
+  fn test_timelocked_change() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .block_timestamp(0)
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      Some(get_disputes_contract()),
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let config = Config::default();
+    contract.change_config(ConfigCreate {
+      bounty_claim_bond: config.bounty_claim_bond,
+      period_for_opening_dispute: config.period_for_opening_dispute,
+      bounty_forgiveness_period: config.bounty_forgiveness_period,
+      platform_fee_percentage: config.platform_fee_percentage,
+      validators_dao_fee_percentage: config.validators_dao_fee_percentage,
+      penalty_platform_fee_percentage: config.penalty_platform_fee_percentage,
+      penalty_validators_dao_fee_percentage: config.penalty_validators_dao_fee_percentage,
+      use_owners_whitelist: config.use_owners_whitelist,
+      max_due_date: None,
+      timelock_delay: Some(U64(100)),
+      admin_approval_threshold: None,
+      archive_retention_period: None,
+      ft_storage_deposit: None,
+    });
+    assert_eq!(contract.get_config().timelock_delay, DEFAULT_TIMELOCK_DELAY);
+    assert_eq!(contract.get_queued_changes()[0].1.executable_at, DEFAULT_TIMELOCK_DELAY);
+    execute_last_queued_change(&mut context, &mut contract);
+    assert_eq!(contract.get_config().timelock_delay, U64(100));
+
+    contract.update_dispute_contract(accounts(4));
+    contract.update_kyc_whitelist_contract(Some(accounts(5)));
+    assert_eq!(contract.dispute_contract, Some(get_disputes_contract()));
+    let changes = contract.get_queued_changes();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].1.executable_at, U64(DEFAULT_TIMELOCK_DELAY.0 + 100));
+
+    contract.cancel_queued_change(changes[1].0);
+    assert_eq!(contract.get_queued_changes().len(), 1);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .block_timestamp(DEFAULT_TIMELOCK_DELAY.0 + 100)
+      .build());
+    contract.execute_queued_change(changes[0].0);
+    assert_eq!(contract.dispute_contract, Some(accounts(4)));
+    assert_eq!(contract.kyc_whitelist_contract, None);
+    assert!(contract.get_queued_changes().is_empty());
+  }
+
+  #[test]
+  #[should_panic(expected = "The timelock delay has not expired yet")]
+  fn test_execute_queued_change_before_delay() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .block_timestamp(0)
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    contract.config.to_config_mut().timelock_delay = U64(100);
+    contract.change_recipient_of_platform_fee(accounts(4));
+
+    testing_env!(context
+      .block_timestamp(99)
+      .build());
+    contract.execute_queued_change(0);
+  }
+
+  /// Executes the last queued change once the timelock delay has expired.
+  fn execute_last_queued_change(context: &mut VMContextBuilder, contract: &mut BountiesContract) {
+    let (change_id, change) = contract.get_queued_changes().pop().unwrap();
+    testing_env!(context.block_timestamp(change.executable_at.0).build());
+    contract.execute_queued_change(change_id);
+  }
+
+  fn set_admin_approval_threshold(
+    context: &mut VMContextBuilder,
+    contract: &mut BountiesContract,
+    threshold: u16,
+  ) {
+    let config = Config::default();
+    contract.change_config(ConfigCreate {
+      bounty_claim_bond: config.bounty_claim_bond,
+      period_for_opening_dispute: config.period_for_opening_dispute,
+      bounty_forgiveness_period: config.bounty_forgiveness_period,
+      platform_fee_percentage: config.platform_fee_percentage,
+      validators_dao_fee_percentage: config.validators_dao_fee_percentage,
+      penalty_platform_fee_percentage: config.penalty_platform_fee_percentage,
+      penalty_validators_dao_fee_percentage: config.penalty_validators_dao_fee_percentage,
+      use_owners_whitelist: config.use_owners_whitelist,
+      max_due_date: None,
+      timelock_delay: None,
+      admin_approval_threshold: Some(threshold),
+      archive_retention_period: None,
+      ft_storage_deposit: None,
+    });
+    execute_last_queued_change(context, contract);
+  }
+
+  #[test]
+  fn test_admin_action_confirmations() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into(), accounts(1).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    set_admin_approval_threshold(&mut context, &mut contract, 2);
+    assert_eq!(contract.get_config().admin_approval_threshold, 2);
+
+    contract.add_to_some_whitelist(Some(accounts(2)), None, WhitelistType::OwnersWhitelist);
+    assert!(!contract.owners_whitelist.contains(&accounts(2)));
+    let actions = contract.get_pending_admin_actions();
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0].1.confirmations, [accounts(0)]);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .attached_deposit(1)
+      .build());
+    contract.confirm_admin_action(0);
+    assert!(contract.owners_whitelist.contains(&accounts(2)));
+    assert!(contract.get_pending_admin_actions().is_empty());
+  }
+
+  #[test]
+  #[should_panic(expected = "The action has already been confirmed by this account")]
+  fn test_confirm_admin_action_twice() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into(), accounts(1).into(), accounts(2).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    set_admin_approval_threshold(&mut context, &mut contract, 2);
+    contract.remove_from_some_whitelist(Some(accounts(1)), None, WhitelistType::AdministratorsWhitelist);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .attached_deposit(1)
+      .build());
+    contract.confirm_admin_action(0);
+  }
+
+  #[test]
+  fn test_grant_role_with_confirmations() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into(), accounts(1).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    set_admin_approval_threshold(&mut context, &mut contract, 2);
+
+    contract.grant_role(Role::ConfigManager, accounts(3));
+    assert!(contract.get_role_holders(Role::ConfigManager).is_empty());
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .attached_deposit(1)
+      .build());
+    contract.confirm_admin_action(0);
+    assert_eq!(contract.get_role_holders(Role::ConfigManager), [accounts(3)]);
+  }
+
+  #[test]
+  #[should_panic(expected = "Not in admin whitelist")]
+  fn test_confirm_admin_action_by_role_holder() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into(), accounts(1).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    contract.grant_role(Role::WhitelistManager, accounts(3));
+    set_admin_approval_threshold(&mut context, &mut contract, 2);
+    contract.add_to_some_whitelist(Some(accounts(2)), None, WhitelistType::OwnersWhitelist);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.confirm_admin_action(0);
+  }
+
+  #[test]
+  #[should_panic(expected = "The admin approval threshold cannot exceed the number of admins")]
+  fn test_admin_approval_threshold_above_admins_count() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into(), accounts(1).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    set_admin_approval_threshold(&mut context, &mut contract, 3);
+  }
+
+  #[test]
+  #[should_panic(expected = "The admin approval threshold cannot exceed the number of admins")]
+  fn test_remove_admins_below_threshold() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into(), accounts(1).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    set_admin_approval_threshold(&mut context, &mut contract, 2);
+    contract.remove_from_some_whitelist(Some(accounts(1)), None, WhitelistType::AdministratorsWhitelist);
+  }
+
+  #[test]
+  fn test_stage_and_deploy_code() {
+    let context = VMContextBuilder::new()
+      .predecessor_account_id(accounts(0))
+      .build();
+    testing_env!(context.clone());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+
+    let code = b"new contract code".to_vec();
+    testing_env!(VMContext { input: code.clone(), ..context.clone() });
+    let code_hash = contract.stage_code();
+    assert_eq!(code_hash, near_sdk::env::sha256_array(&code).into());
+    let staged_code = contract.get_staged_code().unwrap();
+    assert_eq!(staged_code.code_hash, code_hash);
+    // The timelock delay is not set, the minimum upgrade delay is used
+    assert_eq!(staged_code.executable_at, U64(contract_upgrade::MIN_UPGRADE_DELAY));
+
+    testing_env!(VMContext { block_timestamp: contract_upgrade::MIN_UPGRADE_DELAY, ..context });
+    contract.deploy_staged_code(code_hash, None);
+    assert!(contract.get_staged_code().is_none());
+  }
+
+  #[test]
+  #[should_panic(expected = "The upgrade delay has not expired yet")]
+  fn test_deploy_staged_code_before_upgrade_delay() {
+    let context = VMContextBuilder::new()
+      .predecessor_account_id(accounts(0))
+      .build();
+    testing_env!(context.clone());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+
+    testing_env!(VMContext { input: b"new contract code".to_vec(), ..context.clone() });
+    let code_hash = contract.stage_code();
+
+    testing_env!(context);
+    contract.deploy_staged_code(code_hash, None);
+  }
+
+  #[test]
+  fn test_stage_code_with_confirmations() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into(), accounts(1).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    set_admin_approval_threshold(&mut context, &mut contract, 2);
+
+    let context = context.build();
+    testing_env!(VMContext { input: b"new contract code".to_vec(), ..context.clone() });
+    let code_hash = contract.stage_code();
+    assert!(contract.get_staged_code().is_none());
+    let actions = contract.get_pending_admin_actions();
+    assert_eq!(actions[0].1.action, AdminAction::StageCode { code_hash });
+
+    testing_env!(VMContext {
+      predecessor_account_id: accounts(1),
+      attached_deposit: 1,
+      ..context
+    });
+    contract.confirm_admin_action(0);
+    assert_eq!(contract.get_staged_code().unwrap().code_hash, code_hash);
+  }
+
+  #[test]
+  #[should_panic(expected = "The hash of the staged code does not match")]
+  fn test_deploy_staged_code_with_wrong_hash() {
+    let context = VMContextBuilder::new()
+      .predecessor_account_id(accounts(0))
+      .build();
+    testing_env!(context.clone());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+
+    testing_env!(VMContext { input: b"new contract code".to_vec(), ..context.clone() });
+    contract.stage_code();
+
+    testing_env!(context);
+    contract.deploy_staged_code(near_sdk::env::sha256_array(b"other code").into(), None);
+  }
+
+  #[test]
+  fn test_migrate_bounties() {
+    let context = VMContextBuilder::new()
+      .predecessor_account_id(accounts(0))
+      .build();
+    testing_env!(context);
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    add_bounty(&mut contract, &accounts(1), None, None);
+    contract.last_bounty_id = 3;
+    contract.migration_cursor = Some(0);
+
+    contract.migrate_bounties(2);
+    assert_eq!(contract.get_migration_cursor(), Some(2));
+    contract.migrate_bounties(2);
+    assert_eq!(contract.get_migration_cursor(), None);
+    assert_eq!(contract.internal_get_bounty(0).status, BountyStatus::New);
+  }
+
+  #[test]
+  fn test_storage_deposit_and_withdraw() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(ONE_NEAR)
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let min_balance = contract.storage_balance_bounds().min.0;
+
+    let balance = contract.storage_deposit(None, None);
+    assert_eq!(balance.total.0, ONE_NEAR);
+    assert_eq!(balance.available.0, ONE_NEAR - min_balance);
+
+    testing_env!(context
+      .attached_deposit(1)
+      .build());
+    let balance = contract.storage_withdraw(Some(U128(ONE_NEAR / 2)));
+    assert_eq!(balance.total.0, ONE_NEAR / 2);
+    assert!(contract.storage_unregister(None));
+    assert!(contract.storage_balance_of(accounts(2)).is_none());
+  }
+
+  #[test]
+  #[should_panic(expected = "Not enough storage balance")]
+  fn test_bounty_claim_without_enough_storage_balance() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(contract.storage_balance_bounds().min.0)
+      .build());
+    contract.storage_deposit(None, Some(true));
+
+    testing_env!(context
+      .attached_deposit(Config::default().bounty_claim_bond.0)
+      .build());
+    contract.bounty_claim(
+      id,
+      Some(U64(1_000_000_000 * 60 * 60 * 24 * 2)),
+      "Test description".to_string(),
+      None,
+      None
+    );
+  }
+
+  #[test]
+  fn test_archive_bounties() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+    let (claim_id, _) = contract.internal_get_claims_by_bounty_id(id)[0].clone();
+
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .attached_deposit(0)
+      .build());
+    contract.force_cancel_bounty(id, "Fraud".to_string());
+    let bounty = contract.internal_get_bounty(id);
+    contract.internal_bounty_cancellation(id, bounty);
+    let bounty = contract.internal_get_bounty(id);
+    assert_eq!(bounty.finalized_at, Some(U64(0)));
+
+    // The retention period has not passed yet
+    assert!(contract.archive_bounties(vec![id]).is_empty());
+
+    let claimant_used_bytes = contract.storage_balances.get(&receiver_id).unwrap().used_bytes;
+    assert!(claimant_used_bytes > 0);
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .block_timestamp(DEFAULT_ARCHIVE_RETENTION_PERIOD.0)
+      .build());
+    assert_eq!(contract.archive_bounties(vec![id]), vec![id]);
+
+    assert!(contract.bounties.get(&id).is_none());
+    assert!(contract.claims.get(&claim_id).is_none());
+    assert!(contract.get_account_bounties(accounts(1)).is_empty());
+    assert!(contract.get_account_claims(receiver_id.clone(), None, None).is_empty());
+    assert!(contract.storage_balances.get(&receiver_id).unwrap().used_bytes < claimant_used_bytes);
+
+    let summary = contract.get_archived_bounty(id).unwrap();
+    assert_eq!(summary.status, BountyStatus::Canceled);
+    assert_eq!(summary.claims, vec![claim_id]);
+    assert_eq!(contract.get_bounty(id), BountyRecord::Archived(summary));
+    let claim_summary = contract.get_archived_claim(claim_id).unwrap();
+    assert_eq!(claim_summary.status, ClaimStatus::Canceled);
+    assert_eq!(contract.get_bounty_claim(claim_id), ClaimRecord::Archived(claim_summary));
+  }
+
+  #[test]
+  fn test_get_bounties_filtered() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0).into()],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let mut bounty = contract.internal_get_bounty(id);
+    bounty.metadata.category = "Development".to_string();
+    bounty.metadata.tags = Some(vec!["Rust".to_string(), "NFT".to_string()]);
+    bounty.metadata.experience = Some(Experience::Advanced);
+    let rust_bounty_id = contract.internal_add_bounty(bounty);
+
+    let filter = BountyFilter {
+      statuses: Some(vec![BountyStatus::New]),
+      tags: Some(vec!["Rust".to_string()]),
+      token: Some(get_token_id()),
+      ..BountyFilter::default()
+    };
+    let bounties = contract.get_bounties_filtered(filter.clone(), None, None);
+    assert_eq!(bounties.len(), 1);
+    assert_eq!(bounties[0].0, rust_bounty_id);
+    assert_eq!(
+      contract.get_bounties_filtered(
+        BountyFilter {
+          category: Some("Other".to_string()),
+          ..BountyFilter::default()
+        },
+        None,
+        None
+      )[0].0,
+      id
+    );
+    assert_eq!(contract.get_bounties_filtered(BountyFilter::default(), Some(1), None).len(), 1);
+    // Only `limit` of the bounties of the index entry are checked
+    let filter_advanced = BountyFilter {
+      statuses: Some(vec![BountyStatus::New]),
+      experience: Some(Experience::Advanced),
+      ..BountyFilter::default()
+    };
+    assert!(contract.get_bounties_filtered(filter_advanced.clone(), None, Some(1)).is_empty());
+    assert_eq!(
+      contract.get_bounties_filtered(filter_advanced, Some(1), Some(1))[0].0,
+      rust_bounty_id
+    );
+
+    let mut bounty = contract.internal_get_bounty(rust_bounty_id);
+    contract.internal_change_status_and_save_bounty(
+      &rust_bounty_id,
+      &mut bounty,
+      BountyStatus::Claimed
+    );
+    assert!(contract.get_bounties_filtered(filter, None, None).is_empty());
+    assert_eq!(contract.bounties_by_status.get(&BountyStatus::New).unwrap().to_vec(), vec![id]);
+    assert_eq!(
+      contract.bounties_by_status.get(&BountyStatus::Claimed).unwrap().to_vec(),
+      vec![rust_bounty_id]
+    );
+  }
+
+  #[test]
+  fn test_get_pending_reviews() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let reviewer = accounts(3);
+    let mut bounty = contract.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    bounty.reviewers = Some(Reviewers::MoreReviewers {
+      more_reviewers: vec![reviewer.clone()],
+      threshold: 1,
+    });
+    contract.internal_update_bounty(&id, bounty, &old_keys);
+    assert_eq!(contract.get_reviewer_bounties(reviewer.clone(), None, None)[0].0, id);
+
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+    assert!(contract.get_pending_reviews(reviewer.clone(), None, None).is_empty());
+
+    bounty_done(&mut context, &mut contract, id, &receiver_id);
+    let pending_reviews = contract.get_pending_reviews(reviewer.clone(), None, None);
+    assert_eq!(pending_reviews.len(), 1);
+    assert_eq!(pending_reviews[0].bounty_id, id);
+    assert_eq!(pending_reviews[0].kind, PendingReviewKind::ResultVerdict);
+    assert_eq!(pending_reviews[0].proposal_id, None);
+
+    let next_id = contract.internal_add_bounty(contract.internal_get_bounty(id));
+    assert_eq!(contract.get_reviewer_bounties(reviewer.clone(), None, None).len(), 2);
+    let next_page = contract.get_reviewer_bounties(reviewer.clone(), Some(1), Some(1));
+    assert_eq!(next_page.len(), 1);
+    assert_eq!(next_page[0].0, next_id);
+    assert_eq!(contract.get_pending_reviews(reviewer.clone(), None, Some(1)).len(), 1);
+    assert!(contract.get_pending_reviews(reviewer.clone(), Some(1), None).is_empty());
+
+    let mut bounty = contract.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    bounty.reviewers = None;
+    contract.internal_update_bounty(&id, bounty, &old_keys);
+    assert_eq!(contract.get_reviewer_bounties(reviewer.clone(), None, None)[0].0, next_id);
+    assert!(contract.get_pending_reviews(reviewer, None, None).is_empty());
+  }
+
+  fn set_more_reviewers(
+    contract: &mut BountiesContract,
+    id: BountyIndex,
+    more_reviewers: Vec<AccountId>,
+    threshold: u8,
+  ) {
+    let mut bounty = contract.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    bounty.reviewers = Some(Reviewers::MoreReviewers { more_reviewers, threshold });
+    contract.internal_update_bounty(&id, bounty, &old_keys);
+  }
+
+  #[test]
+  fn test_more_reviewers_threshold() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
+    set_more_reviewers(&mut contract, id, vec![accounts(3), accounts(4), accounts(5)], 2);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+    bounty_done(&mut context, &mut contract, id, &receiver_id);
+
+    bounty_approve(&mut context, &mut contract, id, &accounts(3), &receiver_id, None);
+    // The repeated vote of the same reviewer is not counted
+    bounty_approve(&mut context, &mut contract, id, &accounts(3), &receiver_id, None);
+    let (_, claim) = contract.get_claims_by_bounty_id(id, None, None)[0].clone();
+    assert_eq!(claim.status, ClaimStatus::Completed);
+    assert_eq!(
+      claim.reviewer_votes,
+      Some(vec![ReviewerVote {
+        reviewer: accounts(3),
+        kind: PendingReviewKind::ResultVerdict,
+        approve: true,
+        place: None,
+      }])
+    );
+
+    bounty_reject(&mut context, &mut contract, id, &accounts(4), &receiver_id, None);
+    let (_, claim) = contract.get_claims_by_bounty_id(id, None, None)[0].clone();
+    assert_eq!(claim.status, ClaimStatus::Completed);
+    assert_eq!(claim.reviewer_votes.unwrap().len(), 2);
+
+    bounty_reject(&mut context, &mut contract, id, &accounts(5), &receiver_id, None);
+    let (_, claim) = contract.get_claims_by_bounty_id(id, None, None)[0].clone();
+    // Without a dispute contract the rejected claim cannot be disputed
+    assert_eq!(claim.status, ClaimStatus::NotCompleted);
+    assert_eq!(claim.reviewer_votes, None);
+  }
+
+  #[test]
+  fn test_more_reviewers_threshold_for_claimant_approval() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
+    let mut bounty = contract.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    bounty.claimant_approval = ClaimantApproval::MultipleClaims;
+    contract.internal_update_bounty(&id, bounty, &old_keys);
+    set_more_reviewers(&mut contract, id, vec![accounts(3), accounts(4)], 2);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.accept_claimant(id, receiver_id.clone(), None, None);
+    testing_env!(context
+      .predecessor_account_id(accounts(4))
+      .attached_deposit(0)
+      .build());
+    contract.decline_claimant(id, receiver_id.clone(), None);
+    let (_, claim) = contract.get_claims_by_bounty_id(id, None, None)[0].clone();
+    assert_eq!(claim.status, ClaimStatus::New);
+    assert_eq!(claim.reviewer_votes.unwrap().len(), 2);
+
+    // The reviewer changes the vote
+    testing_env!(context
+      .predecessor_account_id(accounts(4))
+      .attached_deposit(1)
+      .build());
+    contract.accept_claimant(id, receiver_id.clone(), None, None);
+    let (_, claim) = contract.get_claims_by_bounty_id(id, None, None)[0].clone();
+    assert_eq!(claim.status, ClaimStatus::InProgress);
+    assert_eq!(claim.reviewer_votes, None);
+  }
+
+  #[test]
+  fn test_votes_of_removed_reviewers_not_counted() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
+    let mut bounty = contract.internal_get_bounty(id);
+    let old_keys = bounty.get_index_keys();
+    bounty.claimant_approval = ClaimantApproval::MultipleClaims;
+    contract.internal_update_bounty(&id, bounty, &old_keys);
+    set_more_reviewers(&mut contract, id, vec![accounts(3), accounts(4), accounts(5)], 2);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.accept_claimant(id, receiver_id.clone(), None, None);
+
+    testing_env!(context
+      .predecessor_account_id(project_owner.clone())
+      .attached_deposit(0)
+      .build());
+    contract.bounty_update(
+      id,
+      BountyUpdate {
+        metadata: None,
+        deadline: None,
+        claimant_approval: None,
+        reviewers: Some(ReviewersParams::MoreReviewers {
+          more_reviewers: vec![accounts(4), accounts(5)],
+          threshold: Some(2),
+        }),
+        amount: None,
+      }
+    );
+
+    testing_env!(context
+      .predecessor_account_id(accounts(4))
+      .attached_deposit(1)
+      .build());
+    contract.accept_claimant(id, receiver_id.clone(), None, None);
+    let (_, claim) = contract.get_claims_by_bounty_id(id, None, None)[0].clone();
+    assert_eq!(claim.status, ClaimStatus::New);
+    assert_eq!(
+      claim.reviewer_votes,
+      Some(vec![ReviewerVote {
+        reviewer: accounts(4),
+        kind: PendingReviewKind::ClaimantApproval,
+        approve: true,
+        place: None,
+      }])
+    );
+  }
+
+  #[test]
+  fn test_update_reviewers_threshold() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
+    set_more_reviewers(&mut contract, id, vec![accounts(3), accounts(4)], 1);
+
+    testing_env!(context.predecessor_account_id(project_owner).build());
+    contract.bounty_update(
+      id,
+      BountyUpdate {
+        metadata: None,
+        deadline: None,
+        claimant_approval: None,
+        reviewers: Some(ReviewersParams::MoreReviewers {
+          more_reviewers: vec![accounts(3), accounts(4)],
+          threshold: Some(2),
+        }),
+        amount: None,
+      }
+    );
+    assert_eq!(
+      contract.internal_get_bounty(id).reviewers,
+      Some(Reviewers::MoreReviewers { more_reviewers: vec![accounts(3), accounts(4)], threshold: 2 })
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "Not enough storage balance")]
+  fn test_reviewer_vote_without_owner_storage_balance() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let project_owner = accounts(1);
+    let id = add_bounty(&mut contract, &project_owner, None, None);
+    set_more_reviewers(&mut contract, id, vec![accounts(3), accounts(4)], 2);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+    bounty_done(&mut context, &mut contract, id, &receiver_id);
+
+    // The storage of the votes is paid by the bounty owner
+    contract.storage_balances.insert(&project_owner, &AccountStorage { deposit: 0, used_bytes: 0 });
+    bounty_approve(&mut context, &mut contract, id, &accounts(3), &receiver_id, None);
+  }
+
+  #[test]
+  #[should_panic(expected = "The threshold must be between one and the number of reviewers")]
+  fn test_more_reviewers_incorrect_threshold() {
+    let context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    set_more_reviewers(&mut contract, id, vec![accounts(3)], 2);
+    contract.internal_get_bounty(id).try_valid().unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  #[test]
+  fn test_get_available_actions() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    let receiver_id = accounts(2);
+    bounty_claim(&mut context, &mut contract, id, &receiver_id);
+
+    let get_blocked_reason = |contract: &BountiesContract, account_id: AccountId, method: &str| {
+      contract.get_available_actions(id, account_id, None)
+        .into_iter()
+        .find(|a| a.method == method)
+        .unwrap()
+        .blocked_reason
+    };
+    assert_eq!(get_blocked_reason(&contract, receiver_id.clone(), "bounty_done"), None);
+    assert_eq!(get_blocked_reason(&contract, receiver_id.clone(), "bounty_give_up"), None);
+    assert_eq!(
+      get_blocked_reason(&contract, receiver_id.clone(), "bounty_claim"),
+      Some("Bounty status does not allow to submit a claim".to_string())
+    );
+    assert_eq!(
+      get_blocked_reason(&contract, receiver_id.clone(), "bounty_update"),
+      Some("Only the owner of the bounty can call this method".to_string())
+    );
+    assert_eq!(get_blocked_reason(&contract, accounts(1), "bounty_update"), None);
+    assert_eq!(
+      get_blocked_reason(&contract, accounts(1), "bounty_cancel"),
+      Some("Bounty status does not allow cancellation".to_string())
+    );
+    assert_eq!(
+      get_blocked_reason(&contract, accounts(1), "bounty_approve"),
+      Some("There are no claims awaiting this action".to_string())
+    );
+
+    bounty_done(&mut context, &mut contract, id, &receiver_id);
+    assert_eq!(get_blocked_reason(&contract, accounts(1), "bounty_approve"), None);
+    assert_eq!(
+      get_blocked_reason(&contract, accounts(3), "bounty_approve"),
+      Some("Only the owner of the bounty can call this method".to_string())
+    );
+
+    contract.paused_categories.push(PauseCategory::Payouts);
+    assert_eq!(
+      get_blocked_reason(&contract, accounts(1), "bounty_approve"),
+      Some("The Payouts operations are paused".to_string())
+    );
+  }
+
+  #[test]
+  fn test_get_bounty_quote() {
+    let context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    add_token(&mut contract);
+    let mut bounty_create = bounty_create_params(None);
+
+    let amount = d(1_000, TOKEN_DECIMALS) + 7;
+    let quote = contract.get_bounty_quote(bounty_create.clone(), Some(get_token_id()), Some(U128(amount)));
+    assert_eq!(quote.error, None);
+    assert_eq!(quote.amount.0, amount);
+    assert_eq!(quote.total_amount.0, amount + quote.platform_fee.0 + quote.dao_fee.0);
+    assert_eq!(quote.payouts, vec![U128(amount)]);
+    let bounty = bounty_create.to_bounty(
+      &accounts(1),
+      Some(get_token_id()),
+      U128(quote.total_amount.0 - 1),
+      contract.config.clone().to_config(),
+      None,
+      None
+    );
+    assert!(bounty.amount.0 < amount);
+
+    bounty_create.multitasking = Some(Multitasking::OneForAll {
+      number_of_slots: 3,
+      amount_per_slot: U128(d(100, TOKEN_DECIMALS)),
+      min_slots_to_start: None,
+      runtime_env: None,
+    });
+    let quote = contract.get_bounty_quote(bounty_create.clone(), Some(get_token_id()), None);
+    assert_eq!(quote.error, None);
+    assert_eq!(quote.amount.0 + quote.platform_fee.0, d(300, TOKEN_DECIMALS));
+    assert_eq!(quote.payouts.len(), 3);
+    assert_eq!(quote.payouts.iter().map(|p| p.0).sum::<u128>(), quote.amount.0);
+
+    bounty_create.metadata.category = "Unknown".to_string();
+    let quote = contract.get_bounty_quote(bounty_create.clone(), Some(get_token_id()), None);
+    assert_eq!(quote.error, Some("Invalid bounty type Unknown".to_string()));
+
+    bounty_create.multitasking = None;
+    let quote = contract.get_bounty_quote(bounty_create, Some(accounts(3)), None);
+    assert_eq!(quote.error, Some("The bounty amount is not specified".to_string()));
+  }
+
+  #[test]
+  fn test_token_bounty_limits() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    add_token(&mut contract);
+    contract.update_token(get_token_id(), TokenDetails {
+      enabled: true,
+      min_amount_for_kyc: None,
+      min_amount_for_kyb: None,
+      symbol: None,
+      decimals: None,
+      min_bounty_amount: Some(U128(d(100, TOKEN_DECIMALS))),
+      max_bounty_amount: Some(U128(d(10_000, TOKEN_DECIMALS))),
+      min_slot_amount: Some(U128(d(50, TOKEN_DECIMALS))),
+    });
+    let token_details = contract.tokens.get(&get_token_id()).unwrap();
+    assert_eq!(token_details.symbol, Some("TEST".to_string()));
+    assert_eq!(token_details.decimals, Some(TOKEN_DECIMALS));
+
+    let mut bounty_create = bounty_create_params(None);
+    let quote = |contract: &BountiesContract, bounty_create: &BountyCreate, amount: u128| {
+      contract.get_bounty_quote(bounty_create.clone(), Some(get_token_id()), Some(U128(amount))).error
+    };
+    assert_eq!(quote(&contract, &bounty_create, d(1_000, TOKEN_DECIMALS)), None);
+    assert_eq!(
+      quote(&contract, &bounty_create, d(10, TOKEN_DECIMALS)),
+      Some("The bounty amount is less than the minimum for this token".to_string())
+    );
+    assert_eq!(
+      quote(&contract, &bounty_create, d(20_000, TOKEN_DECIMALS)),
+      Some("The bounty amount is greater than the maximum for this token".to_string())
+    );
+
+    bounty_create.multitasking = Some(Multitasking::DifferentTasks {
+      subtasks: vec![
+        Subtask { subtask_description: "first".to_string(), subtask_percent: 90_000 },
+        Subtask { subtask_description: "second".to_string(), subtask_percent: 10_000 },
+      ],
+      runtime_env: None,
+    });
+    assert_eq!(
+      quote(&contract, &bounty_create, d(200, TOKEN_DECIMALS)),
+      Some("The amount of one slot or place is less than the minimum for this token".to_string())
+    );
+    assert_eq!(quote(&contract, &bounty_create, d(1_000, TOKEN_DECIMALS)), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "The minimum bounty amount cannot be greater than the maximum")]
+  fn test_update_token_with_incorrect_limits() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    add_token(&mut contract);
+    contract.update_token(get_token_id(), TokenDetails {
+      enabled: true,
+      min_amount_for_kyc: None,
+      min_amount_for_kyb: None,
+      symbol: None,
+      decimals: None,
+      min_bounty_amount: Some(U128(d(100, TOKEN_DECIMALS))),
+      max_bounty_amount: Some(U128(d(10, TOKEN_DECIMALS))),
+      min_slot_amount: None,
+    });
+  }
+
+  #[test]
+  fn test_validators_dao_registry() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    add_token(&mut contract);
+    let dao_account_id: AccountId = "dao".parse().unwrap();
+    let dao_details_params = ValidatorsDaoDetailsParams {
+      fee_percentage: Some(5_000),
+      add_proposal_bond: U128(10u128.pow(24)),
+      gas_for_add_proposal: None,
+      gas_for_claim_approval: Some(U64(50_000_000_000_000)),
+      gas_for_claimant_approval: None,
+      dao_kind: None,
+      categories: Some(vec!["Development".to_string(), "Other".to_string()]),
+    };
+    contract.add_validators_dao(dao_account_id.clone(), dao_details_params.clone());
+    let dao_details = contract.get_validators_dao(dao_account_id.clone()).unwrap();
+    assert_eq!(dao_details.fee_percentage, 5_000);
+    assert_eq!(dao_details.dao_kind, DaoKind::SputnikV2);
+    assert_eq!(contract.get_validators_daos().len(), 1);
+
+    let mut bounty_create = bounty_create_params(Some(ReviewersParams::ValidatorsDao {
+      validators_dao: ValidatorsDaoParams {
+        account_id: "dao2".parse().unwrap(),
+        add_proposal_bond: None,
+        gas_for_add_proposal: None,
+        gas_for_claim_approval: None,
+        gas_for_claimant_approval: None,
+      },
+    }));
+    let quote = |contract: &BountiesContract, bounty_create: &BountyCreate| {
+      contract.get_bounty_quote(bounty_create.clone(), Some(get_token_id()), Some(U128(d(2_000, TOKEN_DECIMALS))))
+    };
+    assert_eq!(
+      quote(&contract, &bounty_create).error,
+      Some("The validators DAO is not registered".to_string())
+    );
+
+    bounty_create.reviewers = Some(ReviewersParams::ValidatorsDao {
+      validators_dao: ValidatorsDaoParams {
+        account_id: dao_account_id.clone(),
+        add_proposal_bond: None,
+        gas_for_add_proposal: None,
+        gas_for_claim_approval: None,
+        gas_for_claimant_approval: None,
+      },
+    });
+    let bounty_quote = quote(&contract, &bounty_create);
+    assert_eq!(bounty_quote.error, None);
+    // The DAO fee percentage is taken from the registry
+    assert_eq!(bounty_quote.dao_fee.0, bounty_quote.total_amount.0 * 5_000 / 105_000);
+
+    register_storage(&mut contract, &accounts(1));
+    contract.internal_create_bounty(
+      bounty_create.clone(),
+      &accounts(1),
+      Some(get_token_id()),
+      bounty_quote.total_amount
+    );
+    let bounty = contract.bounties.get(&0).unwrap().to_bounty();
+    assert_eq!(bounty.amount.0, d(2_000, TOKEN_DECIMALS));
+    assert_eq!(bounty.dao_fee, bounty_quote.dao_fee);
+    assert_eq!(
+      bounty.reviewers.unwrap(),
+      Reviewers::ValidatorsDao {
+        validators_dao: ValidatorsDao {
+          account_id: dao_account_id.clone(),
+          add_proposal_bond: U128(10u128.pow(24)),
+          gas_for_add_proposal: dao_details.gas_for_add_proposal,
+          gas_for_claim_approval: U64(50_000_000_000_000),
+          gas_for_claimant_approval: dao_details.gas_for_claimant_approval,
+          dao_kind: DaoKind::SputnikV2,
+        }
+      }
+    );
+
+    bounty_create.metadata.category = "Design".to_string();
+    assert_eq!(
+      quote(&contract, &bounty_create).error,
+      Some("The validators DAO does not support the bounty type Design".to_string())
+    );
+
+    contract.remove_validators_dao(dao_account_id.clone());
+    assert!(contract.get_validators_dao(dao_account_id).is_none());
+    assert_eq!(contract.get_validators_daos_fees().len(), 0);
+  }
+
+  #[test]
+  fn test_penalty_by_validators_dao_fee_percentage() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      Some(Config {
+        validators_dao_fee_percentage: 0,
+        penalty_validators_dao_fee_percentage: 2_500,
+        ..Config::default()
+      }),
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    add_token(&mut contract);
+    let dao_account_id: AccountId = "dao".parse().unwrap();
+    contract.add_validators_dao(dao_account_id.clone(), ValidatorsDaoDetailsParams {
+      fee_percentage: Some(5_000),
+      add_proposal_bond: U128(10u128.pow(24)),
+      gas_for_add_proposal: None,
+      gas_for_claim_approval: None,
+      gas_for_claimant_approval: None,
+      dao_kind: None,
+      categories: None,
+    });
+
+    let bounty_create = bounty_create_params(Some(ReviewersParams::ValidatorsDao {
+      validators_dao: ValidatorsDaoParams {
+        account_id: dao_account_id,
+        add_proposal_bond: None,
+        gas_for_add_proposal: None,
+        gas_for_claim_approval: None,
+        gas_for_claimant_approval: None,
+      },
+    }));
+    let bounty_quote = contract.get_bounty_quote(
+      bounty_create.clone(),
+      Some(get_token_id()),
+      Some(U128(d(2_000, TOKEN_DECIMALS)))
+    );
+    register_storage(&mut contract, &accounts(1));
+    contract.internal_create_bounty(
+      bounty_create,
+      &accounts(1),
+      Some(get_token_id()),
+      bounty_quote.total_amount
+    );
+    let bounty = contract.bounties.get(&0).unwrap().to_bounty();
+    assert_eq!(bounty.dao_fee_percentage, Some(5_000));
+
+    let (_, _, _, dao_fee, penalty_validators_dao_fee) = contract.internal_get_bounty_amount_to_return(&bounty);
+    assert_eq!(dao_fee, bounty.dao_fee);
+    assert_eq!(penalty_validators_dao_fee.0, bounty.dao_fee.0 / 2);
+  }
+
+  #[test]
+  #[should_panic(expected = "The fee percentage of the validators DAO cannot exceed 100%")]
+  fn test_validators_dao_fee_percentage_above_100() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    contract.add_validators_dao("dao".parse().unwrap(), ValidatorsDaoDetailsParams {
+      fee_percentage: Some(100_001),
+      add_proposal_bond: U128(10u128.pow(24)),
+      gas_for_add_proposal: None,
+      gas_for_claim_approval: None,
+      gas_for_claimant_approval: None,
+      dao_kind: None,
+      categories: None,
+    });
+  }
+
+  #[test]
+  fn test_min_amount_for_kyc() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    add_token(&mut contract);
+    contract.update_token(get_token_id(), TokenDetails {
+      enabled: true,
+      min_amount_for_kyc: Some(U128(d(1_000, TOKEN_DECIMALS))),
+      min_amount_for_kyb: None,
+      symbol: None,
+      decimals: None,
+      min_bounty_amount: None,
+      max_bounty_amount: None,
+      min_slot_amount: None,
+    });
+
+    let bounty_create = bounty_create_params(None);
+    let quote = |contract: &BountiesContract, amount: u128| {
+      contract.get_bounty_quote(bounty_create.clone(), Some(get_token_id()), Some(U128(amount))).error
+    };
+    assert_eq!(quote(&contract, d(500, TOKEN_DECIMALS)), None);
+    assert_eq!(
+      quote(&contract, d(1_000, TOKEN_DECIMALS)),
+      Some("KYC whitelist contract is not set".to_string())
+    );
+
+    contract.kyc_whitelist_contract = Some(accounts(5));
+    register_storage(&mut contract, &accounts(1));
+    contract.internal_create_bounty(
+      bounty_create.clone(),
+      &accounts(1),
+      Some(get_token_id()),
+      U128(d(500, TOKEN_DECIMALS))
+    );
+    let bounty = contract.bounties.get(&0).unwrap().to_bounty();
+    assert_eq!(bounty.kyc_config, KycConfig::KycNotRequired);
+
+    contract.internal_create_bounty(
+      bounty_create,
+      &accounts(1),
+      Some(get_token_id()),
+      U128(d(2_000, TOKEN_DECIMALS))
+    );
+    let bounty = contract.bounties.get(&1).unwrap().to_bounty();
+    assert_eq!(bounty.kyc_config, KycConfig::default());
+
+    let mut bounty = contract.bounties.get(&0).unwrap().to_bounty();
+    bounty.amount = U128(d(1_500, TOKEN_DECIMALS));
+    contract.check_bounty(&mut bounty);
+    assert_eq!(bounty.kyc_config, KycConfig::default());
+  }
+
+  #[test]
+  fn test_min_amount_for_kyb() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    contract.kyc_whitelist_contract = Some(accounts(5));
+    add_token(&mut contract);
+    contract.update_token(get_token_id(), TokenDetails {
+      enabled: true,
+      min_amount_for_kyc: None,
+      min_amount_for_kyb: Some(U128(d(1_000, TOKEN_DECIMALS))),
+      symbol: None,
+      decimals: None,
+      min_bounty_amount: None,
+      max_bounty_amount: None,
+      min_slot_amount: None,
+    });
+
+    let bounty_create = bounty_create_params(None);
+    let msg = serde_json::to_string(&bounty_create).unwrap();
+    register_storage(&mut contract, &accounts(1));
+
+    testing_env!(context.predecessor_account_id(get_token_id()).build());
+    let result = contract.ft_on_transfer(accounts(1), U128(d(500, TOKEN_DECIMALS)), msg.clone());
+    assert!(matches!(result, PromiseOrValue::Value(U128(0))));
+    assert_eq!(contract.last_bounty_id, 1);
+    // KYB is required only if the amount exceeds the threshold
+    let result = contract.ft_on_transfer(accounts(1), U128(d(1_000, TOKEN_DECIMALS)), msg.clone());
+    assert!(matches!(result, PromiseOrValue::Value(U128(0))));
+    assert_eq!(contract.last_bounty_id, 2);
+
+    let amount = U128(d(2_000, TOKEN_DECIMALS));
+    let result = contract.ft_on_transfer(accounts(1), amount, msg);
+    assert!(matches!(result, PromiseOrValue::Promise(_)));
+    assert_eq!(contract.last_bounty_id, 2);
+
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let refund = contract.after_check_if_owner_verified(
+      Ok(false),
+      bounty_create.clone(),
+      accounts(1),
+      get_token_id(),
+      amount
+    );
+    assert_eq!(refund, amount);
+    assert_eq!(contract.last_bounty_id, 2);
+
+    let refund = contract.after_check_if_owner_verified(
+      Ok(true),
+      bounty_create,
+      accounts(1),
+      get_token_id(),
+      amount
+    );
+    assert_eq!(refund, U128(0));
+    assert_eq!(contract.last_bounty_id, 3);
+    assert_eq!(contract.bounties.get(&2).unwrap().to_bounty().owner, accounts(1));
+  }
+
+  #[test]
+  fn test_team_claim() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+
+    register_storage(&mut contract, &accounts(2));
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(DEFAULT_BOUNTY_CLAIM_BOND.0)
+      .build());
+    contract.bounty_claim(
+      id,
+      Some(U64(1_000_000_000 * 60 * 60 * 24 * 2)),
+      "Test description".to_string(),
+      None,
+      Some(vec![
+        TeamMember { account_id: accounts(2), payout_percent: 33_333 },
+        TeamMember { account_id: accounts(3), payout_percent: 66_667 },
+      ])
+    );
+    assert!(contract.get_pending_team_claim(id, accounts(2)).is_some());
+    assert!(contract.get_claims_by_bounty_id(id, None, None).is_empty());
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.accept_team_membership(id, accounts(2));
+    assert!(contract.get_pending_team_claim(id, accounts(2)).is_none());
+
+    let (claim_id, mut claim) = contract.internal_get_claim(id, accounts(2), None);
+    assert_eq!(claim.get_members(), vec![accounts(2), accounts(3)]);
+    assert_eq!(
+      claim.get_team_payouts(U128(1_000)),
+      vec![(accounts(2), U128(333)), (accounts(3), U128(667))]
+    );
+    assert!(!claim.is_team_paid());
+
+    claim.paid_team_members = Some(vec![accounts(2)]);
+    contract.claims.insert(&claim_id, &claim.clone().into());
+    assert_eq!(claim.get_team_payouts(U128(1_000)), vec![(accounts(3), U128(667))]);
+    claim.paid_team_members = Some(vec![accounts(2), accounts(3)]);
+    assert!(claim.is_team_paid());
+  }
+
+  fn team_claim(
+    context: &mut VMContextBuilder,
+    contract: &mut BountiesContract,
+    id: BountyIndex,
+  ) {
+    register_storage(contract, &accounts(2));
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(DEFAULT_BOUNTY_CLAIM_BOND.0)
+      .build());
+    contract.bounty_claim(
+      id,
+      Some(U64(1_000_000_000 * 60 * 60 * 24 * 2)),
+      "Test description".to_string(),
+      None,
+      Some(vec![
+        TeamMember { account_id: accounts(2), payout_percent: 50_000 },
+        TeamMember { account_id: accounts(3), payout_percent: 50_000 },
+      ])
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "The account is not a member of the team")]
+  fn test_accept_team_membership_by_other_account() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    team_claim(&mut context, &mut contract, id);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(4))
+      .attached_deposit(1)
+      .build());
+    contract.accept_team_membership(id, accounts(2));
+  }
+
+  #[test]
+  fn test_decline_team_membership() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    team_claim(&mut context, &mut contract, id);
+    let used_bytes = contract.storage_balances.get(&accounts(2)).unwrap().used_bytes;
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.decline_team_membership(id, accounts(2));
+    assert!(contract.get_pending_team_claim(id, accounts(2)).is_none());
+    assert!(contract.get_claims_by_bounty_id(id, None, None).is_empty());
+    assert!(contract.storage_balances.get(&accounts(2)).unwrap().used_bytes < used_bytes);
+  }
+
+  #[test]
+  fn test_team_payout_with_failed_member_transfer() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    team_claim(&mut context, &mut contract, id);
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.accept_team_membership(id, accounts(2));
+    bounty_done(&mut context, &mut contract, id, &accounts(2));
+    bounty_approve(&mut context, &mut contract, id, &accounts(1), &accounts(2), None);
+
+    let bounty = contract.bounties.get(&id).unwrap().to_bounty();
+    let amounts = BountiesContract::internal_get_bounty_amount_for_payment(&bounty, None);
+    let (_, claim) = contract.internal_get_claim(id, accounts(2), None);
+    let payouts = claim.get_team_payouts(amounts.0);
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(vec![]), PromiseResult::Failed]
+    );
+    assert!(!contract.after_ft_transfer(
+      id,
+      Some((accounts(2), None)),
+      amounts.clone(),
+      None,
+      payouts.clone(),
+      false
+    ));
+    assert_eq!(contract.bounties.get(&id).unwrap().to_bounty().status, BountyStatus::Completed);
+    assert!(contract.get_pending_payouts(accounts(2)).is_empty());
+    assert_eq!(
+      contract.get_pending_payouts(accounts(3)),
+      vec![PendingPayout { token_id: get_token_id(), amount: payouts[1].1, in_progress: false }]
+    );
+    let (_, claim) = contract.internal_get_claim(id, accounts(2), None);
+    assert_eq!(claim.status, ClaimStatus::Approved);
+    assert!(claim.is_team_paid());
+  }
+
+  #[test]
+  #[should_panic(expected = "The sum of the payout shares of all team members must equal 100%")]
+  fn test_team_claim_with_incorrect_shares() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(DEFAULT_BOUNTY_CLAIM_BOND.0)
+      .build());
+    contract.bounty_claim(
+      id,
+      Some(U64(1_000_000_000 * 60 * 60 * 24 * 2)),
+      "Test description".to_string(),
+      None,
+      Some(vec![
+        TeamMember { account_id: accounts(2), payout_percent: 50_000 },
+        TeamMember { account_id: accounts(3), payout_percent: 40_000 },
+      ])
+    );
+  }
+
+  #[test]
+  fn test_claim_handover() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+
+    register_storage(&mut contract, &accounts(3));
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(1)
+      .build());
+    contract.propose_claim_handover(id, None, accounts(3));
+    assert_eq!(
+      get_claim_by_claimant_account_id(&contract, &accounts(2)).handover_to,
+      Some(accounts(3))
+    );
+    let successor_storage = contract.storage_balances.get(&accounts(3)).unwrap().used_bytes;
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .attached_deposit(1)
+      .build());
+    contract.accept_claim_handover(id, accounts(2), None);
+    assert!(get_claim_by_claimant_account_id(&contract, &accounts(2)).handover_accepted);
+    assert_eq!(contract.get_claims_by_bounty_id(id, None, None).len(), 1);
+    assert_eq!(contract.storage_balances.get(&accounts(3)).unwrap().used_bytes, successor_storage);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.take_over_claim(id, accounts(2), None);
+    assert!(contract.storage_balances.get(&accounts(3)).unwrap().used_bytes > successor_storage);
+
+    let old_claim = get_claim_by_claimant_account_id(&contract, &accounts(2));
+    assert_eq!(old_claim.status, ClaimStatus::HandedOver);
+    assert_eq!(old_claim.bond, None);
+    assert_eq!(old_claim.handover_to, None);
+    let new_claim = get_claim_by_claimant_account_id(&contract, &accounts(3));
+    assert_eq!(new_claim.status, ClaimStatus::InProgress);
+    assert_eq!(new_claim.start_time, old_claim.start_time);
+    assert_eq!(new_claim.deadline, old_claim.deadline);
+    assert_eq!(new_claim.bond, Some(DEFAULT_BOUNTY_CLAIM_BOND));
+    assert_eq!(contract.locked_amount, DEFAULT_BOUNTY_CLAIM_BOND.0);
+    assert_eq!(contract.bounties.get(&id).unwrap().to_bounty().status, BountyStatus::Claimed);
+  }
+
+  #[test]
+  #[should_panic(expected = "No claim handover has been proposed")]
+  fn test_decline_claim_handover() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+
+    register_storage(&mut contract, &accounts(3));
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(1)
+      .build());
+    contract.propose_claim_handover(id, None, accounts(3));
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .attached_deposit(1)
+      .build());
+    contract.decline_claim_handover(id, accounts(2), None);
+    assert_eq!(get_claim_by_claimant_account_id(&contract, &accounts(2)).handover_to, None);
+
+    contract.accept_claim_handover(id, accounts(2), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "The claim handover has not been accepted yet")]
+  fn test_take_over_claim_before_acceptance() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+
+    register_storage(&mut contract, &accounts(3));
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(1)
+      .build());
+    contract.propose_claim_handover(id, None, accounts(3));
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.take_over_claim(id, accounts(2), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "Only the proposed successor can take over the claim")]
+  fn test_take_over_claim_by_other_account() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+
+    register_storage(&mut contract, &accounts(3));
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(1)
+      .build());
+    contract.propose_claim_handover(id, None, accounts(3));
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .attached_deposit(1)
+      .build());
+    contract.accept_claim_handover(id, accounts(2), None);
+    contract.take_over_claim(id, accounts(2), None);
+  }
+
+  #[test]
+  fn test_set_claim_payout_target() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+
+    let payout_target = PayoutTarget {
+      receiver_id: accounts(3),
+      msg: Some("deposit".to_string()),
+    };
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(1)
+      .build());
+    contract.set_claim_payout_target(id, None, Some(payout_target.clone()));
+    assert_eq!(
+      get_claim_by_claimant_account_id(&contract, &accounts(2)).payout_target,
+      Some(payout_target)
+    );
+
+    contract.set_claim_payout_target(id, None, None);
+    assert_eq!(get_claim_by_claimant_account_id(&contract, &accounts(2)).payout_target, None);
+  }
+
+  #[test]
+  #[should_panic(expected = "The payout target can no longer be changed")]
+  fn test_set_claim_payout_target_after_approval() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+
+    let (claim_id, mut claim) = contract.internal_get_claim(id, accounts(2), None);
+    claim.status = ClaimStatus::Approved;
+    contract.claims.insert(&claim_id, &claim.into());
+
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(1)
+      .build());
+    contract.set_claim_payout_target(
+      id,
+      None,
+      Some(PayoutTarget { receiver_id: accounts(3), msg: None })
+    );
+  }
+
+  #[test]
+  fn test_gas_for_payout_after_dispute() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+    testing_env!(context
+      .predecessor_account_id(accounts(2))
+      .attached_deposit(1)
+      .build());
+    contract.set_claim_payout_target(
+      id,
+      None,
+      Some(PayoutTarget { receiver_id: accounts(3), msg: Some("deposit".to_string()) })
+    );
+    bounty_done(&mut context, &mut contract, id, &accounts(2));
+    let (claim_id, mut claim) = contract.internal_get_claim(id, accounts(2), None);
+    claim.status = ClaimStatus::Disputed;
+    contract.claims.insert(&claim_id, &claim.into());
+
+    // The worst case of the payout (registration check and ft_transfer_call)
+    // must fit into the gas of the callback
+    let dispute = Dispute { status: "InFavorOfClaimant".to_string() };
+    testing_env!(
+      context
+        .predecessor_account_id(accounts(0))
+        .prepaid_gas(GAS_FOR_AFTER_CHECK_DISPUTE)
+        .build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(b"{}".to_vec())]
+    );
+    contract.after_get_dispute(Ok(dispute), id, accounts(2), None);
+    let (_, claim) = contract.internal_get_claim(id, accounts(2), None);
+    assert_eq!(claim.status, ClaimStatus::Disputed);
+  }
+
+  #[test]
+  fn test_pending_payout_after_failed_transfer() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+    bounty_done(&mut context, &mut contract, id, &accounts(2));
+    bounty_approve(&mut context, &mut contract, id, &accounts(1), &accounts(2), None);
+
+    let bounty = contract.bounties.get(&id).unwrap().to_bounty();
+    let amounts = BountiesContract::internal_get_bounty_amount_for_payment(&bounty, None);
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Failed]
+    );
+    assert!(!contract.after_ft_transfer(
+      id,
+      Some((accounts(2), None)),
+      amounts.clone(),
+      None,
+      vec![(accounts(2), amounts.0)],
+      false
+    ));
+    assert_eq!(contract.bounties.get(&id).unwrap().to_bounty().status, BountyStatus::Completed);
+    assert_eq!(
+      contract.get_pending_payouts(accounts(2)),
+      vec![PendingPayout { token_id: get_token_id(), amount: amounts.0, in_progress: false }]
+    );
+
+    testing_env!(context
+      .predecessor_account_id(accounts(3))
+      .attached_deposit(1)
+      .build());
+    contract.withdraw_pending_payout(accounts(2), get_token_id());
+    assert!(contract.get_pending_payouts(accounts(2))[0].in_progress);
+
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).attached_deposit(0).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(vec![])]
+    );
+    assert!(contract.after_pending_payout(accounts(2), get_token_id(), amounts.0));
+    assert!(contract.get_pending_payouts(accounts(2)).is_empty());
+    assert!(contract.get_all_pending_payouts(None, None).is_empty());
+  }
+
+  #[test]
+  fn test_refund_of_transfer_call_payout() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let id = add_bounty(&mut contract, &accounts(1), None, None);
+    bounty_claim(&mut context, &mut contract, id, &accounts(2));
+    bounty_done(&mut context, &mut contract, id, &accounts(2));
+    bounty_approve(&mut context, &mut contract, id, &accounts(1), &accounts(2), None);
+
+    let bounty = contract.bounties.get(&id).unwrap().to_bounty();
+    let amounts = BountiesContract::internal_get_bounty_amount_for_payment(&bounty, None);
+    let refund = 100;
+    testing_env!(
+      context.predecessor_account_id(accounts(0)).build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Successful(serde_json::to_vec(&U128(amounts.0.0 - refund)).unwrap())]
+    );
+    assert!(!contract.after_ft_transfer(
+      id,
+      Some((accounts(2), None)),
+      amounts,
+      None,
+      vec![(accounts(3), amounts.0)],
+      true
+    ));
+    assert_eq!(contract.bounties.get(&id).unwrap().to_bounty().status, BountyStatus::Completed);
+    assert_eq!(
+      contract.get_pending_payouts(accounts(2)),
+      vec![PendingPayout { token_id: get_token_id(), amount: U128(refund), in_progress: false }]
+    );
+    assert!(contract.get_pending_payouts(accounts(3)).is_empty());
+  }
+
+  #[test]
+  fn test_reroute_pending_payout() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    contract.internal_add_pending_payout(&accounts(2), &get_token_id(), U128(1_000));
+    contract.internal_add_pending_payout(&accounts(2), &get_token_id(), U128(500));
+
+    contract.reroute_pending_payout(accounts(2), get_token_id(), accounts(3));
+    assert!(contract.get_pending_payouts(accounts(2)).is_empty());
+    assert_eq!(
+      contract.get_pending_payouts(accounts(3)),
+      vec![PendingPayout { token_id: get_token_id(), amount: U128(1_500), in_progress: false }]
+    );
+  }
+
+  #[test]
+  fn test_storage_subsidy_for_unregistered_receiver() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      None
+    );
+    contract.set_status(ContractStatus::Live);
+    let deposit = Config::default().ft_storage_deposit.0;
+
+    contract.after_storage_balance_of(Ok(None), get_token_id(), accounts(2));
+    assert_eq!(contract.get_storage_subsidy_pool(), U128(0));
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .attached_deposit(deposit)
+      .build());
+    contract.fund_storage_subsidy_pool();
+    assert_eq!(contract.get_storage_subsidy_pool(), U128(deposit));
+
+    testing_env!(context.predecessor_account_id(accounts(0)).attached_deposit(0).build());
+    contract.after_storage_balance_of(
+      Ok(Some(StorageBalance { total: U128(deposit), available: U128(0) })),
+      get_token_id(),
+      accounts(2)
+    );
+    assert_eq!(contract.get_storage_subsidy_pool(), U128(deposit));
+    contract.after_storage_balance_of(Ok(None), get_token_id(), accounts(2));
+    assert_eq!(contract.get_storage_subsidy_pool(), U128(0));
+
+    testing_env!(
+      context.build(),
+      VMConfig::test(),
+      RuntimeFeesConfig::test(),
+      Default::default(),
+      vec![PromiseResult::Failed]
+    );
+    assert!(!contract.after_storage_deposit(get_token_id(), accounts(2), U128(deposit)));
+    assert_eq!(contract.get_storage_subsidy_pool(), U128(deposit));
+  }
+
+  #[test]
+  fn test_withdraw_storage_subsidy_pool() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      Some(accounts(5))
+    );
+    contract.set_status(ContractStatus::Live);
+    let deposit = Config::default().ft_storage_deposit.0;
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .attached_deposit(deposit * 3)
+      .build());
+    contract.fund_storage_subsidy_pool();
+
+    testing_env!(context
+      .predecessor_account_id(accounts(0))
+      .attached_deposit(1)
+      .build());
+    contract.withdraw_storage_subsidy_pool(Some(U128(deposit)));
+    assert_eq!(contract.get_storage_subsidy_pool(), U128(deposit * 2));
+    contract.withdraw_storage_subsidy_pool(None);
+    assert_eq!(contract.get_storage_subsidy_pool(), U128(0));
+  }
+
+  #[test]
+  #[should_panic(expected = "This account does not have permission to perform this action")]
+  fn test_withdraw_storage_subsidy_pool_by_other_account() {
+    let mut context = VMContextBuilder::new();
+    testing_env!(context.predecessor_account_id(accounts(0)).build());
+    let mut contract = BountiesContract::new(
+      vec![accounts(0)],
+      None,
+      None,
+      None,
+      None,
+      Some(accounts(5))
+    );
+    contract.set_status(ContractStatus::Live);
+
+    testing_env!(context
+      .predecessor_account_id(accounts(1))
+      .attached_deposit(Config::default().ft_storage_deposit.0)
+      .build());
+    contract.fund_storage_subsidy_pool();
+
+    testing_env!(context.attached_deposit(1).build());
+    contract.withdraw_storage_subsidy_pool(None);
+  }
 }
@@ -16,6 +16,7 @@ impl FungibleTokenReceiver for BountiesContract {
     msg: String,
   ) -> PromiseOrValue<U128> {
     self.assert_live();
+    self.assert_not_paused(PauseCategory::BountyCreation);
     let token_id = &env::predecessor_account_id();
     self.assert_that_token_is_allowed(token_id);
     assert!(
@@ -28,6 +29,11 @@ impl FungibleTokenReceiver for BountiesContract {
     match ft_message {
       FtMessage::BountyCreate(bounty_create) => {
         assert!(bounty_create.postpaid.is_none(), "The postpaid parameter is incorrect");
+        if self.internal_is_kyb_required(&bounty_create, token_id, amount) {
+          return self
+            .check_if_owner_in_kyb_whitelist(bounty_create, sender_id, token_id.clone(), amount)
+            .into();
+        }
         self.internal_create_bounty(bounty_create, &sender_id, Some(token_id.clone()), amount);
       },
     }
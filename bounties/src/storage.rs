@@ -0,0 +1,90 @@
+use crate::*;
+use near_contract_standards::storage_management::{StorageBalance, StorageBalanceBounds,
+                                                  StorageManagement};
+
+#[near_bindgen]
+impl StorageManagement for BountiesContract {
+  #[payable]
+  fn storage_deposit(
+    &mut self,
+    account_id: Option<AccountId>,
+    registration_only: Option<bool>,
+  ) -> StorageBalance {
+    let amount = env::attached_deposit();
+    let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+    let registration_only = registration_only.unwrap_or_default();
+    let min_balance = self.storage_balance_bounds().min.0;
+
+    let refund = if let Some(mut storage) = self.storage_balances.get(&account_id) {
+      if registration_only {
+        amount
+      } else {
+        storage.deposit += amount;
+        self.storage_balances.insert(&account_id, &storage);
+        0
+      }
+    } else {
+      assert!(
+        amount >= min_balance,
+        "The attached deposit is less than the minimum storage balance"
+      );
+      let deposit = if registration_only { min_balance } else { amount };
+      self.storage_balances.insert(&account_id, &AccountStorage { deposit, used_bytes: 0 });
+      amount - deposit
+    };
+
+    if refund > 0 {
+      Promise::new(env::predecessor_account_id()).transfer(refund);
+    }
+    self.storage_balance_of(account_id).unwrap()
+  }
+
+  #[payable]
+  fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+    assert_one_yocto();
+    let account_id = env::predecessor_account_id();
+    let mut storage = self.storage_balances
+      .get(&account_id)
+      .expect("The account is not registered");
+    let available = self.internal_get_available_storage_balance(&storage);
+    let amount = amount.map(|a| a.0).unwrap_or(available);
+    assert!(amount <= available, "The amount is greater than the available storage balance");
+
+    if amount > 0 {
+      storage.deposit -= amount;
+      self.storage_balances.insert(&account_id, &storage);
+      Promise::new(account_id.clone()).transfer(amount);
+    }
+    self.storage_balance_of(account_id).unwrap()
+  }
+
+  #[payable]
+  fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+    assert_one_yocto();
+    assert!(!force.unwrap_or_default(), "Force unregistration is not supported");
+    let account_id = env::predecessor_account_id();
+
+    if let Some(storage) = self.storage_balances.get(&account_id) {
+      assert_eq!(storage.used_bytes, 0, "The account still pays for the contract storage");
+      self.storage_balances.remove(&account_id);
+      Promise::new(account_id).transfer(storage.deposit);
+      true
+    } else {
+      false
+    }
+  }
+
+  fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+    StorageBalanceBounds {
+      min: U128(Balance::from(ACCOUNT_STORAGE_BYTES) * env::storage_byte_cost()),
+      max: None,
+    }
+  }
+
+  fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+    self.storage_balances.get(&account_id).map(|storage| StorageBalance {
+      total: U128(storage.deposit),
+      available: U128(self.internal_get_available_storage_balance(&storage)),
+    })
+  }
+}
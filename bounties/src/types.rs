@@ -1,10 +1,17 @@
+use std::collections::HashSet;
+
+use near_contract_standards::storage_management::StorageBalance;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::json_types::{Base64VecU8, U128, U64};
+use near_sdk::json_types::{Base58CryptoHash, Base64VecU8, U128, U64};
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::{env, ext_contract, AccountId, Balance, BorshStorageKey, Gas, ONE_NEAR};
+use near_sdk::{env, ext_contract, AccountId, Balance, BorshStorageKey, CryptoHash, Gas, ONE_NEAR,
+               StorageUsage};
+
+use crate::dao::DaoKind;
 
 pub type BountyIndex = u64;
 pub type ClaimIndex = u64;
+pub type ClaimEntry = (ClaimIndex, BountyClaim);
 
 pub const GAS_FOR_ADD_PROPOSAL: Gas = Gas(25_000_000_000_000);
 pub const GAS_FOR_ON_ADDED_PROPOSAL_CALLBACK: Gas = Gas(10_000_000_000_000);
@@ -12,20 +19,35 @@ pub const GAS_FOR_AFTER_ADD_PROPOSAL: Gas = Gas(45_000_000_000_000);
 pub const GAS_FOR_CLAIM_APPROVAL: Gas = Gas(70_000_000_000_000);
 pub const GAS_FOR_CLAIMANT_APPROVAL: Gas = Gas(120_000_000_000_000);
 pub const GAS_FOR_FT_TRANSFER: Gas = Gas(5_000_000_000_000);
+pub const GAS_FOR_FT_TRANSFER_CALL: Gas = Gas(50_000_000_000_000);
+pub const GAS_FOR_STORAGE_BALANCE_OF: Gas = Gas(5_000_000_000_000);
+pub const GAS_FOR_AFTER_STORAGE_BALANCE_OF: Gas = Gas(20_000_000_000_000);
+pub const GAS_FOR_STORAGE_DEPOSIT: Gas = Gas(8_000_000_000_000);
+pub const GAS_FOR_AFTER_STORAGE_DEPOSIT: Gas = Gas(5_000_000_000_000);
 pub const GAS_FOR_AFTER_FT_TRANSFER: Gas = Gas(40_000_000_000_000);
 pub const GAS_FOR_AFTER_FT_TRANSACT: Gas = Gas(15_000_000_000_000);
 pub const GAS_FOR_CHECK_PROPOSAL: Gas = Gas(15_000_000_000_000);
-pub const GAS_FOR_AFTER_CHECK_BOUNTY_PAYOUT_PROPOSAL: Gas = Gas(70_000_000_000_000);
+/// Static gas of the worst case of a bounty payout: the receiver registration check,
+/// ft_transfer_call and the after_ft_transfer callback.
+pub const GAS_FOR_BOUNTY_PAYOUT: Gas = Gas(
+  GAS_FOR_STORAGE_BALANCE_OF.0 + GAS_FOR_AFTER_STORAGE_BALANCE_OF.0 + GAS_FOR_FT_TRANSFER_CALL.0 +
+    GAS_FOR_AFTER_FT_TRANSFER.0
+);
+pub const GAS_FOR_AFTER_CHECK_BOUNTY_PAYOUT_PROPOSAL: Gas = Gas(GAS_FOR_BOUNTY_PAYOUT.0 + 60_000_000_000_000);
 pub const GAS_FOR_AFTER_CHECK_APPROVE_CLAIMANT_PROPOSAL: Gas = Gas(30_000_000_000_000);
+pub const GAS_FOR_AFTER_CHECK_STUCK_PROPOSAL: Gas = Gas(80_000_000_000_000);
+pub const GAS_FOR_AFTER_RESUBMIT_PROPOSAL: Gas = Gas(10_000_000_000_000);
+pub const GAS_FOR_FINALIZE_PROPOSAL: Gas = Gas(10_000_000_000_000);
 pub const GAS_FOR_CREATE_DISPUTE: Gas = Gas(15_000_000_000_000);
 pub const GAS_FOR_AFTER_CREATE_DISPUTE: Gas = Gas(15_000_000_000_000);
 pub const GAS_FOR_CHECK_DISPUTE: Gas = Gas(15_000_000_000_000);
-pub const GAS_FOR_AFTER_CHECK_DISPUTE: Gas = Gas(70_000_000_000_000);
+pub const GAS_FOR_AFTER_CHECK_DISPUTE: Gas = Gas(GAS_FOR_BOUNTY_PAYOUT.0 + 60_000_000_000_000);
 pub const GAS_FOR_UPDATE_STATISTIC: Gas = Gas(6_000_000_000_000);
 pub const GAS_FOR_GET_FT_METADATA: Gas = Gas(15_000_000_000_000);
 pub const GAS_FOR_AFTER_GET_FT_METADATA: Gas = Gas(15_000_000_000_000);
 pub const GAS_FOR_CHECK_IF_WHITELISTED: Gas = Gas(5_000_000_000_000);
 pub const GAS_FOR_AFTER_CHECK_IF_WHITELISTED: Gas = Gas(85_000_000_000_000);
+pub const GAS_FOR_AFTER_CHECK_IF_OWNER_VERIFIED: Gas = Gas(30_000_000_000_000);
 
 pub const DEFAULT_BOUNTY_CLAIM_BOND: U128 = U128(ONE_NEAR);
 pub const DEFAULT_BOUNTY_FORGIVENESS_PERIOD: U64 = U64(1_000_000_000 * 60 * 60 * 24);
@@ -35,10 +57,23 @@ pub const DEFAULT_VALIDATORS_DAO_FEE_PERCENTAGE: u32 = 10_000;
 pub const DEFAULT_PENALTY_PLATFORM_FEE_PERCENTAGE: u32 = 0;
 pub const DEFAULT_PENALTY_VALIDATORS_DAO_FEE_PERCENTAGE: u32 = 0;
 pub const MAX_SLOTS: u16 = 32;
+pub const MAX_TEAM_MEMBERS: usize = 10;
+/// Transfers of the platform fee shares per call, so that they fit into the gas limit
+pub const MAX_PLATFORM_FEE_PAYOUTS: usize = 5;
 pub const MIN_DEVIATION_FOR_TOTAL_BOUNTY_AMOUNT: u128 = 20;
 pub const MAX_DUE_DATE: U64 = U64(1_000_000_000 * 60 * 60 * 24 * 90); // 90 days
+pub const DEFAULT_TIMELOCK_DELAY: U64 = U64(1_000_000_000 * 60 * 60 * 24); // 1 day
+pub const DEFAULT_ADMIN_APPROVAL_THRESHOLD: u16 = 1;
+pub const DEFAULT_ARCHIVE_RETENTION_PERIOD: U64 = U64(1_000_000_000 * 60 * 60 * 24 * 180); // 180 days
+pub const DEFAULT_FT_STORAGE_DEPOSIT: U128 = U128(1_250_000_000_000_000_000_000); // 0.00125 NEAR
 
 pub const NO_DEPOSIT: Balance = 0;
+/// The storage reserved for the account registration in the storage balances
+pub const ACCOUNT_STORAGE_BYTES: StorageUsage = 200;
+/// The estimated storage of a claim without its description
+pub const CLAIM_STORAGE_BYTES: StorageUsage = 500;
+/// The estimated storage of one member of a team claim
+pub const TEAM_MEMBER_STORAGE_BYTES: StorageUsage = 100;
 pub const INITIAL_CATEGORIES: [&str; 4] = ["Marketing", "Development", "Design", "Other"];
 pub const INITIAL_TAGS: [&str; 18] = ["API", "Blockchain", "Community", "CSS", "DAO", "dApp",
   "DeFi", "Design", "Documentation", "HTML", "Javascript", "NFT", "React", "Rust", "Smart contract",
@@ -53,6 +88,22 @@ trait ExtFtContract {
     memo: Option<String>
   );
 
+  fn ft_transfer_call(
+    &mut self,
+    receiver_id: AccountId,
+    amount: U128,
+    memo: Option<String>,
+    msg: String,
+  ) -> U128;
+
+  fn storage_deposit(
+    &mut self,
+    account_id: Option<AccountId>,
+    registration_only: Option<bool>,
+  ) -> StorageBalance;
+
+  fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance>;
+
   fn ft_metadata(&self) -> FungibleTokenMetadata;
 }
 
@@ -68,14 +119,6 @@ pub struct FungibleTokenMetadata {
   pub decimals: u8,
 }
 
-#[derive(Deserialize)]
-#[serde(crate = "near_sdk::serde")]
-pub struct Proposal {
-  pub id: u64,
-  pub proposer: AccountId,
-  pub status: String,
-}
-
 #[derive(Serialize)]
 #[serde(crate = "near_sdk::serde")]
 pub struct DisputeCreate {
@@ -105,6 +148,7 @@ pub enum ReputationActionKind {
   UnsuccessfulClaim { with_dispute: bool },
   SuccessfulClaim { with_dispute: bool },
   SuccessfulBounty,
+  ClaimHandedOver,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, PartialEq)]
@@ -129,6 +173,28 @@ pub enum ContractStatus {
   ReadOnly,
 }
 
+/// Groups of operations that can be paused separately while the contract is Live.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PauseCategory {
+  /// Creating bounties
+  BountyCreation,
+  /// Creating claims
+  ClaimCreation,
+  /// Bounty payouts, refunds and withdrawals of fees and bonds
+  Payouts,
+  /// Opening disputes and applying their results
+  Disputes,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct ContractStatusDetails {
+  pub status: ContractStatus,
+  pub paused_categories: Vec<PauseCategory>,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
@@ -160,22 +226,45 @@ impl Deadline {
 #[serde(crate = "near_sdk::serde")]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
 pub enum Reviewers {
+  ValidatorsDaoV1 { validators_dao: ValidatorsDaoV1 },
+  MoreReviewersV1 { more_reviewers: Vec<AccountId> },
   ValidatorsDao { validators_dao: ValidatorsDao },
-  MoreReviewers { more_reviewers: Vec<AccountId> },
+  /// The decisions on the claim take effect when the threshold number of reviewers have voted
+  MoreReviewers { more_reviewers: Vec<AccountId>, threshold: u8 },
 }
 
 impl Reviewers {
+  pub fn upgrade_v1_to_v2(self) -> Self {
+    match self {
+      Self::ValidatorsDaoV1 { validators_dao } =>
+        Self::ValidatorsDao { validators_dao: validators_dao.into() },
+      Self::MoreReviewersV1 { more_reviewers } =>
+        Self::MoreReviewers { more_reviewers, threshold: 1 },
+      _ => self,
+    }
+  }
+
   pub fn get_more_reviewers(&self) -> Vec<AccountId> {
-    match self.clone() {
-      Self::MoreReviewers { more_reviewers } => more_reviewers,
+    match self.clone().upgrade_v1_to_v2() {
+      Self::MoreReviewers { more_reviewers, .. } => more_reviewers,
       _ => env::panic_str("There are no other reviewers")
     }
   }
+
+  /// Accounts responsible for reviewing the claims: the reviewers or the validators DAO.
+  /// The legacy variants are upgraded first, no account is returned if that is not possible.
+  pub fn get_reviewer_accounts(&self) -> Vec<AccountId> {
+    match self.clone().upgrade_v1_to_v2() {
+      Self::ValidatorsDao { validators_dao } => vec![validators_dao.account_id],
+      Self::MoreReviewers { more_reviewers, .. } => more_reviewers,
+      Self::ValidatorsDaoV1 { .. } | Self::MoreReviewersV1 { .. } => vec![],
+    }
+  }
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(crate = "near_sdk::serde")]
-#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug))]
 pub enum Experience {
   Beginner,
   Intermediate,
@@ -244,32 +333,81 @@ pub struct BountyMetadata {
 #[serde(crate = "near_sdk::serde")]
 pub struct ValidatorsDaoParams {
   pub account_id: AccountId,
-  pub add_proposal_bond: U128,
+  /// The bond from the validators DAO registry by default
+  pub add_proposal_bond: Option<U128>,
   pub gas_for_add_proposal: Option<U64>,
   pub gas_for_claim_approval: Option<U64>,
   pub gas_for_claimant_approval: Option<U64>,
 }
 
 impl ValidatorsDaoParams {
-  pub fn to_validators_dao(&self) -> ValidatorsDao {
-    let mut gas_for_add_proposal = GAS_FOR_ADD_PROPOSAL.0.into();
-    if self.gas_for_add_proposal.is_some() {
-      gas_for_add_proposal = self.gas_for_add_proposal.unwrap();
-    }
-    let mut gas_for_claim_approval = GAS_FOR_CLAIM_APPROVAL.0.into();
-    if self.gas_for_claim_approval.is_some() {
-      gas_for_claim_approval = self.gas_for_claim_approval.unwrap();
-    }
-    let mut gas_for_claimant_approval = GAS_FOR_CLAIMANT_APPROVAL.0.into();
-    if self.gas_for_claimant_approval.is_some() {
-      gas_for_claimant_approval = self.gas_for_claimant_approval.unwrap();
-    }
+  /// Unspecified settings are taken from the registry entry of the DAO
+  pub fn to_validators_dao(&self, dao_details: &ValidatorsDaoDetails) -> ValidatorsDao {
     ValidatorsDao {
       account_id: self.account_id.clone(),
-      add_proposal_bond: self.add_proposal_bond.clone(),
-      gas_for_add_proposal,
-      gas_for_claim_approval,
-      gas_for_claimant_approval,
+      add_proposal_bond: self.add_proposal_bond.unwrap_or(dao_details.add_proposal_bond),
+      gas_for_add_proposal: self.gas_for_add_proposal.unwrap_or(dao_details.gas_for_add_proposal),
+      gas_for_claim_approval: self.gas_for_claim_approval
+        .unwrap_or(dao_details.gas_for_claim_approval),
+      gas_for_claimant_approval: self.gas_for_claimant_approval
+        .unwrap_or(dao_details.gas_for_claimant_approval),
+      dao_kind: dao_details.dao_kind,
+    }
+  }
+}
+
+/// Validators DAO approved by the admins. New bounties can only use the registered DAOs.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct ValidatorsDaoDetails {
+  /// Fee of the DAO, replaces validators_dao_fee_percentage of the configuration
+  pub fee_percentage: u32,
+  pub add_proposal_bond: U128,
+  pub gas_for_add_proposal: U64,
+  pub gas_for_claim_approval: U64,
+  pub gas_for_claimant_approval: U64,
+  pub dao_kind: DaoKind,
+  /// Bounty categories validated by the DAO, all categories if not specified
+  pub categories: Option<Vec<String>>,
+}
+
+impl ValidatorsDaoDetails {
+  pub fn try_category_is_supported(&self, category: &String) -> Result<(), String> {
+    match self.categories.as_ref() {
+      Some(categories) if !categories.contains(category) =>
+        Err(format!("The validators DAO does not support the bounty type {}", category)),
+      _ => Ok(()),
+    }
+  }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ValidatorsDaoDetailsParams {
+  /// validators_dao_fee_percentage of the configuration by default
+  pub fee_percentage: Option<u32>,
+  pub add_proposal_bond: U128,
+  pub gas_for_add_proposal: Option<U64>,
+  pub gas_for_claim_approval: Option<U64>,
+  pub gas_for_claimant_approval: Option<U64>,
+  /// Sputnik DAO v2 by default
+  pub dao_kind: Option<DaoKind>,
+  pub categories: Option<Vec<String>>,
+}
+
+impl ValidatorsDaoDetailsParams {
+  pub fn to_validators_dao_details(&self, config: &Config) -> ValidatorsDaoDetails {
+    ValidatorsDaoDetails {
+      fee_percentage: self.fee_percentage.unwrap_or(config.validators_dao_fee_percentage),
+      add_proposal_bond: self.add_proposal_bond,
+      gas_for_add_proposal: self.gas_for_add_proposal.unwrap_or(GAS_FOR_ADD_PROPOSAL.0.into()),
+      gas_for_claim_approval: self.gas_for_claim_approval
+        .unwrap_or(GAS_FOR_CLAIM_APPROVAL.0.into()),
+      gas_for_claimant_approval: self.gas_for_claimant_approval
+        .unwrap_or(GAS_FOR_CLAIMANT_APPROVAL.0.into()),
+      dao_kind: self.dao_kind.unwrap_or_default(),
+      categories: self.categories.clone(),
     }
   }
 }
@@ -278,22 +416,34 @@ impl ValidatorsDaoParams {
 #[serde(crate = "near_sdk::serde")]
 pub enum ReviewersParams {
   ValidatorsDao { validators_dao: ValidatorsDaoParams },
-  MoreReviewers { more_reviewers: Vec<AccountId> },
+  /// One reviewer by default
+  MoreReviewers { more_reviewers: Vec<AccountId>, threshold: Option<u8> },
 }
 
 impl ReviewersParams {
-  pub fn to_reviewers(&self) -> Reviewers {
+  /// The registry entry is required for the validators DAO
+  pub fn to_reviewers(&self, dao_details: Option<&ValidatorsDaoDetails>) -> Reviewers {
     match self.clone() {
-      Self::ValidatorsDao { validators_dao } =>
-        Reviewers::ValidatorsDao { validators_dao: validators_dao.to_validators_dao() },
-      Self::MoreReviewers { more_reviewers } =>
-        Reviewers::MoreReviewers { more_reviewers },
+      Self::ValidatorsDao { validators_dao } => Reviewers::ValidatorsDao {
+        validators_dao: validators_dao.to_validators_dao(
+          dao_details.unwrap_or_else(|| env::panic_str("The validators DAO is not registered"))
+        ),
+      },
+      Self::MoreReviewers { more_reviewers, threshold } =>
+        Reviewers::MoreReviewers { more_reviewers, threshold: threshold.unwrap_or(1) },
+    }
+  }
+
+  pub fn get_validators_dao_account_id(&self) -> Option<AccountId> {
+    match self {
+      Self::ValidatorsDao { validators_dao } => Some(validators_dao.account_id.clone()),
+      _ => None,
     }
   }
 
   pub fn get_more_reviewers(&self) -> Vec<AccountId> {
     match self.clone() {
-      Self::MoreReviewers { more_reviewers } => more_reviewers,
+      Self::MoreReviewers { more_reviewers, .. } => more_reviewers,
       _ => env::panic_str("There are no other reviewers")
     }
   }
@@ -324,9 +474,10 @@ impl Default for KycConfig {
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub enum PlaceOfCheckKYC {
-  CreatingClaim { deadline: Option<U64>, description: String },
+  CreatingClaim { deadline: Option<U64>, description: String, team: Option<Vec<TeamMember>> },
   DecisionOnClaim { is_kyc_delayed: Option<DefermentOfKYC> },
   ClaimDone { description: String },
+  ClaimHandover { successor_id: AccountId },
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
@@ -353,6 +504,48 @@ pub struct Subtask {
   pub subtask_percent: u32,
 }
 
+/// Member of a team claim and the member's share of the reward.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct TeamMember {
+  pub account_id: AccountId,
+  /// Share of the reward, where 100_000 is 100%
+  pub payout_percent: u32,
+}
+
+/// Team claim waiting for all team members to accept their membership.
+/// The claim is created only after that.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct PendingTeamClaim {
+  pub deadline: Option<U64>,
+  pub description: String,
+  pub slot: Option<usize>,
+  pub team: Vec<TeamMember>,
+  /// Team members who have accepted their membership, the claim owner is one of them
+  pub accepted_members: Vec<AccountId>,
+  /// Bond attached by the claim owner
+  pub bond: U128,
+}
+
+impl PendingTeamClaim {
+  pub fn is_accepted(&self) -> bool {
+    self.team.iter().all(|member| self.accepted_members.contains(&member.account_id))
+  }
+}
+
+/// The account that receives the reward instead of the claimant.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct PayoutTarget {
+  pub receiver_id: AccountId,
+  /// If specified, the reward is transferred with ft_transfer_call and this message
+  pub msg: Option<String>,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
@@ -1126,16 +1319,12 @@ impl BountyCreate {
     payer_id: &AccountId,
     token_id: Option<AccountId>,
     amount: U128,
-    config: Config
+    config: Config,
+    min_amount_for_kyc: Option<U128>,
+    dao_details: Option<&ValidatorsDaoDetails>
   ) -> Bounty {
-    let (percentage_platform, percentage_dao) = Bounty::get_percentage_of_commissions(
-      config,
-      if self.reviewers.is_some() {
-        Some(self.reviewers.clone().unwrap().to_reviewers())
-      } else {
-        None
-      }
-    );
+    let (percentage_platform, percentage_dao) =
+      Bounty::get_percentage_of_commissions(config, dao_details);
 
     let platform_fee = if self.postpaid.is_none() {
       amount.0 * percentage_platform / (100_000 + percentage_dao)
@@ -1153,7 +1342,7 @@ impl BountyCreate {
       KycConfig::default()
     };
 
-    Bounty {
+    let mut bounty = Bounty {
       token: token_id,
       amount: U128(bounty_amount),
       platform_fee: U128(platform_fee),
@@ -1162,7 +1351,7 @@ impl BountyCreate {
       deadline: self.deadline.clone(),
       claimant_approval: self.claimant_approval.clone(),
       reviewers: if self.reviewers.is_some() {
-        Some(self.reviewers.clone().unwrap().to_reviewers())
+        Some(self.reviewers.clone().unwrap().to_reviewers(dao_details))
       } else {
         None
       },
@@ -1183,7 +1372,12 @@ impl BountyCreate {
       allow_deadline_stretch: self.allow_deadline_stretch.unwrap_or_default(),
       bounty_flow: self.bounty_flow.clone().unwrap_or_default(),
       allow_creating_many_claims: self.allow_creating_many_claims.unwrap_or_default(),
-    }
+      moderation: None,
+      finalized_at: None,
+      dao_fee_percentage: dao_details.map(|d| d.fee_percentage),
+    };
+    bounty.apply_min_amount_for_kyc(min_amount_for_kyc);
+    bounty
   }
 }
 
@@ -1197,6 +1391,17 @@ pub struct BountyUpdate {
   pub amount: Option<U128>,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct ValidatorsDaoV1 {
+  pub account_id: AccountId,
+  pub add_proposal_bond: U128,
+  pub gas_for_add_proposal: U64,
+  pub gas_for_claim_approval: U64,
+  pub gas_for_claimant_approval: U64,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
@@ -1206,6 +1411,20 @@ pub struct ValidatorsDao {
   pub gas_for_add_proposal: U64,
   pub gas_for_claim_approval: U64,
   pub gas_for_claimant_approval: U64,
+  pub dao_kind: DaoKind,
+}
+
+impl From<ValidatorsDaoV1> for ValidatorsDao {
+  fn from(validators_dao: ValidatorsDaoV1) -> Self {
+    ValidatorsDao {
+      account_id: validators_dao.account_id,
+      add_proposal_bond: validators_dao.add_proposal_bond,
+      gas_for_add_proposal: validators_dao.gas_for_add_proposal,
+      gas_for_claim_approval: validators_dao.gas_for_claim_approval,
+      gas_for_claimant_approval: validators_dao.gas_for_claimant_approval,
+      dao_kind: DaoKind::SputnikV2,
+    }
+  }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
@@ -1328,6 +1547,42 @@ pub struct BountyV6 {
   pub allow_creating_many_claims: bool,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct BountyModeration {
+  pub moderator: AccountId,
+  pub reason: String,
+  /// The bounty is excluded from the lists of bounties
+  pub hidden: bool,
+  /// Claims and any actions with the bounty are prohibited
+  pub frozen: bool,
+  pub updated_at: U64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct BountyV7 {
+  pub token: Option<AccountId>,
+  pub amount: U128,
+  pub platform_fee: U128,
+  pub dao_fee: U128,
+  pub metadata: BountyMetadata,
+  pub deadline: Deadline,
+  pub claimant_approval: ClaimantApproval,
+  pub reviewers: Option<Reviewers>,
+  pub owner: AccountId,
+  pub status: BountyStatus,
+  pub created_at: U64,
+  pub kyc_config: KycConfig,
+  pub postpaid: Option<Postpaid>,
+  pub multitasking: Option<Multitasking>,
+  pub allow_deadline_stretch: bool,
+  pub bounty_flow: BountyFlow,
+  pub allow_creating_many_claims: bool,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
@@ -1349,65 +1604,82 @@ pub struct Bounty {
   pub allow_deadline_stretch: bool,
   pub bounty_flow: BountyFlow,
   pub allow_creating_many_claims: bool,
+  pub moderation: Option<BountyModeration>,
+  /// When the bounty reached one of the final statuses
+  pub finalized_at: Option<U64>,
+  /// Fee rate of the validators DAO at the time of the bounty creation, where 100_000 is 100%.
+  /// Not set for the bounties created before the registry of validators DAOs.
+  pub dao_fee_percentage: Option<u32>,
 }
 
 impl Bounty {
+  /// Requires KYC of claimants if the bounty amount has reached the threshold of the token,
+  /// regardless of the setting chosen by the owner.
+  pub fn apply_min_amount_for_kyc(&mut self, min_amount_for_kyc: Option<U128>) {
+    if matches!(self.kyc_config, KycConfig::KycNotRequired) &&
+      min_amount_for_kyc.map_or(false, |min| self.amount.0 >= min.0)
+    {
+      self.kyc_config = KycConfig::default();
+    }
+  }
+
+  /// The amounts of the slots, subtasks or prize places of the multitasking bounty.
+  pub fn get_slot_amounts(&self) -> Vec<u128> {
+    match self.multitasking.clone() {
+      Some(Multitasking::OneForAll { number_of_slots, .. }) if number_of_slots > 0 =>
+        vec![self.amount.0 / number_of_slots as u128; number_of_slots as usize],
+      Some(Multitasking::DifferentTasks { subtasks, .. }) => subtasks
+        .iter()
+        .map(|subtask| self.amount.0 * subtask.subtask_percent as u128 / 100_000)
+        .collect(),
+      Some(Multitasking::ContestOrHackathon { prize_places: Some(prize_places), .. }) => prize_places
+        .iter()
+        .map(|place| place.place_amount.0)
+        .collect(),
+      _ => vec![],
+    }
+  }
+
   pub fn assert_valid(&self) {
-    assert!(
-      !self.metadata.title.is_empty(),
-      "The title cannot be empty"
-    );
-    assert!(
-      !self.metadata.description.is_empty(),
-      "The description cannot be empty"
-    );
-    assert!(
-      !self.metadata.category.is_empty(),
-      "The bounty type cannot be empty"
-    );
-    if self.metadata.attachments.is_some() {
-      assert!(
-        self.metadata.attachments.clone().unwrap().len() > 0,
-        "The expected number of attachments is greater than zero"
-      );
+    self.try_valid().unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  pub fn try_valid(&self) -> Result<(), String> {
+    if self.metadata.title.is_empty() {
+      return Err("The title cannot be empty".to_string());
+    }
+    if self.metadata.description.is_empty() {
+      return Err("The description cannot be empty".to_string());
+    }
+    if self.metadata.category.is_empty() {
+      return Err("The bounty type cannot be empty".to_string());
+    }
+    if self.metadata.attachments.is_some() && self.metadata.attachments.clone().unwrap().is_empty() {
+      return Err("The expected number of attachments is greater than zero".to_string());
+    }
+    if self.amount.0 == 0 {
+      return Err("The bounty amount is incorrect".to_string());
+    }
+    if self.is_payment_outside_contract() != self.token.is_none() {
+      return Err("Invalid token_id value".to_string());
     }
-    assert!(
-      self.amount.0 > 0,
-      "The bounty amount is incorrect",
-    );
-    assert!(
-      if self.is_payment_outside_contract() {
-        self.token.is_none()
-      } else {
-        self.token.is_some()
-      },
-      "Invalid token_id value"
-    );
     match self.deadline {
-      Deadline::DueDate { due_date } =>
-        assert!(
-          due_date.0 > env::block_timestamp(),
-          "Incorrect due date",
-        ),
-      Deadline::MaxDeadline { max_deadline } =>
-        assert!(
-          max_deadline.0 > 0,
-          "The max deadline is incorrect"
-        ),
-      Deadline::WithoutDeadline =>
-        assert!(
-          !self.allow_deadline_stretch,
-          "This bounty has no deadline, so the claimant must specify a deadline"
+      Deadline::DueDate { due_date } if due_date.0 <= env::block_timestamp() =>
+        return Err("Incorrect due date".to_string()),
+      Deadline::MaxDeadline { max_deadline } if max_deadline.0 == 0 =>
+        return Err("The max deadline is incorrect".to_string()),
+      Deadline::WithoutDeadline if self.allow_deadline_stretch =>
+        return Err(
+          "This bounty has no deadline, so the claimant must specify a deadline".to_string()
         ),
+      _ => (),
     }
-    if self.reviewers.clone().is_some() {
-      match self.reviewers.clone().unwrap() {
-        Reviewers::MoreReviewers { more_reviewers } =>
-          assert!(
-            more_reviewers.len() > 0,
-            "The expected number of reviewers is greater than zero",
-          ),
-        _ => (),
+    if let Some(Reviewers::MoreReviewers { more_reviewers, threshold }) = self.reviewers.clone() {
+      if more_reviewers.is_empty() {
+        return Err("The expected number of reviewers is greater than zero".to_string());
+      }
+      if threshold == 0 || threshold as usize > more_reviewers.len() {
+        return Err("The threshold must be between one and the number of reviewers".to_string());
       }
     }
     if self.multitasking.is_some() {
@@ -1419,57 +1691,40 @@ impl Bounty {
           prize_places,
           ..
         } => {
-          if allowed_create_claim_to.is_some() {
-            match allowed_create_claim_to.unwrap() {
-              DateOrPeriod::Date { date } => {
-                assert!(
-                  env::block_timestamp() < date.0 &&
-                    (
-                      !matches!(self.deadline, Deadline::DueDate { .. }) ||
-                        date.0 <= self.deadline.get_deadline_value().0
-                    ),
-                  "The date until which it is allowed to create claims is incorrect"
-                );
-              },
-              _ => (),
+          if let Some(DateOrPeriod::Date { date }) = allowed_create_claim_to {
+            if env::block_timestamp() >= date.0 ||
+              (
+                matches!(self.deadline, Deadline::DueDate { .. }) &&
+                  date.0 > self.deadline.get_deadline_value().0
+              )
+            {
+              return Err(
+                "The date until which it is allowed to create claims is incorrect".to_string()
+              );
             }
           }
-          if successful_claims_for_result.is_some() {
-            let successful_claims_for_result = successful_claims_for_result.unwrap();
-            assert!(
-              successful_claims_for_result > 1 && successful_claims_for_result <= MAX_SLOTS,
-              "Incorrect number of successful claims to obtain a result"
-            );
+          if let Some(successful_claims_for_result) = successful_claims_for_result {
+            if successful_claims_for_result <= 1 || successful_claims_for_result > MAX_SLOTS {
+              return Err("Incorrect number of successful claims to obtain a result".to_string());
+            }
           }
-          if start_conditions.is_some() {
-            match start_conditions.unwrap() {
-              StartConditions::MinAmountToStart { amount } =>
-                assert!(
-                  amount > 1,
-                  "The minimum number of claims to start is incorrect"
-                ),
-              _ => (),
+          if let Some(StartConditions::MinAmountToStart { amount }) = start_conditions {
+            if amount <= 1 {
+              return Err("The minimum number of claims to start is incorrect".to_string());
             }
           }
-          if prize_places.is_some() {
-            let places_of_winners = prize_places.unwrap();
-            assert!(
-              places_of_winners.len() > 0 && places_of_winners.len() <= MAX_SLOTS as usize,
-              "The number of places must be greater than zero"
-            );
-            assert!(
-              places_of_winners
-                .clone()
-                .into_iter()
-                .find(|subtask| subtask.place_description.is_empty())
-                .is_none(),
-              "The places description cannot be empty"
-            );
-            assert_eq!(
-              places_of_winners.into_iter().map(|subtask| subtask.place_amount.0).sum::<u128>(),
-              self.amount.0,
-              "The sum of all places must equal the bounty amount"
-            );
+          if let Some(places_of_winners) = prize_places {
+            if places_of_winners.is_empty() || places_of_winners.len() > MAX_SLOTS as usize {
+              return Err("The number of places must be greater than zero".to_string());
+            }
+            if places_of_winners.iter().any(|place| place.place_description.is_empty()) {
+              return Err("The places description cannot be empty".to_string());
+            }
+            if places_of_winners.iter().map(|place| place.place_amount.0).sum::<u128>() !=
+              self.amount.0
+            {
+              return Err("The sum of all places must equal the bounty amount".to_string());
+            }
           }
         },
         Multitasking::OneForAll {
@@ -1478,104 +1733,85 @@ impl Bounty {
           min_slots_to_start,
           ..
         } => {
-          assert!(
-            number_of_slots > 1,
-            "The number of slots must be greater than one"
-          );
-          assert!(
-            amount_per_slot.0 > 0,
-            "The cost of one slot cannot be zero"
-          );
-          if min_slots_to_start.is_some() {
-            assert!(
-              min_slots_to_start.unwrap() > 1 && min_slots_to_start.unwrap() <= MAX_SLOTS,
-              "The minimum number of claims to start is incorrect"
-            );
+          if number_of_slots <= 1 {
+            return Err("The number of slots must be greater than one".to_string());
+          }
+          if amount_per_slot.0 == 0 {
+            return Err("The cost of one slot cannot be zero".to_string());
+          }
+          if let Some(min_slots_to_start) = min_slots_to_start {
+            if min_slots_to_start <= 1 || min_slots_to_start > MAX_SLOTS {
+              return Err("The minimum number of claims to start is incorrect".to_string());
+            }
+          }
+          let total_amount = self.amount.0 + self.platform_fee.0;
+          let slots_amount = amount_per_slot.0 * number_of_slots as u128;
+          if total_amount < slots_amount ||
+            total_amount - slots_amount > MIN_DEVIATION_FOR_TOTAL_BOUNTY_AMOUNT
+          {
+            return Err("Total bounty amount is incorrect".to_string());
           }
-          assert!(
-            self.amount.0 + self.platform_fee.0 >= amount_per_slot.0 * number_of_slots as u128 &&
-            self.amount.0 + self.platform_fee.0 - amount_per_slot.0 * number_of_slots as u128 <=
-              MIN_DEVIATION_FOR_TOTAL_BOUNTY_AMOUNT,
-            "Total bounty amount is incorrect"
-          );
         },
         Multitasking::DifferentTasks { subtasks, .. } => {
-          assert!(
-            subtasks.len() > 1 && subtasks.len() <= MAX_SLOTS as usize,
-            "The number of subtasks must be greater than one"
-          );
-          assert!(
-            subtasks
-              .clone()
-              .into_iter()
-              .find(|subtask| subtask.subtask_description.is_empty())
-              .is_none(),
-            "The subtask description cannot be empty"
-          );
-          assert_eq!(
-            subtasks.into_iter().map(|subtask| subtask.subtask_percent).sum::<u32>(),
-            100_000,
-            "The sum of the cost of all subtasks must equal 100%"
-          );
+          if subtasks.len() <= 1 || subtasks.len() > MAX_SLOTS as usize {
+            return Err("The number of subtasks must be greater than one".to_string());
+          }
+          if subtasks.iter().any(|subtask| subtask.subtask_description.is_empty()) {
+            return Err("The subtask description cannot be empty".to_string());
+          }
+          if subtasks.iter().map(|subtask| subtask.subtask_percent).sum::<u32>() != 100_000 {
+            return Err("The sum of the cost of all subtasks must equal 100%".to_string());
+          }
         },
         _ => unreachable!(),
       }
     }
     if self.bounty_flow == BountyFlow::SimpleBounty {
-      assert!(
-        matches!(self.claimant_approval, ClaimantApproval::WithoutApproval) ||
-          matches!(self.claimant_approval, ClaimantApproval::ApprovalByWhitelist { .. }),
-        "claimant_approval and bounty_flow values are incompatible"
-      );
-      assert!(
-        !matches!(
-          self.kyc_config,
-          KycConfig::KycRequired {
-            kyc_verification_method: KycVerificationMethod::DuringClaimApproval
-          }
-        ),
-        "kyc_config and bounty_flow values are incompatible"
-      );
-      assert!(
-        !self.is_validators_dao_used(),
-        "Validators Dao cannot be used for Simple Bounty"
-      );
-      assert!(
-        !self.allow_deadline_stretch,
-        "allow_deadline_stretch and bounty_flow values are incompatible"
+      if !matches!(
+        self.claimant_approval,
+        ClaimantApproval::WithoutApproval | ClaimantApproval::ApprovalByWhitelist { .. }
+      ) {
+        return Err("claimant_approval and bounty_flow values are incompatible".to_string());
+      }
+      if matches!(
+        self.kyc_config,
+        KycConfig::KycRequired {
+          kyc_verification_method: KycVerificationMethod::DuringClaimApproval
+        }
+      ) {
+        return Err("kyc_config and bounty_flow values are incompatible".to_string());
+      }
+      if self.is_validators_dao_used() {
+        return Err("Validators Dao cannot be used for Simple Bounty".to_string());
+      }
+      if self.allow_deadline_stretch {
+        return Err("allow_deadline_stretch and bounty_flow values are incompatible".to_string());
+      }
+    }
+    if self.allow_creating_many_claims && self.multitasking.is_none() {
+      return Err(
+        "One account can create multiple claims only for multitasking bounties".to_string()
       );
     }
-    assert!(
-      !self.allow_creating_many_claims || self.multitasking.is_some(),
-      "One account can create multiple claims only for multitasking bounties"
-    );
+    Ok(())
   }
 
   pub fn check_access_rights(&self) {
-    let sender_id = env::predecessor_account_id();
-    if self.reviewers.is_some() {
-      match self.reviewers.clone().unwrap() {
-        Reviewers::ValidatorsDao { validators_dao } =>
-          assert_eq!(
-            validators_dao.account_id,
-            sender_id,
-            "This method can only call DAO validators"
-          ),
-        Reviewers::MoreReviewers { more_reviewers } => {
-          let mut all_reviewers = more_reviewers;
-          all_reviewers.push(self.owner.clone());
-          assert!(
-            all_reviewers.contains(&sender_id),
-            "This method can only be called by one of the reviewers"
-          );
-        }
-      }
-    } else {
-      assert_eq!(
-        self.owner,
-        sender_id,
-        "Only the owner of the bounty can call this method"
-      );
+    self.try_access_rights(&env::predecessor_account_id()).unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  pub fn try_access_rights(&self, account_id: &AccountId) -> Result<(), String> {
+    match self.reviewers.clone().map(Reviewers::upgrade_v1_to_v2) {
+      Some(Reviewers::ValidatorsDao { validators_dao }) if &validators_dao.account_id != account_id =>
+        Err("This method can only call DAO validators".to_string()),
+      Some(Reviewers::MoreReviewers { more_reviewers, .. })
+        if !more_reviewers.contains(account_id) && &self.owner != account_id =>
+        Err("This method can only be called by one of the reviewers".to_string()),
+      None if &self.owner != account_id =>
+        Err("Only the owner of the bounty can call this method".to_string()),
+      Some(Reviewers::ValidatorsDao { .. }) | Some(Reviewers::MoreReviewers { .. }) | None => Ok(()),
+      Some(Reviewers::ValidatorsDaoV1 { .. }) | Some(Reviewers::MoreReviewersV1 { .. }) =>
+        Err("The reviewers of the bounty have not been upgraded".to_string()),
     }
   }
 
@@ -1619,6 +1855,38 @@ impl Bounty {
     }
   }
 
+  pub fn is_hidden(&self) -> bool {
+    self.moderation.is_some() && self.moderation.clone().unwrap().hidden
+  }
+
+  pub fn is_frozen(&self) -> bool {
+    self.moderation.is_some() && self.moderation.clone().unwrap().frozen
+  }
+
+  pub fn assert_not_frozen(&self) {
+    self.try_not_frozen().unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  pub fn try_not_frozen(&self) -> Result<(), String> {
+    if self.is_frozen() {
+      return Err("The bounty is frozen by the moderator".to_string());
+    }
+    Ok(())
+  }
+
+  pub fn try_claim_number(&self, claim_number: Option<u8>) -> Result<(), String> {
+    if claim_number.is_some() != self.allow_creating_many_claims {
+      return Err("Invalid claim_number value".to_string());
+    }
+    Ok(())
+  }
+
+  pub fn is_finalized(&self) -> bool {
+    self.status == BountyStatus::Completed
+      || self.status == BountyStatus::Canceled
+      || self.status == BountyStatus::PartiallyCompleted
+  }
+
   pub fn is_validators_dao_used(&self) -> bool {
     self.reviewers.is_some() && match self.reviewers.clone().unwrap() {
       Reviewers::ValidatorsDao { .. } => true,
@@ -1638,36 +1906,29 @@ impl Bounty {
   }
 
   pub fn assert_account_is_not_owner_or_reviewer(&self, account_id: AccountId) {
-    assert_ne!(self.owner, account_id, "The predecessor cannot be a bounty owner");
-    if self.reviewers.clone().is_some() {
-      match self.reviewers.clone().unwrap() {
-        Reviewers::MoreReviewers { more_reviewers } =>
-          assert!(
-            !more_reviewers.contains(&account_id),
-            "The predecessor cannot be one of the reviewers"
-          ),
-        Reviewers::ValidatorsDao { validators_dao } =>
-          assert_ne!(
-            validators_dao.account_id,
-            account_id,
-            "The predecessor cannot be a validators DAO"
-          ),
-      }
+    self.try_account_is_not_owner_or_reviewer(&account_id).unwrap_or_else(|e| panic!("{}", e));
+  }
+
+  pub fn try_account_is_not_owner_or_reviewer(&self, account_id: &AccountId) -> Result<(), String> {
+    if &self.owner == account_id {
+      return Err("The predecessor cannot be a bounty owner".to_string());
+    }
+    match self.reviewers.clone() {
+      Some(Reviewers::MoreReviewers { more_reviewers, .. }) if more_reviewers.contains(account_id) =>
+        Err("The predecessor cannot be one of the reviewers".to_string()),
+      Some(Reviewers::ValidatorsDao { validators_dao }) if &validators_dao.account_id == account_id =>
+        Err("The predecessor cannot be a validators DAO".to_string()),
+      _ => Ok(()),
     }
   }
 
+  /// The fee of the validators DAO is taken from its registry entry
   pub fn get_percentage_of_commissions(
     config: Config,
-    reviewers: Option<Reviewers>
+    dao_details: Option<&ValidatorsDaoDetails>
   ) -> (u128, u128) {
     let percentage_platform: u128 = config.platform_fee_percentage.into();
-    let percentage_dao: u128 = if reviewers.is_some() {
-      match reviewers.unwrap() {
-        Reviewers::ValidatorsDao { .. } =>
-          config.validators_dao_fee_percentage.into(),
-        _ => 0
-      }
-    } else { 0 };
+    let percentage_dao: u128 = dao_details.map_or(0, |d| d.fee_percentage.into());
     (percentage_platform, percentage_dao)
   }
 
@@ -1687,6 +1948,16 @@ impl Bounty {
     self.postpaid.is_some() &&
       matches!(self.postpaid.clone().unwrap(), Postpaid::PaymentOutsideContract { .. })
   }
+
+  pub fn get_index_keys(&self) -> BountyIndexKeys {
+    BountyIndexKeys {
+      status: self.status.clone(),
+      category: self.metadata.category.clone(),
+      tags: self.metadata.tags.clone().unwrap_or_default(),
+      token: self.token.clone(),
+      reviewers: self.reviewers.clone().map(|r| r.get_reviewer_accounts()).unwrap_or_default(),
+    }
+  }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
@@ -1698,6 +1969,7 @@ pub enum VersionedBounty {
   V4(BountyV4),
   V5(BountyV5),
   V6(BountyV6),
+  V7(BountyV7),
   Current(Bounty),
 }
 
@@ -1821,14 +2093,14 @@ impl VersionedBounty {
     }
   }
 
-  fn upgrade_v6_to_v7(bounty: BountyV6) -> Bounty {
+  fn upgrade_v6_to_v7(bounty: BountyV6) -> BountyV7 {
     let multitasking = if bounty.multitasking.is_some() {
       Some(bounty.multitasking.clone().unwrap().upgrade_v2_to_v3())
     } else {
       None
     };
 
-    Bounty {
+    BountyV7 {
       token: bounty.token,
       amount: bounty.amount,
       platform_fee: bounty.platform_fee,
@@ -1849,50 +2121,90 @@ impl VersionedBounty {
     }
   }
 
+  fn upgrade_v7_to_v8(bounty: BountyV7) -> Bounty {
+    Bounty {
+      token: bounty.token,
+      amount: bounty.amount,
+      platform_fee: bounty.platform_fee,
+      dao_fee: bounty.dao_fee,
+      metadata: bounty.metadata,
+      deadline: bounty.deadline,
+      claimant_approval: bounty.claimant_approval,
+      reviewers: bounty.reviewers.map(|reviewers| reviewers.upgrade_v1_to_v2()),
+      owner: bounty.owner,
+      status: bounty.status,
+      created_at: bounty.created_at,
+      kyc_config: bounty.kyc_config,
+      postpaid: bounty.postpaid,
+      multitasking: bounty.multitasking,
+      allow_deadline_stretch: bounty.allow_deadline_stretch,
+      bounty_flow: bounty.bounty_flow,
+      allow_creating_many_claims: bounty.allow_creating_many_claims,
+      moderation: None,
+      finalized_at: None,
+      dao_fee_percentage: None,
+    }
+  }
+
   pub fn to_bounty(self) -> Bounty {
     match self {
       VersionedBounty::Current(bounty) => bounty,
       VersionedBounty::V1(bounty_v1) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(
-            VersionedBounty::upgrade_v4_to_v5(
-              VersionedBounty::upgrade_v3_to_v4(
-                VersionedBounty::upgrade_v2_to_v3(
-                  VersionedBounty::upgrade_v1_to_v2(bounty_v1)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(
+              VersionedBounty::upgrade_v4_to_v5(
+                VersionedBounty::upgrade_v3_to_v4(
+                  VersionedBounty::upgrade_v2_to_v3(
+                    VersionedBounty::upgrade_v1_to_v2(bounty_v1)
+                  )
                 )
               )
             )
-          ),        ),
+          )
+        ),
       VersionedBounty::V2(bounty_v2) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(
-            VersionedBounty::upgrade_v4_to_v5(
-              VersionedBounty::upgrade_v3_to_v4(
-                VersionedBounty::upgrade_v2_to_v3(bounty_v2)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(
+              VersionedBounty::upgrade_v4_to_v5(
+                VersionedBounty::upgrade_v3_to_v4(
+                  VersionedBounty::upgrade_v2_to_v3(bounty_v2)
+                )
               )
             )
           )
         ),
       VersionedBounty::V3(bounty_v3) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(
-            VersionedBounty::upgrade_v4_to_v5(
-              VersionedBounty::upgrade_v3_to_v4(bounty_v3)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(
+              VersionedBounty::upgrade_v4_to_v5(
+                VersionedBounty::upgrade_v3_to_v4(bounty_v3)
+              )
             )
           )
         ),
       VersionedBounty::V4(bounty_v4) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(
-            VersionedBounty::upgrade_v4_to_v5(bounty_v4)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(
+              VersionedBounty::upgrade_v4_to_v5(bounty_v4)
+            )
           )
         ),
       VersionedBounty::V5(bounty_v5) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(bounty_v5)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(bounty_v5)
+          )
         ),
       VersionedBounty::V6(bounty_v6) =>
-        VersionedBounty::upgrade_v6_to_v7(bounty_v6)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(bounty_v6)
+        ),
+      VersionedBounty::V7(bounty_v7) =>
+        VersionedBounty::upgrade_v7_to_v8(bounty_v7)
     }
   }
 }
@@ -1902,46 +2214,61 @@ impl From<VersionedBounty> for Bounty {
     match value {
       VersionedBounty::Current(bounty) => bounty,
       VersionedBounty::V1(bounty_v1) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(
-            VersionedBounty::upgrade_v4_to_v5(
-              VersionedBounty::upgrade_v3_to_v4(
-                VersionedBounty::upgrade_v2_to_v3(
-                  VersionedBounty::upgrade_v1_to_v2(bounty_v1)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(
+              VersionedBounty::upgrade_v4_to_v5(
+                VersionedBounty::upgrade_v3_to_v4(
+                  VersionedBounty::upgrade_v2_to_v3(
+                    VersionedBounty::upgrade_v1_to_v2(bounty_v1)
+                  )
                 )
               )
             )
-          ),        ),
+          )
+        ),
       VersionedBounty::V2(bounty_v2) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(
-            VersionedBounty::upgrade_v4_to_v5(
-              VersionedBounty::upgrade_v3_to_v4(
-                VersionedBounty::upgrade_v2_to_v3(bounty_v2)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(
+              VersionedBounty::upgrade_v4_to_v5(
+                VersionedBounty::upgrade_v3_to_v4(
+                  VersionedBounty::upgrade_v2_to_v3(bounty_v2)
+                )
               )
             )
           )
         ),
       VersionedBounty::V3(bounty_v3) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(
-            VersionedBounty::upgrade_v4_to_v5(
-              VersionedBounty::upgrade_v3_to_v4(bounty_v3)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(
+              VersionedBounty::upgrade_v4_to_v5(
+                VersionedBounty::upgrade_v3_to_v4(bounty_v3)
+              )
             )
           )
         ),
       VersionedBounty::V4(bounty_v4) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(
-            VersionedBounty::upgrade_v4_to_v5(bounty_v4)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(
+              VersionedBounty::upgrade_v4_to_v5(bounty_v4)
+            )
           )
         ),
       VersionedBounty::V5(bounty_v5) =>
-        VersionedBounty::upgrade_v6_to_v7(
-          VersionedBounty::upgrade_v5_to_v6(bounty_v5)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(
+            VersionedBounty::upgrade_v5_to_v6(bounty_v5)
+          )
         ),
       VersionedBounty::V6(bounty_v6) =>
-        VersionedBounty::upgrade_v6_to_v7(bounty_v6)
+        VersionedBounty::upgrade_v7_to_v8(
+          VersionedBounty::upgrade_v6_to_v7(bounty_v6)
+        ),
+      VersionedBounty::V7(bounty_v7) =>
+        VersionedBounty::upgrade_v7_to_v8(bounty_v7)
     }
   }
 }
@@ -1957,7 +2284,45 @@ impl From<Bounty> for VersionedBounty {
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
 pub struct TokenDetails {
   pub enabled: bool,
+  /// Bounties with an amount without fees not less than this always require KYC of claimants
   pub min_amount_for_kyc: Option<U128>,
+  /// Bounties with an amount without fees greater than this can only be created by owners who have passed KYB
+  pub min_amount_for_kyb: Option<U128>,
+  /// Symbol cached from the token metadata
+  pub symbol: Option<String>,
+  /// Decimals cached from the token metadata
+  pub decimals: Option<u8>,
+  /// The minimum bounty amount without fees
+  pub min_bounty_amount: Option<U128>,
+  /// The maximum bounty amount without fees
+  pub max_bounty_amount: Option<U128>,
+  /// The minimum amount of one slot, subtask or prize place of a multitasking bounty
+  pub min_slot_amount: Option<U128>,
+}
+
+impl TokenDetails {
+  pub fn try_valid(&self) -> Result<(), String> {
+    match (self.min_bounty_amount, self.max_bounty_amount) {
+      (Some(min), Some(max)) if min.0 > max.0 =>
+        Err("The minimum bounty amount cannot be greater than the maximum".to_string()),
+      _ => Ok(()),
+    }
+  }
+
+  /// Checks the bounty amount and the amounts of its slots against the token limits.
+  pub fn try_bounty_amount_within_limits(&self, bounty: &Bounty) -> Result<(), String> {
+    if self.min_bounty_amount.map_or(false, |min| bounty.amount.0 < min.0) {
+      return Err("The bounty amount is less than the minimum for this token".to_string());
+    }
+    if self.max_bounty_amount.map_or(false, |max| bounty.amount.0 > max.0) {
+      return Err("The bounty amount is greater than the maximum for this token".to_string());
+    }
+    let min_slot_amount = bounty.get_slot_amounts().into_iter().min();
+    if matches!((self.min_slot_amount, min_slot_amount), (Some(min), Some(amount)) if amount < min.0) {
+      return Err("The amount of one slot or place is less than the minimum for this token".to_string());
+    }
+    Ok(())
+  }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq)]
@@ -1976,7 +2341,8 @@ pub enum ClaimStatus {
   NotHired,
   Competes,
   ReadyToStart,
-  CompletedWithDispute
+  CompletedWithDispute,
+  HandedOver,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
@@ -1987,6 +2353,28 @@ pub enum DefermentOfKYC {
   BeforeDeadline,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct BountyClaimV7 {
+  pub owner: AccountId,
+  pub bounty_id: BountyIndex,
+  pub created_at: U64,
+  pub start_time: Option<U64>,
+  pub deadline: Option<U64>,
+  pub description: String,
+  pub status: ClaimStatus,
+  pub bounty_payout_proposal_id: Option<U64>,
+  pub approve_claimant_proposal_id: Option<U64>,
+  pub rejected_timestamp: Option<U64>,
+  pub dispute_id: Option<U64>,
+  pub is_kyc_delayed: Option<DefermentOfKYC>,
+  pub payment_timestamps: Option<PaymentTimestamps>,
+  pub slot: Option<usize>,
+  pub bond: Option<U128>,
+  pub claim_number: Option<u8>,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
@@ -2023,9 +2411,101 @@ pub struct BountyClaim {
   pub bond: Option<U128>,
   /// Claim number within one account
   pub claim_number: Option<u8>,
+  /// Team members who share the reward, the claim owner is one of them
+  pub team: Option<Vec<TeamMember>>,
+  /// Team members whose share has already been transferred or recorded as a pending payout
+  pub paid_team_members: Option<Vec<AccountId>>,
+  /// Freelancer proposed by the claimant to take over the claim
+  pub handover_to: Option<AccountId>,
+  /// Whether the bounty owner or a reviewer has accepted the proposed handover
+  pub handover_accepted: bool,
+  /// The account that receives the reward instead of the claimant
+  pub payout_target: Option<PayoutTarget>,
+  /// Votes of the reviewers that have not yet reached the threshold
+  pub reviewer_votes: Option<Vec<ReviewerVote>>,
 }
 
 impl BountyClaim {
+  /// Replaces the previous vote of the reviewer on the same decision.
+  /// Returns the number of reviewers who have voted the same way.
+  /// Records the vote and returns the number of the same votes. The votes of the accounts
+  /// that are no longer reviewers are removed and not counted.
+  pub fn add_reviewer_vote(&mut self, vote: ReviewerVote, reviewers: &[AccountId]) -> usize {
+    let mut votes = self.reviewer_votes.take().unwrap_or_default();
+    votes.retain(|v| reviewers.contains(&v.reviewer) && (v.reviewer != vote.reviewer || v.kind != vote.kind));
+    let count = votes
+      .iter()
+      .filter(|v| v.kind == vote.kind && v.approve == vote.approve && v.place == vote.place)
+      .count() + 1;
+    votes.push(vote);
+    self.reviewer_votes = Some(votes);
+    count
+  }
+
+  pub fn clear_reviewer_votes(&mut self, kind: PendingReviewKind) {
+    let votes: Vec<ReviewerVote> = self.reviewer_votes
+      .take()
+      .unwrap_or_default()
+      .into_iter()
+      .filter(|v| v.kind != kind)
+      .collect();
+    self.reviewer_votes = if votes.is_empty() { None } else { Some(votes) };
+  }
+
+  /// Checks the team passed when creating a claim.
+  pub fn try_valid_team(owner: &AccountId, team: &[TeamMember]) -> Result<(), String> {
+    if team.len() < 2 || team.len() > MAX_TEAM_MEMBERS {
+      return Err(format!("The team must have from 2 to {} members", MAX_TEAM_MEMBERS));
+    }
+    if !team.iter().any(|member| &member.account_id == owner) {
+      return Err("The claim owner must be a member of the team".to_string());
+    }
+    let accounts: HashSet<&AccountId> = team.iter().map(|member| &member.account_id).collect();
+    if accounts.len() != team.len() {
+      return Err("Team members must be unique".to_string());
+    }
+    if team.iter().any(|member| member.payout_percent == 0) {
+      return Err("The payout share of a team member cannot be zero".to_string());
+    }
+    if team.iter().map(|member| member.payout_percent).sum::<u32>() != 100_000 {
+      return Err("The sum of the payout shares of all team members must equal 100%".to_string());
+    }
+    Ok(())
+  }
+
+  /// Accounts that share the reward of the claim.
+  pub fn get_members(&self) -> Vec<AccountId> {
+    match self.team.clone() {
+      Some(team) => team.into_iter().map(|member| member.account_id).collect(),
+      None => vec![self.owner.clone()],
+    }
+  }
+
+  /// Splits the amount between the team members who have not yet been paid.
+  /// The rounding remainder goes to the last member.
+  pub fn get_team_payouts(&self, amount: U128) -> Vec<(AccountId, U128)> {
+    let team = self.team.clone().expect("The claim has no team");
+    let paid_team_members = self.paid_team_members.clone().unwrap_or_default();
+    let mut payouts: Vec<(AccountId, U128)> = team
+      .iter()
+      .map(|member| (
+        member.account_id.clone(),
+        U128(amount.0 * member.payout_percent as u128 / 100_000)
+      ))
+      .collect();
+    let other_members: u128 = payouts[..payouts.len() - 1].iter().map(|(_, a)| a.0).sum();
+    payouts.last_mut().unwrap().1 = U128(amount.0 - other_members);
+    payouts
+      .into_iter()
+      .filter(|(account_id, _)| !paid_team_members.contains(account_id))
+      .collect()
+  }
+
+  pub fn is_team_paid(&self) -> bool {
+    let paid_team_members = self.paid_team_members.clone().unwrap_or_default();
+    self.get_members().iter().all(|account_id| paid_team_members.contains(account_id))
+  }
+
   pub fn get_start_time(&self, bounty: &Bounty) -> U64 {
     if bounty.is_contest_or_hackathon() {
       let multitasking = bounty.multitasking.clone().unwrap();
@@ -2063,22 +2543,50 @@ impl BountyClaim {
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub enum VersionedBountyClaim {
+  V7(BountyClaimV7),
   Current(BountyClaim),
 }
 
 impl VersionedBountyClaim {
+  fn upgrade_v7_to_v8(bounty_claim: BountyClaimV7) -> BountyClaim {
+    BountyClaim {
+      owner: bounty_claim.owner,
+      bounty_id: bounty_claim.bounty_id,
+      created_at: bounty_claim.created_at,
+      start_time: bounty_claim.start_time,
+      deadline: bounty_claim.deadline,
+      description: bounty_claim.description,
+      status: bounty_claim.status,
+      bounty_payout_proposal_id: bounty_claim.bounty_payout_proposal_id,
+      approve_claimant_proposal_id: bounty_claim.approve_claimant_proposal_id,
+      rejected_timestamp: bounty_claim.rejected_timestamp,
+      dispute_id: bounty_claim.dispute_id,
+      is_kyc_delayed: bounty_claim.is_kyc_delayed,
+      payment_timestamps: bounty_claim.payment_timestamps,
+      slot: bounty_claim.slot,
+      bond: bounty_claim.bond,
+      claim_number: bounty_claim.claim_number,
+      team: None,
+      paid_team_members: None,
+      handover_to: None,
+      handover_accepted: false,
+      payout_target: None,
+      reviewer_votes: None,
+    }
+  }
+
   pub fn to_bounty_claim(self) -> BountyClaim {
     match self {
       VersionedBountyClaim::Current(bounty_claim) => bounty_claim,
+      VersionedBountyClaim::V7(bounty_claim_v7) =>
+        VersionedBountyClaim::upgrade_v7_to_v8(bounty_claim_v7),
     }
   }
 }
 
 impl From<VersionedBountyClaim> for BountyClaim {
   fn from(value: VersionedBountyClaim) -> Self {
-    match value {
-      VersionedBountyClaim::Current(bounty_claim) => bounty_claim,
-    }
+    value.to_bounty_claim()
   }
 }
 
@@ -2088,8 +2596,9 @@ impl From<BountyClaim> for VersionedBountyClaim {
   }
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
 pub enum ReferenceType {
   Categories,
   Tags,
@@ -2109,6 +2618,10 @@ pub struct ConfigCreate {
   pub penalty_validators_dao_fee_percentage: u32,
   pub use_owners_whitelist: bool,
   pub max_due_date: Option<U64>,
+  pub timelock_delay: Option<U64>,
+  pub admin_approval_threshold: Option<u16>,
+  pub archive_retention_period: Option<U64>,
+  pub ft_storage_deposit: Option<U128>,
 }
 
 impl ConfigCreate {
@@ -2126,6 +2639,12 @@ impl ConfigCreate {
       currencies: config.currencies,
       use_owners_whitelist: self.use_owners_whitelist,
       max_due_date: self.max_due_date,
+      timelock_delay: self.timelock_delay.unwrap_or(config.timelock_delay),
+      admin_approval_threshold: self.admin_approval_threshold
+        .unwrap_or(config.admin_approval_threshold),
+      archive_retention_period: self.archive_retention_period
+        .unwrap_or(config.archive_retention_period),
+      ft_storage_deposit: self.ft_storage_deposit.unwrap_or(config.ft_storage_deposit),
     }
   }
 }
@@ -2178,6 +2697,24 @@ pub struct ConfigV3 {
   pub use_owners_whitelist: bool,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct ConfigV4 {
+  pub bounty_claim_bond: U128,
+  pub bounty_forgiveness_period: U64,
+  pub period_for_opening_dispute: U64,
+  pub categories: Vec<String>,
+  pub tags: Vec<String>,
+  pub platform_fee_percentage: u32,
+  pub validators_dao_fee_percentage: u32,
+  pub penalty_platform_fee_percentage: u32,
+  pub penalty_validators_dao_fee_percentage: u32,
+  pub currencies: Vec<String>,
+  pub use_owners_whitelist: bool,
+  pub max_due_date: Option<U64>,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
@@ -2194,6 +2731,14 @@ pub struct Config {
   pub currencies: Vec<String>,
   pub use_owners_whitelist: bool,
   pub max_due_date: Option<U64>,
+  /// The delay before a queued change of the configuration or linked contracts can be executed
+  pub timelock_delay: U64,
+  /// The number of confirmations from admins required to execute a critical admin action
+  pub admin_approval_threshold: u16,
+  /// The period after the bounty is finalized during which its records are kept in full
+  pub archive_retention_period: U64,
+  /// The amount paid from the storage subsidy pool to register a reward receiver on the token
+  pub ft_storage_deposit: U128,
 }
 
 impl Config {
@@ -2229,6 +2774,10 @@ impl Default for Config {
       currencies: vec![],
       use_owners_whitelist: false,
       max_due_date: Config::default_max_due_date(),
+      timelock_delay: DEFAULT_TIMELOCK_DELAY,
+      admin_approval_threshold: DEFAULT_ADMIN_APPROVAL_THRESHOLD,
+      archive_retention_period: DEFAULT_ARCHIVE_RETENTION_PERIOD,
+      ft_storage_deposit: DEFAULT_FT_STORAGE_DEPOSIT,
     }
   }
 }
@@ -2239,6 +2788,7 @@ pub enum VersionedConfig {
   V1(ConfigV1),
   V2(ConfigV2),
   V3(ConfigV3),
+  V4(ConfigV4),
   Current(Config),
 }
 
@@ -2274,8 +2824,8 @@ impl VersionedConfig {
     }
   }
 
-  fn upgrade_v3_to_v4(config: ConfigV3) -> Config {
-    Config {
+  fn upgrade_v3_to_v4(config: ConfigV3) -> ConfigV4 {
+    ConfigV4 {
       bounty_claim_bond: config.bounty_claim_bond,
       bounty_forgiveness_period: config.bounty_forgiveness_period,
       period_for_opening_dispute: config.period_for_opening_dispute,
@@ -2291,18 +2841,46 @@ impl VersionedConfig {
     }
   }
 
+  fn upgrade_v4_to_v5(config: ConfigV4) -> Config {
+    Config {
+      bounty_claim_bond: config.bounty_claim_bond,
+      bounty_forgiveness_period: config.bounty_forgiveness_period,
+      period_for_opening_dispute: config.period_for_opening_dispute,
+      categories: config.categories,
+      tags: config.tags,
+      platform_fee_percentage: config.platform_fee_percentage,
+      validators_dao_fee_percentage: config.validators_dao_fee_percentage,
+      penalty_platform_fee_percentage: config.penalty_platform_fee_percentage,
+      penalty_validators_dao_fee_percentage: config.penalty_validators_dao_fee_percentage,
+      currencies: config.currencies,
+      use_owners_whitelist: config.use_owners_whitelist,
+      max_due_date: config.max_due_date,
+      timelock_delay: DEFAULT_TIMELOCK_DELAY,
+      admin_approval_threshold: DEFAULT_ADMIN_APPROVAL_THRESHOLD,
+      archive_retention_period: DEFAULT_ARCHIVE_RETENTION_PERIOD,
+      ft_storage_deposit: DEFAULT_FT_STORAGE_DEPOSIT,
+    }
+  }
+
   pub fn to_config(self) -> Config {
     match self {
       VersionedConfig::Current(config) => config,
-      VersionedConfig::V1(config_v1) => VersionedConfig::upgrade_v3_to_v4(
-        VersionedConfig::upgrade_v2_to_v3(
-          VersionedConfig::upgrade_v1_to_v2(config_v1)
+      VersionedConfig::V1(config_v1) => VersionedConfig::upgrade_v4_to_v5(
+        VersionedConfig::upgrade_v3_to_v4(
+          VersionedConfig::upgrade_v2_to_v3(
+            VersionedConfig::upgrade_v1_to_v2(config_v1)
+          )
         )
       ),
-      VersionedConfig::V2(config_v2) => VersionedConfig::upgrade_v3_to_v4(
-        VersionedConfig::upgrade_v2_to_v3(config_v2)
+      VersionedConfig::V2(config_v2) => VersionedConfig::upgrade_v4_to_v5(
+        VersionedConfig::upgrade_v3_to_v4(
+          VersionedConfig::upgrade_v2_to_v3(config_v2)
+        )
+      ),
+      VersionedConfig::V3(config_v3) => VersionedConfig::upgrade_v4_to_v5(
+        VersionedConfig::upgrade_v3_to_v4(config_v3)
       ),
-      VersionedConfig::V3(config_v3) => VersionedConfig::upgrade_v3_to_v4(config_v3),
+      VersionedConfig::V4(config_v4) => VersionedConfig::upgrade_v4_to_v5(config_v4),
     }
   }
 
@@ -2320,15 +2898,22 @@ impl From<VersionedConfig> for Config {
   fn from(value: VersionedConfig) -> Self {
     match value {
       VersionedConfig::Current(config) => config,
-      VersionedConfig::V1(config_v1) => VersionedConfig::upgrade_v3_to_v4(
-        VersionedConfig::upgrade_v2_to_v3(
-          VersionedConfig::upgrade_v1_to_v2(config_v1)
+      VersionedConfig::V1(config_v1) => VersionedConfig::upgrade_v4_to_v5(
+        VersionedConfig::upgrade_v3_to_v4(
+          VersionedConfig::upgrade_v2_to_v3(
+            VersionedConfig::upgrade_v1_to_v2(config_v1)
+          )
+        )
+      ),
+      VersionedConfig::V2(config_v2) => VersionedConfig::upgrade_v4_to_v5(
+        VersionedConfig::upgrade_v3_to_v4(
+          VersionedConfig::upgrade_v2_to_v3(config_v2)
         )
       ),
-      VersionedConfig::V2(config_v2) => VersionedConfig::upgrade_v3_to_v4(
-        VersionedConfig::upgrade_v2_to_v3(config_v2)
+      VersionedConfig::V3(config_v3) => VersionedConfig::upgrade_v4_to_v5(
+        VersionedConfig::upgrade_v3_to_v4(config_v3)
       ),
-      VersionedConfig::V3(config_v3) => VersionedConfig::upgrade_v3_to_v4(config_v3),
+      VersionedConfig::V4(config_v4) => VersionedConfig::upgrade_v4_to_v5(config_v4),
     }
   }
 }
@@ -2417,6 +3002,296 @@ impl DaoFeeStats {
   }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct PlatformFeeRecipient {
+  pub account_id: AccountId,
+  /// The share of the platform fee in relation to the total weight of all recipients
+  pub weight: u32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct PlatformFeeShare {
+  pub account_id: AccountId,
+  /// The part of the platform fee allocated to the recipient and not yet transferred
+  pub amount: U128,
+  pub in_progress: bool,
+}
+
+/// The reward whose transfer has failed. It can be transferred again with the withdraw_pending_payout method.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct PendingPayout {
+  pub token_id: AccountId,
+  pub amount: U128,
+  pub in_progress: bool,
+}
+
+/// Changes that take effect only after the timelock delay specified in the configuration.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub enum TimelockedAction {
+  ChangeConfig { config_create: ConfigCreate },
+  UpdateDisputeContract { dispute_contract: AccountId },
+  UpdateReputationContract { reputation_contract: AccountId },
+  UpdateKycWhitelistContract { kyc_whitelist_contract: Option<AccountId> },
+  ChangeRecipientOfPlatformFee { recipient_of_platform_fee: AccountId },
+  ChangePlatformFeeRecipients { recipients: Vec<PlatformFeeRecipient> },
+}
+
+impl TimelockedAction {
+  pub fn get_role(&self) -> Role {
+    match self {
+      TimelockedAction::ChangeRecipientOfPlatformFee { .. } |
+      TimelockedAction::ChangePlatformFeeRecipients { .. } => Role::FeeManager,
+      _ => Role::ConfigManager,
+    }
+  }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct QueuedChange {
+  pub action: TimelockedAction,
+  pub proposer: AccountId,
+  pub queued_at: U64,
+  pub executable_at: U64,
+}
+
+/// Critical admin actions that are executed only after the confirmations
+/// of the number of admins specified in the configuration.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub enum AdminAction {
+  AddToWhitelist { account_ids: Vec<AccountId>, whitelist_type: WhitelistType },
+  RemoveFromWhitelist { account_ids: Vec<AccountId>, whitelist_type: WhitelistType },
+  AddToken { token_id: AccountId, min_amount_for_kyc: Option<U128> },
+  UpdateToken { token_id: AccountId, token_details: TokenDetails },
+  ChangeConfig { config_create: ConfigCreate },
+  ChangePlatformFeeRecipients { recipients: Vec<PlatformFeeRecipient> },
+  UpdateConfigurationDictionaryEntries { dict: ReferenceType, entries: Vec<String> },
+  RemoveConfigurationDictionaryEntries { dict: ReferenceType, entries: Vec<String> },
+  WithdrawPlatformFee { token_id: AccountId },
+  DistributePlatformFee { token_ids: Option<Vec<AccountId>> },
+  WithdrawNonRefundedBonds,
+  StageCode { code_hash: Base58CryptoHash },
+  DeployStagedCode { code_hash: Base58CryptoHash, migrate_args: Option<Base64VecU8> },
+  MigrateBounties { limit: u64 },
+  ReroutePendingPayout { account_id: AccountId, token_id: AccountId, new_account_id: AccountId },
+  AddValidatorsDao { dao_account_id: AccountId, dao_details: ValidatorsDaoDetails },
+  UpdateValidatorsDao { dao_account_id: AccountId, dao_details: ValidatorsDaoDetails },
+  RemoveValidatorsDao { dao_account_id: AccountId },
+  GrantRole { role: Role, account_id: AccountId },
+  RevokeRole { role: Role, account_id: AccountId },
+  WithdrawStorageSubsidyPool { amount: U128 },
+}
+
+impl AdminAction {
+  /// The role whose holders can confirm the action, None means that only super-admins can do it
+  pub fn get_role(&self) -> Option<Role> {
+    match self {
+      AdminAction::StageCode { .. } |
+      AdminAction::DeployStagedCode { .. } |
+      AdminAction::MigrateBounties { .. } |
+      AdminAction::ReroutePendingPayout { .. } |
+      AdminAction::GrantRole { .. } |
+      AdminAction::RevokeRole { .. } => None,
+      AdminAction::AddToWhitelist { whitelist_type, .. } |
+      AdminAction::RemoveFromWhitelist { whitelist_type, .. } => match whitelist_type {
+        WhitelistType::AdministratorsWhitelist => None,
+        _ => Some(Role::WhitelistManager),
+      },
+      AdminAction::AddToken { .. } | AdminAction::UpdateToken { .. } => Some(Role::TokenManager),
+      AdminAction::ChangeConfig { .. } |
+      AdminAction::AddValidatorsDao { .. } |
+      AdminAction::UpdateValidatorsDao { .. } |
+      AdminAction::RemoveValidatorsDao { .. } |
+      AdminAction::UpdateConfigurationDictionaryEntries { .. } |
+      AdminAction::RemoveConfigurationDictionaryEntries { .. } => Some(Role::ConfigManager),
+      _ => Some(Role::FeeManager),
+    }
+  }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct PendingAdminAction {
+  pub action: AdminAction,
+  pub proposer: AccountId,
+  pub proposed_at: U64,
+  /// Admins who have confirmed the action
+  pub confirmations: Vec<AccountId>,
+}
+
+/// NEP-145 storage balance of the account
+#[derive(BorshSerialize, BorshDeserialize, Clone, Default)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct AccountStorage {
+  /// The amount of $NEAR deposited for storage, including the registration
+  pub deposit: Balance,
+  /// The number of bytes of the contract storage paid by the account
+  pub used_bytes: StorageUsage,
+}
+
+/// Criteria of the get_bounties_filtered view, all specified criteria must match.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct BountyFilter {
+  /// The bounty has one of the statuses
+  pub statuses: Option<Vec<BountyStatus>>,
+  pub category: Option<String>,
+  /// The bounty has all the tags
+  pub tags: Option<Vec<String>>,
+  pub token: Option<AccountId>,
+  pub experience: Option<Experience>,
+  pub owner: Option<AccountId>,
+}
+
+/// The values of the bounty fields used as keys of the bounty indexes. They are taken before
+/// the bounty is changed, so that the indexes can be updated without reading the stored bounty.
+#[derive(Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct BountyIndexKeys {
+  pub status: BountyStatus,
+  pub category: String,
+  pub tags: Vec<String>,
+  pub token: Option<AccountId>,
+  pub reviewers: Vec<AccountId>,
+}
+
+impl BountyFilter {
+  pub fn matches(&self, bounty: &Bounty) -> bool {
+    let bounty_tags = bounty.metadata.tags.clone().unwrap_or_default();
+    self.statuses.iter().all(|s| s.contains(&bounty.status))
+      && self.category.iter().all(|c| c == &bounty.metadata.category)
+      && self.tags.iter().flatten().all(|tag| bounty_tags.contains(tag))
+      && self.token.iter().all(|t| bounty.token.as_ref() == Some(t))
+      && self.experience.iter().all(|e| bounty.metadata.experience.as_ref() == Some(e))
+      && self.owner.iter().all(|o| o == &bounty.owner)
+  }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug))]
+pub enum PendingReviewKind {
+  /// A new claim is waiting for the claimant to be approved
+  ClaimantApproval,
+  /// The completed claim is waiting for the result to be accepted or rejected
+  ResultVerdict,
+}
+
+/// Decision of one of the reviewers on the claim.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct ReviewerVote {
+  pub reviewer: AccountId,
+  pub kind: PendingReviewKind,
+  pub approve: bool,
+  /// Prize place of the contest or hackathon for which the reviewer approves the result
+  pub place: Option<usize>,
+}
+
+/// The claim waiting for the action of the reviewer.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct PendingReview {
+  pub bounty_id: BountyIndex,
+  pub claim_id: ClaimIndex,
+  pub claim: BountyClaim,
+  pub kind: PendingReviewKind,
+  /// The proposal pending in the validators DAO
+  pub proposal_id: Option<U64>,
+}
+
+/// The bounty method and whether the account can call it right now.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct AvailableAction {
+  pub method: String,
+  /// The reason why the method cannot be called, None if the method is available
+  pub blocked_reason: Option<String>,
+}
+
+/// The amounts the bounty will be created with for the given parameters.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct BountyQuote {
+  /// The amount of tokens to be transferred when creating the bounty
+  pub total_amount: U128,
+  pub amount: U128,
+  pub platform_fee: U128,
+  pub dao_fee: U128,
+  /// Payouts for each slot, prize place or subtask, or a single payout for a regular bounty
+  pub payouts: Vec<U128>,
+  /// The reason why the bounty cannot be created, None if the parameters are valid
+  pub error: Option<String>,
+}
+
+/// Compact record that is kept instead of an archived bounty.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct BountySummary {
+  /// sha256 hash of the full bounty record
+  pub bounty_hash: Base58CryptoHash,
+  pub owner: AccountId,
+  pub token: Option<AccountId>,
+  pub amount: U128,
+  pub platform_fee: U128,
+  pub dao_fee: U128,
+  pub status: BountyStatus,
+  pub created_at: U64,
+  pub finalized_at: Option<U64>,
+  pub archived_at: U64,
+  pub claims: Vec<ClaimIndex>,
+}
+
+/// Compact record that is kept instead of an archived claim.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct ClaimSummary {
+  /// sha256 hash of the full claim record
+  pub claim_hash: Base58CryptoHash,
+  pub owner: AccountId,
+  pub bounty_id: BountyIndex,
+  pub status: ClaimStatus,
+  pub bond: Option<U128>,
+  pub created_at: U64,
+}
+
+/// The bounty as returned by the views: the full record, or the summary if the bounty is archived.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde", untagged)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub enum BountyRecord {
+  Active(Box<Bounty>),
+  Archived(BountySummary),
+}
+
+/// The claim as returned by the views: the full record, or the summary if the claim is archived.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde", untagged)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub enum ClaimRecord {
+  Active(BountyClaim),
+  Archived(ClaimSummary),
+}
+
 #[derive(BorshStorageKey, BorshSerialize)]
 pub(crate) enum StorageKey {
   AccountBounties,
@@ -2432,12 +3307,59 @@ pub(crate) enum StorageKey {
   Claims,
   BountyClaims,
   BountyClaimants,
+  PlatformFeeShares,
+  Roles,
+  QueuedChanges,
+  PendingAdminActions,
+  StorageBalances,
+  ArchivedBounties,
+  ArchivedClaims,
+  BountiesByStatus,
+  BountiesByCategory,
+  BountiesByTag,
+  BountiesByToken,
+  BountiesByReviewer,
+  PendingPayouts,
+  ValidatorsDaos,
+  PendingTeamClaims,
+  /// The bounties of one key of the index, the key is hashed to keep the prefix short
+  BountyIndexEntries { index_prefix: Vec<u8>, key_hash: CryptoHash },
+  ProposalBonds,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
 pub enum WhitelistType {
   AdministratorsWhitelist,
   OwnersWhitelist,
   PostpaidSubscribersWhitelist,
 }
+
+/// Administrative roles. The accounts of the administrators whitelist are super-admins:
+/// they hold all roles and are the only ones who can grant and revoke them.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+  /// Adding and updating tokens
+  TokenManager,
+  /// Changing the contract configuration, references, linked contracts and validators DAOs
+  ConfigManager,
+  /// Managing the recipients of the platform fee
+  FeeManager,
+  /// Moderation of bounties
+  Moderator,
+  /// Changing the contract status
+  Pauser,
+  /// Managing the owners and postpaid subscribers whitelists
+  WhitelistManager,
+}
+
+pub const ROLES: [Role; 6] = [
+  Role::TokenManager,
+  Role::ConfigManager,
+  Role::FeeManager,
+  Role::Moderator,
+  Role::Pauser,
+  Role::WhitelistManager,
+];
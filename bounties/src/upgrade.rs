@@ -112,14 +112,36 @@ pub enum OldVersionedBountyClaim {
   Current(BountyClaimV6),
 }
 
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct TokenDetailsV1 {
+  pub enabled: bool,
+  pub min_amount_for_kyc: Option<U128>,
+}
+
+impl From<TokenDetailsV1> for TokenDetails {
+  fn from(token_details: TokenDetailsV1) -> Self {
+    TokenDetails {
+      enabled: token_details.enabled,
+      min_amount_for_kyc: token_details.min_amount_for_kyc,
+      min_amount_for_kyb: None,
+      symbol: None,
+      decimals: None,
+      min_bounty_amount: None,
+      max_bounty_amount: None,
+      min_slot_amount: None,
+    }
+  }
+}
+
+// Contract state version 2.0.17
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct OldState {
-  pub tokens: UnorderedMap<AccountId, TokenDetails>,
+  pub tokens: UnorderedMap<AccountId, TokenDetailsV1>,
   pub last_bounty_id: BountyIndex,
   pub bounties: LookupMap<BountyIndex, VersionedBounty>,
   pub account_bounties: LookupMap<AccountId, Vec<BountyIndex>>,
-  pub bounty_claimants: LookupMap<AccountId, Vec<OldVersionedBountyClaim>>,
-  pub bounty_claimant_accounts: LookupMap<BountyIndex, Vec<AccountId>>,
+  pub old_bounty_claimants: LookupMap<AccountId, Vec<OldVersionedBountyClaim>>,
+  pub old_bounty_claimant_accounts: LookupMap<BountyIndex, Vec<AccountId>>,
   pub locked_amount: Balance,
   pub unlocked_amount: Balance,
   pub admins_whitelist: UnorderedSet<AccountId>,
@@ -133,6 +155,10 @@ pub struct OldState {
   pub total_validators_dao_fees: LookupMap<AccountId, Vec<DaoFeeStats>>,
   pub recipient_of_platform_fee: Option<AccountId>,
   pub status: ContractStatus,
+  pub last_claim_id: ClaimIndex,
+  pub claims: LookupMap<ClaimIndex, VersionedBountyClaim>,
+  pub bounty_claimants: LookupMap<AccountId, Vec<ClaimIndex>>,
+  pub bounty_claims: LookupMap<BountyIndex, Vec<ClaimIndex>>,
 }
 
 #[near_bindgen]
@@ -140,14 +166,23 @@ impl BountiesContract {
   #[private]
   #[init(ignore_state)]
   pub fn migrate() -> Self {
-    let old_state: OldState = env::state_read().expect("Old state doesn't exist");
+    let mut old_state: OldState = env::state_read().expect("Old state doesn't exist");
+    // There are few tokens, so they are migrated at once. The metadata is loaded
+    // later with the refresh_token_metadata method.
+    let old_tokens = old_state.tokens.to_vec();
+    old_state.tokens.clear();
+    let mut tokens = UnorderedMap::new(StorageKey::Tokens);
+    for (token_id, token_details) in old_tokens {
+      tokens.insert(&token_id, &token_details.into());
+    }
+
     Self {
-      tokens: old_state.tokens,
+      tokens,
       last_bounty_id: old_state.last_bounty_id,
       bounties: old_state.bounties,
       account_bounties: old_state.account_bounties,
-      old_bounty_claimants: old_state.bounty_claimants,
-      old_bounty_claimant_accounts: old_state.bounty_claimant_accounts,
+      old_bounty_claimants: old_state.old_bounty_claimants,
+      old_bounty_claimant_accounts: old_state.old_bounty_claimant_accounts,
       locked_amount: old_state.locked_amount,
       unlocked_amount: old_state.unlocked_amount,
       admins_whitelist: old_state.admins_whitelist,
@@ -160,14 +195,73 @@ impl BountiesContract {
       total_fees: old_state.total_fees,
       total_validators_dao_fees: old_state.total_validators_dao_fees,
       recipient_of_platform_fee: old_state.recipient_of_platform_fee,
-      status: ContractStatus::Genesis,
-      last_claim_id: 0,
-      claims: LookupMap::new(StorageKey::Claims),
-      bounty_claimants: LookupMap::new(StorageKey::BountyClaimants),
-      bounty_claims: LookupMap::new(StorageKey::BountyClaims),
+      status: old_state.status,
+      last_claim_id: old_state.last_claim_id,
+      claims: old_state.claims,
+      bounty_claimants: old_state.bounty_claimants,
+      bounty_claims: old_state.bounty_claims,
+      platform_fee_recipients: vec![],
+      platform_fee_shares: LookupMap::new(StorageKey::PlatformFeeShares),
+      roles: LookupMap::new(StorageKey::Roles),
+      paused_categories: vec![],
+      last_queued_change_id: 0,
+      queued_changes: UnorderedMap::new(StorageKey::QueuedChanges),
+      last_admin_action_id: 0,
+      pending_admin_actions: UnorderedMap::new(StorageKey::PendingAdminActions),
+      migration_cursor: if old_state.last_bounty_id > 0 { Some(0) } else { None },
+      storage_balances: LookupMap::new(StorageKey::StorageBalances),
+      archived_bounties: LookupMap::new(StorageKey::ArchivedBounties),
+      archived_claims: LookupMap::new(StorageKey::ArchivedClaims),
+      bounties_by_status: LookupMap::new(StorageKey::BountiesByStatus),
+      bounties_by_category: LookupMap::new(StorageKey::BountiesByCategory),
+      bounties_by_tag: LookupMap::new(StorageKey::BountiesByTag),
+      bounties_by_token: LookupMap::new(StorageKey::BountiesByToken),
+      bounties_by_reviewer: LookupMap::new(StorageKey::BountiesByReviewer),
+      pending_payouts: UnorderedMap::new(StorageKey::PendingPayouts),
+      storage_subsidy_pool: 0,
+      validators_daos: UnorderedMap::new(StorageKey::ValidatorsDaos),
+      pending_team_claims: LookupMap::new(StorageKey::PendingTeamClaims),
+      proposal_bonds: LookupMap::new(StorageKey::ProposalBonds),
     }
   }
 
+  /// Uploads the new contract code passed as the raw input of the call and proposes to stage it.
+  /// Returns the sha256 hash of the code that should be checked before the confirmation.
+  pub fn stage_code(&mut self) -> Base58CryptoHash {
+    self.assert_admins_whitelist(&env::predecessor_account_id());
+    let code_hash = contract_upgrade::upload_code();
+    self.internal_propose_admin_action(AdminAction::StageCode { code_hash });
+    code_hash
+  }
+
+  pub fn unstage_code(&mut self) {
+    self.assert_admins_whitelist(&env::predecessor_account_id());
+    contract_upgrade::unstage_code();
+  }
+
+  /// Deploys the staged code after the upgrade delay has expired and calls `migrate`
+  /// of the new code in the same batch. The hash must match the hash of the staged code.
+  pub fn deploy_staged_code(
+    &mut self,
+    code_hash: Base58CryptoHash,
+    migrate_args: Option<Base64VecU8>,
+  ) -> PromiseOrValue<()> {
+    self.assert_admins_whitelist(&env::predecessor_account_id());
+    let staged_code = contract_upgrade::get_staged_code().expect("No staged code found");
+    assert!(staged_code.code_hash == code_hash, "The hash of the staged code does not match");
+
+    self.internal_propose_admin_action(AdminAction::DeployStagedCode { code_hash, migrate_args })
+  }
+
+  /// Continues the migration of the stored bounties to the current version from the saved cursor.
+  /// The cursor for the next call is returned by get_migration_cursor.
+  pub fn migrate_bounties(&mut self, limit: u64) -> PromiseOrValue<()> {
+    self.assert_admins_whitelist(&env::predecessor_account_id());
+    assert!(self.migration_cursor.is_some(), "There is no migration in progress");
+
+    self.internal_propose_admin_action(AdminAction::MigrateBounties { limit })
+  }
+
   #[private]
   pub fn migrate_claims(&mut self, claims: Vec<BountyClaim>) {
     assert!(
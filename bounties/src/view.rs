@@ -15,6 +15,19 @@ impl BountiesContract {
     self.admins_whitelist.to_vec()
   }
 
+  /// Returns the accounts that are explicitly granted the role (super-admins are not included).
+  pub fn get_role_holders(&self, role: Role) -> Vec<AccountId> {
+    self.roles.get(&role).unwrap_or_default()
+  }
+
+  /// Returns all roles held by the account.
+  pub fn get_account_roles(&self, account_id: AccountId) -> Vec<Role> {
+    ROLES
+      .into_iter()
+      .filter(|role| self.has_role(*role, &account_id))
+      .collect()
+  }
+
   pub fn is_owner_whitelisted(&self, account_id: AccountId) -> bool {
     self.owners_whitelist.contains(&account_id)
   }
@@ -45,15 +58,55 @@ impl BountiesContract {
     U128(locked_storage_amount)
   }
 
-  /// Returns available amount of NEAR that can be spent (outside of amount for storage and bonds).
+  /// Returns available amount of NEAR that can be spent (outside of amount for storage, bonds
+  /// and the storage subsidy pool).
   pub fn get_available_amount(&self) -> U128 {
-    U128(env::account_balance() - self.get_locked_storage_amount().0 - self.locked_amount)
+    U128(
+      env::account_balance() - self.get_locked_storage_amount().0 - self.locked_amount -
+        self.storage_subsidy_pool
+    )
+  }
+
+  pub fn get_storage_subsidy_pool(&self) -> U128 {
+    U128(self.storage_subsidy_pool)
   }
 
   pub fn get_recipient_of_platform_fee(&self) -> Option<AccountId> {
     self.recipient_of_platform_fee.clone()
   }
 
+  pub fn get_platform_fee_recipients(&self) -> Vec<PlatformFeeRecipient> {
+    self.platform_fee_recipients.clone()
+  }
+
+  pub fn get_platform_fee_shares(&self, token_id: AccountId) -> Vec<PlatformFeeShare> {
+    self.platform_fee_shares.get(&token_id).unwrap_or_default()
+  }
+
+  /// Returns the team claim waiting for the team members to accept their membership.
+  pub fn get_pending_team_claim(&self, id: BountyIndex, claimant_id: AccountId) -> Option<PendingTeamClaim> {
+    self.pending_team_claims.get(&(id, claimant_id))
+  }
+
+  pub fn get_pending_payouts(&self, account_id: AccountId) -> Vec<PendingPayout> {
+    self.pending_payouts.get(&account_id).unwrap_or_default()
+  }
+
+  /// Returns the accounts with rewards whose transfer has failed.
+  pub fn get_all_pending_payouts(
+    &self,
+    from_index: Option<u64>,
+    limit: Option<u64>,
+  ) -> Vec<(AccountId, Vec<PendingPayout>)> {
+    let from_index = from_index.unwrap_or(0);
+    let limit = limit.unwrap_or(100);
+    self.pending_payouts
+      .iter()
+      .skip(from_index as usize)
+      .take(limit as usize)
+      .collect()
+  }
+
   pub fn get_non_refunded_bonds_amount(&self) -> U128 {
     U128(self.unlocked_amount)
   }
@@ -70,9 +123,18 @@ impl BountiesContract {
       .collect()
   }
 
-  pub fn get_bounty(&self, id: BountyIndex) -> Bounty {
-    let bounty = self.bounties.get(&id).expect("Bounty not found");
-    bounty.into()
+  /// Returns the summary instead of the full record if the bounty is archived.
+  pub fn get_bounty(&self, id: BountyIndex) -> BountyRecord {
+    match self.bounties.get(&id) {
+      Some(bounty) => BountyRecord::Active(Box::new(bounty.into())),
+      None => BountyRecord::Archived(
+        self.archived_bounties.get(&id).expect("Bounty not found")
+      ),
+    }
+  }
+
+  pub fn get_archived_bounty(&self, id: BountyIndex) -> Option<BountySummary> {
+    self.archived_bounties.get(&id)
   }
 
   pub fn get_last_bounty_id(&self) -> BountyIndex {
@@ -90,6 +152,7 @@ impl BountiesContract {
       .collect()
   }
 
+  /// Bounties hidden by the moderator are omitted.
   pub fn get_bounties(
     &self,
     from_index: Option<BountyIndex>,
@@ -99,15 +162,97 @@ impl BountiesContract {
     let limit = limit.unwrap_or(100);
     (from_index..std::cmp::min(from_index + limit, self.last_bounty_id))
       .filter_map(|id| self.bounties.get(&id).map(|bounty| (id, bounty.into())))
+      .filter(|(_, bounty): &(BountyIndex, Bounty)| !bounty.is_hidden())
       .collect()
   }
 
-  /// Get claim by id.
-  pub fn get_bounty_claim(&self, id: ClaimIndex) -> BountyClaim {
-    self.claims
-      .get(&id)
-      .expect(format!("No claim found with ID {}", id).as_str())
-      .into()
+  /// Returns the bounties that match all specified criteria. Only `limit` of the bounties
+  /// starting from the position `from_index` in the smallest index entry used by the filter
+  /// (or from the bounty ID `from_index` if no index is used) are checked, so the next page
+  /// starts from `from_index + limit`. Bounties hidden by the moderator are omitted.
+  pub fn get_bounties_filtered(
+    &self,
+    filter: BountyFilter,
+    from_index: Option<u64>,
+    limit: Option<u64>,
+  ) -> Vec<(BountyIndex, Bounty)> {
+    let from_index = from_index.unwrap_or(0);
+    let limit = limit.unwrap_or(100);
+    self.internal_get_filter_candidates(&filter, from_index, limit)
+      .unwrap_or_else(|| {
+        (from_index..std::cmp::min(from_index + limit, self.last_bounty_id)).collect()
+      })
+      .into_iter()
+      .filter_map(|id| self.bounties.get(&id).map(|bounty| (id, bounty.into())))
+      .filter(|(_, bounty): &(BountyIndex, Bounty)| !bounty.is_hidden() && filter.matches(bounty))
+      .collect()
+  }
+
+  /// Returns the bounties where the account is one of the reviewers or the validators DAO.
+  pub fn get_reviewer_bounties(
+    &self,
+    account_id: AccountId,
+    from_index: Option<usize>,
+    limit: Option<usize>,
+  ) -> Vec<(BountyIndex, Bounty)> {
+    let from_index = from_index.unwrap_or(0);
+    let limit = limit.unwrap_or(100);
+    self.internal_get_reviewer_bounty_ids(&account_id, from_index, limit)
+      .into_iter()
+      .map(|id| (id, self.internal_get_bounty(id)))
+      .collect()
+  }
+
+  /// Returns the claims waiting for the action of the reviewer or the validators DAO.
+  /// The pagination goes over the reviewed bounties, as in get_reviewer_bounties.
+  pub fn get_pending_reviews(
+    &self,
+    account_id: AccountId,
+    from_index: Option<usize>,
+    limit: Option<usize>,
+  ) -> Vec<PendingReview> {
+    let from_index = from_index.unwrap_or(0);
+    let limit = limit.unwrap_or(100);
+    self.internal_get_pending_reviews(&account_id, from_index, limit)
+  }
+
+  /// Returns the bounty methods that the account can call right now, or the reasons
+  /// why they are blocked. The claim arguments (deadline, description, KYC) are not checked.
+  pub fn get_available_actions(
+    &self,
+    id: BountyIndex,
+    account_id: AccountId,
+    claim_number: Option<u8>,
+  ) -> Vec<AvailableAction> {
+    self.internal_get_available_actions(id, account_id, claim_number)
+  }
+
+  /// Returns the amount to be transferred to create a bounty with the given net reward, along
+  /// with the fees and payouts. The reward can be omitted for one for all bounties and contests
+  /// with prize places, in which case it is taken from the multitasking settings.
+  pub fn get_bounty_quote(
+    &self,
+    bounty_create: BountyCreate,
+    token_id: Option<AccountId>,
+    amount: Option<U128>,
+  ) -> BountyQuote {
+    self.internal_get_bounty_quote(bounty_create, token_id, amount)
+  }
+
+  /// Get claim by id. Returns the summary instead of the full record if the claim is archived.
+  pub fn get_bounty_claim(&self, id: ClaimIndex) -> ClaimRecord {
+    match self.claims.get(&id) {
+      Some(claim) => ClaimRecord::Active(claim.into()),
+      None => ClaimRecord::Archived(
+        self.archived_claims
+          .get(&id)
+          .expect(format!("No claim found with ID {}", id).as_str())
+      ),
+    }
+  }
+
+  pub fn get_archived_claim(&self, id: ClaimIndex) -> Option<ClaimSummary> {
+    self.archived_claims.get(&id)
   }
 
   pub fn get_bounty_claims(
@@ -166,11 +311,52 @@ impl BountiesContract {
       .unwrap_or_default()
   }
 
-  pub fn get_status(&self) -> ContractStatus {
-    self.status.clone()
+  pub fn get_validators_daos(&self) -> Vec<(AccountId, ValidatorsDaoDetails)> {
+    self.validators_daos.to_vec()
+  }
+
+  pub fn get_validators_dao(&self, dao_account_id: AccountId) -> Option<ValidatorsDaoDetails> {
+    self.validators_daos.get(&dao_account_id)
+  }
+
+  /// Total fees of the registered validators DAOs
+  pub fn get_validators_daos_fees(&self) -> Vec<(AccountId, Vec<DaoFeeStats>)> {
+    self.validators_daos
+      .keys()
+      .map(|dao_account_id| {
+        let stats = self.get_total_validators_dao_fees(dao_account_id.clone());
+        (dao_account_id, stats)
+      })
+      .collect()
+  }
+
+  /// Returns the changes waiting for the timelock delay to expire.
+  pub fn get_queued_changes(&self) -> Vec<(u64, QueuedChange)> {
+    self.queued_changes.to_vec()
+  }
+
+  /// Returns the critical admin actions waiting for confirmations.
+  pub fn get_pending_admin_actions(&self) -> Vec<(u64, PendingAdminAction)> {
+    self.pending_admin_actions.to_vec()
+  }
+
+  /// Returns the information about the staged contract code.
+  pub fn get_staged_code(&self) -> Option<StagedCode> {
+    contract_upgrade::get_staged_code()
+  }
+
+  pub fn get_migration_cursor(&self) -> Option<BountyIndex> {
+    self.migration_cursor
+  }
+
+  pub fn get_status(&self) -> ContractStatusDetails {
+    ContractStatusDetails {
+      status: self.status.clone(),
+      paused_categories: self.paused_categories.clone(),
+    }
   }
 
   pub fn get_version() -> String {
-    "2.0.17".to_string()
+    "2.0.18".to_string()
   }
 }
@@ -4,6 +4,7 @@ cd "$(dirname $0)"
 
 for d in */Cargo.toml ; do
     d=$(dirname "$d");
+    [ -f ./$d/build.sh ] || continue
     echo "Building $d";
     sh ./$d/build.sh
 done
@@ -0,0 +1,105 @@
+//! Staged self-upgrade shared by the contracts. The new code is uploaded to the contract storage,
+//! staged once its hash has been approved, and deployed after the upgrade delay together with
+//! the `migrate` call of the new code. The contracts check the access rights themselves.
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::{Base58CryptoHash, Base64VecU8, U64};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, AccountId, Balance, Gas, Promise};
+
+pub const GAS_FOR_MIGRATE: Gas = Gas(150_000_000_000_000);
+/// The staged code cannot be deployed earlier than this delay, whatever the contract settings
+pub const MIN_UPGRADE_DELAY: u64 = 1_000_000_000 * 60 * 60 * 24; // 1 day
+pub const STAGED_CODE_KEY: &[u8] = b"STAGED_CODE";
+pub const STAGED_CODE_INFO_KEY: &[u8] = b"STAGED_CODE_INFO";
+
+const NO_DEPOSIT: Balance = 0;
+
+/// The new contract code waiting to be deployed. The code itself is stored
+/// outside the contract state under the STAGED_CODE_KEY.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct StagedCode {
+  /// sha256 hash of the code
+  pub code_hash: Base58CryptoHash,
+  pub staged_by: AccountId,
+  pub staged_at: U64,
+  pub executable_at: U64,
+}
+
+/// The delay of the upgrade, the contract can only extend the minimum delay
+pub fn upgrade_delay(delay: u64) -> u64 {
+  delay.max(MIN_UPGRADE_DELAY)
+}
+
+/// Stores the contract code passed as the raw input of the call and returns its sha256 hash.
+/// The previously staged code is replaced, the new code has to be staged by its hash.
+pub fn upload_code() -> Base58CryptoHash {
+  let code = env::input().expect("Expected the contract code");
+  assert!(!code.is_empty(), "Expected the contract code");
+
+  env::storage_write(STAGED_CODE_KEY, &code);
+  env::storage_remove(STAGED_CODE_INFO_KEY);
+  let code_hash = env::sha256_array(&code).into();
+  env::log_str(format!("Code {} is uploaded", String::from(&code_hash)).as_str());
+  code_hash
+}
+
+/// Stages the uploaded code, it can be deployed once the upgrade delay has expired.
+pub fn stage_code(code_hash: Base58CryptoHash, staged_by: AccountId, delay: u64) -> StagedCode {
+  let code = env::storage_read(STAGED_CODE_KEY).expect("No uploaded code found");
+  assert!(
+    Base58CryptoHash::from(env::sha256_array(&code)) == code_hash,
+    "The hash of the staged code does not match"
+  );
+
+  let staged_at = env::block_timestamp();
+  let staged_code = StagedCode {
+    code_hash,
+    staged_by,
+    staged_at: U64(staged_at),
+    executable_at: U64(staged_at + upgrade_delay(delay)),
+  };
+  env::storage_write(STAGED_CODE_INFO_KEY, &staged_code.try_to_vec().unwrap());
+  env::log_str(format!("Code {} is staged", String::from(&staged_code.code_hash)).as_str());
+  staged_code
+}
+
+pub fn get_staged_code() -> Option<StagedCode> {
+  env::storage_read(STAGED_CODE_INFO_KEY)
+    .map(|value| StagedCode::try_from_slice(&value).expect("Cannot read the staged code"))
+}
+
+/// Removes the uploaded code, whether it has been staged or not.
+pub fn unstage_code() {
+  assert!(env::storage_has_key(STAGED_CODE_KEY), "No staged code found");
+
+  env::storage_remove(STAGED_CODE_KEY);
+  env::storage_remove(STAGED_CODE_INFO_KEY);
+}
+
+/// Deploys the staged code after the upgrade delay has expired and calls `migrate`
+/// of the new code in the same batch. The hash must match the hash of the staged code.
+pub fn deploy_staged_code(code_hash: Base58CryptoHash, migrate_args: Option<Base64VecU8>) -> Promise {
+  let staged_code = get_staged_code().expect("No staged code found");
+  assert!(staged_code.code_hash == code_hash, "The hash of the staged code does not match");
+  assert!(
+    env::block_timestamp() >= staged_code.executable_at.0,
+    "The upgrade delay has not expired yet"
+  );
+
+  let code = env::storage_read(STAGED_CODE_KEY).expect("No staged code found");
+  env::storage_remove(STAGED_CODE_KEY);
+  env::storage_remove(STAGED_CODE_INFO_KEY);
+  env::log_str(format!("Code {} is deployed", String::from(&code_hash)).as_str());
+
+  Promise::new(env::current_account_id())
+    .deploy_contract(code)
+    .function_call(
+      "migrate".to_string(),
+      migrate_args.map(|args| args.0).unwrap_or_default(),
+      NO_DEPOSIT,
+      GAS_FOR_MIGRATE,
+    )
+}
@@ -168,4 +168,22 @@ impl DisputesContract {
       "The contract status is not Live"
     );
   }
+
+  /// Checks that the attached deposit covers the storage used since `initial_storage`
+  /// and refunds the excess to the caller.
+  pub(crate) fn internal_charge_storage_deposit(initial_storage: StorageUsage) {
+    let required = Balance::from(env::storage_usage().saturating_sub(initial_storage)) *
+      env::storage_byte_cost();
+    let attached_deposit = env::attached_deposit();
+    assert!(
+      attached_deposit >= required,
+      "The attached deposit is not enough to cover the storage, {} yoctoNEAR required",
+      required
+    );
+
+    let refund = attached_deposit - required;
+    if refund > 0 {
+      Promise::new(env::predecessor_account_id()).transfer(refund);
+    }
+  }
 }
@@ -1,11 +1,12 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::{LookupMap, UnorderedSet};
-use near_sdk::json_types::{Base64VecU8, U64};
+use near_sdk::json_types::{Base58CryptoHash, Base64VecU8, U64};
 use near_sdk::serde_json::json;
-use near_sdk::{env, is_promise_success, near_bindgen, AccountId, PanicOnDefault, Promise,
-               PromiseError, PromiseOrValue, Timestamp};
+use near_sdk::{env, is_promise_success, near_bindgen, AccountId, Balance, PanicOnDefault, Promise,
+               PromiseError, PromiseOrValue, StorageUsage, Timestamp};
 
 pub use crate::types::*;
+use contract_upgrade::StagedCode;
 
 pub mod callbacks;
 pub mod internal;
@@ -129,6 +130,8 @@ impl DisputesContract {
     id
   }
 
+  /// The attached deposit must cover the storage of the argument, the excess is refunded.
+  #[payable]
   pub fn provide_arguments(&mut self, id: DisputeIndex, description: String) -> usize {
     self.assert_live();
     let dispute = self.get_dispute(id);
@@ -141,6 +144,7 @@ impl DisputesContract {
     );
 
     let side = dispute.get_side_of_dispute();
+    let initial_storage = env::storage_usage();
     let reason_idx = self.internal_add_argument(
       &id,
       Reason {
@@ -149,6 +153,7 @@ impl DisputesContract {
         description,
       },
     );
+    Self::internal_charge_storage_deposit(initial_storage);
     reason_idx
   }
 
@@ -573,13 +578,13 @@ mod tests {
     testing_env!(context
       .predecessor_account_id(accounts(2))
       .block_timestamp(1)
-      .attached_deposit(1)
+      .attached_deposit(ONE_NEAR / 100)
       .build());
     contract.provide_arguments(dispute_id, "The first argument".to_string());
     testing_env!(context
       .predecessor_account_id(accounts(1))
       .block_timestamp(2)
-      .attached_deposit(1)
+      .attached_deposit(ONE_NEAR / 100)
       .build());
     contract.provide_arguments(dispute_id, "The second argument".to_string());
 
@@ -629,7 +634,7 @@ mod tests {
     testing_env!(context
       .predecessor_account_id(accounts(3))
       .block_timestamp(1)
-      .attached_deposit(1)
+      .attached_deposit(ONE_NEAR / 100)
       .build());
     contract.provide_arguments(dispute_id, "The test argument".to_string());
   }
@@ -662,7 +667,7 @@ mod tests {
     change_status(&mut contract, dispute_id, DisputeStatus::DecisionPending);
     testing_env!(context
       .predecessor_account_id(accounts(2))
-      .attached_deposit(1)
+      .attached_deposit(ONE_NEAR / 100)
       .build());
     contract.provide_arguments(dispute_id, "The test argument".to_string());
   }
@@ -702,7 +707,7 @@ mod tests {
 
     testing_env!(context
       .predecessor_account_id(accounts(2))
-      .attached_deposit(1)
+      .attached_deposit(ONE_NEAR / 100)
       .block_timestamp(20)
       .build());
     contract.provide_arguments(dispute_id, "The test argument".to_string());
@@ -735,7 +740,7 @@ mod tests {
 
     testing_env!(context
       .predecessor_account_id(accounts(2))
-      .attached_deposit(1)
+      .attached_deposit(ONE_NEAR / 100)
       .build());
     contract.provide_arguments(1, "The test argument".to_string());
   }
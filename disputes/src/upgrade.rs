@@ -30,4 +30,28 @@ impl DisputesContract {
       status: ContractStatus::Genesis,
     }
   }
+
+  /// Stages the new contract code passed as the raw input of the call.
+  /// Returns the sha256 hash of the code that should be checked before the deployment.
+  pub fn stage_code(&mut self) -> Base58CryptoHash {
+    self.assert_admin_whitelist(&env::predecessor_account_id());
+    let code_hash = contract_upgrade::upload_code();
+    contract_upgrade::stage_code(code_hash, env::predecessor_account_id(), 0).code_hash
+  }
+
+  pub fn unstage_code(&mut self) {
+    self.assert_admin_whitelist(&env::predecessor_account_id());
+    contract_upgrade::unstage_code();
+  }
+
+  /// Deploys the staged code after the upgrade delay has expired and calls `migrate`
+  /// of the new code in the same batch.
+  pub fn deploy_staged_code(
+    &mut self,
+    code_hash: Base58CryptoHash,
+    migrate_args: Option<Base64VecU8>,
+  ) -> Promise {
+    self.assert_admin_whitelist(&env::predecessor_account_id());
+    contract_upgrade::deploy_staged_code(code_hash, migrate_args)
+  }
 }
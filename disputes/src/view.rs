@@ -67,7 +67,12 @@ impl DisputesContract {
     self.status.clone()
   }
 
+  /// Returns the information about the staged contract code.
+  pub fn get_staged_code(&self) -> Option<StagedCode> {
+    contract_upgrade::get_staged_code()
+  }
+
   pub fn get_version() -> String {
-    "2.0.3".to_string()
+    "2.0.4".to_string()
   }
 }
@@ -3,9 +3,10 @@ use near_sdk::json_types::{U128, U64};
 use serde_json::json;
 use workspaces::Account;
 use bounties::{Bounty, BountyFlow, BountyMetadata, BountyStatus, BountyUpdate, ClaimantApproval,
-               ClaimStatus, ContactDetails, ContactType, Deadline, DefermentOfKYC, Experience,
-               KycConfig, KycVerificationMethod, Multitasking, Postpaid, PrizePlace, Reviewers,
-               ReviewersParams, StartConditions, Subtask, TokenDetails, WhitelistType};
+               ClaimStatus, ContactDetails, ContactType, ContractStatus, ContractStatusDetails,
+               Deadline, DefermentOfKYC, Experience, KycConfig, KycVerificationMethod, Multitasking,
+               Postpaid, PrizePlace, Reviewers, ReviewersParams, StartConditions, Subtask,
+               TokenDetails, WhitelistType};
 use disputes::DisputeStatus;
 
 mod utils;
@@ -51,6 +52,9 @@ async fn main() -> anyhow::Result<()> {
 }
 
 async fn test_create_bounty(e: &Env) -> anyhow::Result<()> {
+  let status = e.bounties.call("get_status").view().await?.json::<ContractStatusDetails>()?;
+  assert_eq!(status, ContractStatusDetails { status: ContractStatus::Live, paused_categories: vec![] });
+
   let last_bounty_id = get_last_bounty_id(&e.bounties).await?;
   assert_eq!(last_bounty_id, 0);
   let owner_balance = e.get_token_balance(e.project_owner.id()).await?;
@@ -123,6 +127,9 @@ async fn test_create_bounty(e: &Env) -> anyhow::Result<()> {
       allow_deadline_stretch: false,
       bounty_flow: BountyFlow::AdvancedFlow,
       allow_creating_many_claims: false,
+      moderation: None,
+      finalized_at: None,
+      dao_fee_percentage: Some(10_000),
     }
   );
 
@@ -1480,6 +1487,7 @@ async fn test_bounty_update(e: &Env) -> anyhow::Result<()> {
     claimant_approval: Some(ClaimantApproval::MultipleClaims),
     reviewers: Some(ReviewersParams::MoreReviewers {
       more_reviewers: vec![reviewer2.id().to_string().parse().unwrap()],
+      threshold: None,
     }),
     amount: None,
   };
@@ -1514,6 +1522,7 @@ async fn test_bounty_update(e: &Env) -> anyhow::Result<()> {
         reviewer2.id().to_string().parse().unwrap(),
         reviewer3.id().to_string().parse().unwrap(),
       ],
+      threshold: None,
     }),
     amount: None,
   };
@@ -1538,6 +1547,7 @@ async fn test_bounty_update(e: &Env) -> anyhow::Result<()> {
           reviewer2.id().to_string().parse().unwrap(),
           reviewer3.id().to_string().parse().unwrap(),
         ],
+        threshold: 1,
       }),
       owner: e.project_owner.id().to_string().parse().unwrap(),
       status: BountyStatus::New,
@@ -1548,6 +1558,9 @@ async fn test_bounty_update(e: &Env) -> anyhow::Result<()> {
       allow_deadline_stretch: false,
       bounty_flow: BountyFlow::AdvancedFlow,
       allow_creating_many_claims: false,
+      moderation: None,
+      finalized_at: None,
+      dao_fee_percentage: None,
     }
   );
 
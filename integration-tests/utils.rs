@@ -164,6 +164,7 @@ impl Env {
       .transact()
       .await?;
     Self::assert_contract_call_result(res, None).await?;
+    Self::add_validators_dao(&validators_dao, &bounties, &bounties_contract_admin).await?;
 
     let arbitrator = root
       .create_subaccount("arbitrator")
@@ -303,6 +304,7 @@ impl Env {
     Self::register_user(&test_token, bounties_contract_admin.id()).await?;
     Self::register_user(&test_token, validators_dao.id()).await?;
     Self::add_token(&test_token, &disputed_bounties, &bounties_contract_admin).await?;
+    Self::add_validators_dao(&validators_dao, &disputed_bounties, &bounties_contract_admin).await?;
     Self::update_configuration_dictionary_entries(
       &disputed_bounties,
       &bounties_contract_admin,
@@ -344,6 +346,18 @@ impl Env {
     Ok(())
   }
 
+  pub async fn storage_deposit(bounties: &Contract, user: &Account) -> anyhow::Result<()> {
+    let res = user
+      .call(bounties.id(), "storage_deposit")
+      .args_json((Option::<AccountId>::None, Option::<bool>::None))
+      .deposit(parse_near!("0.1 N"))
+      .max_gas()
+      .transact()
+      .await?;
+    Self::assert_contract_call_result(res, None).await?;
+    Ok(())
+  }
+
   async fn add_token(
     test_token: &Contract,
     bounties: &Contract,
@@ -362,6 +376,31 @@ impl Env {
     Ok(())
   }
 
+  async fn add_validators_dao(
+    validators_dao: &Contract,
+    bounties: &Contract,
+    administrator: &Account,
+  ) -> anyhow::Result<()> {
+    let policy: DaoPolicy = validators_dao
+      .call("get_policy")
+      .view()
+      .await?
+      .json()?;
+    let res = administrator
+      .call(bounties.id(), "add_validators_dao")
+      .args_json(json!({
+        "dao_account_id": validators_dao.id(),
+        "dao_details": {
+          "add_proposal_bond": policy.proposal_bond,
+        },
+      }))
+      .max_gas()
+      .transact()
+      .await?;
+    Self::assert_contract_call_result(res, None).await?;
+    Ok(())
+  }
+
   pub async fn update_token(
     &self,
     bounties: &Contract,
@@ -621,6 +660,7 @@ impl Env {
       _ => None
     };
     let amount = Self::get_bounty_amount(total_amount, reviewers, postpaid.clone()).await?;
+    Self::storage_deposit(bounties, &self.project_owner).await?;
     let res;
 
     if postpaid.is_none() {
@@ -707,6 +747,8 @@ impl Env {
     expected_msg: Option<&str>,
   ) -> anyhow::Result<()> {
     let config: bounties::Config = bounties.call("get_config").view().await?.json()?;
+    Self::storage_deposit(bounties, if user.is_some() { user.unwrap() } else { &self.freelancer })
+      .await?;
     let res = if user.is_some() { user.unwrap() } else { &self.freelancer }
       .call(bounties.id(), "bounty_claim")
       .args_json((bounty_id, deadline, description, slot))
@@ -959,6 +1001,7 @@ impl Env {
       .call(self.dispute_contract.id(), "provide_arguments")
       .args_json((dispute_id, description))
       .max_gas()
+      .deposit(parse_near!("0.01 N"))
       .transact()
       .await?;
     Self::assert_contract_call_result(res, None).await?;
@@ -1029,7 +1072,7 @@ impl Env {
     let reviewers_params = ReviewersParams::ValidatorsDao {
       validators_dao: ValidatorsDaoParams {
         account_id: self.validators_dao.id().to_string().parse().unwrap(),
-        add_proposal_bond: self.get_proposal_bond().await?,
+        add_proposal_bond: Some(self.get_proposal_bond().await?),
         gas_for_add_proposal: None,
         gas_for_claim_approval: None,
         gas_for_claimant_approval: None,
@@ -1041,6 +1084,7 @@ impl Env {
   pub async fn more_reviewers(&self, reviewer: &Account) -> anyhow::Result<ReviewersParams> {
     let reviewers_params = ReviewersParams::MoreReviewers {
       more_reviewers: vec![reviewer.id().to_string().parse().unwrap()],
+      threshold: None,
     };
     Ok(reviewers_params)
   }
@@ -1398,6 +1442,9 @@ impl Env {
         penalty_validators_dao_fee_percentage: current_config.penalty_validators_dao_fee_percentage,
         use_owners_whitelist,
         max_due_date: None,
+        timelock_delay: None,
+        admin_approval_threshold: None,
+        archive_retention_period: None,
       }
     ).await?;
     Ok(())
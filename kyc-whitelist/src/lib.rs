@@ -1,8 +1,10 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::{ LookupMap, LookupSet };
-use near_sdk::json_types::U64;
+use near_sdk::json_types::{Base58CryptoHash, Base64VecU8, U64};
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::{env, near_bindgen, AccountId, BorshStorageKey, PanicOnDefault};
+use near_sdk::{env, near_bindgen, AccountId, BorshStorageKey, PanicOnDefault, Promise};
+
+use contract_upgrade::StagedCode;
 
 mod migrate;
 
@@ -364,7 +366,13 @@ impl KycWhitelist {
   **/
 
   /// Returns 'true' if the given account ID is whitelisted.
-  pub fn is_whitelisted(&self, account_id: AccountId, service_name: Option<String>) -> bool {
+  /// If the verification type is specified, only entries of this type are taken into account.
+  pub fn is_whitelisted(
+    &self,
+    account_id: AccountId,
+    service_name: Option<String>,
+    verification_type: Option<VerificationType>,
+  ) -> bool {
     let config = self.config.clone().to_config();
     let profile = self.get_default_profile(service_name);
     let whitelist_entries = self.whitelist.get(&account_id).unwrap_or_default();
@@ -373,7 +381,10 @@ impl KycWhitelist {
       .find(
         |e| {
           let entry = e.clone().to_whitelist_entry();
-          Self::math_profile_params(
+          let type_matches = verification_type
+            .as_ref()
+            .map_or(true, |v| *v == entry.verification_type);
+          type_matches && Self::math_profile_params(
             config.clone(),
             profile.clone(),
             entry.provider,
@@ -417,8 +428,13 @@ impl KycWhitelist {
     self.config.clone().into()
   }
 
+  /// Returns the information about the staged contract code.
+  pub fn get_staged_code(&self) -> Option<StagedCode> {
+    contract_upgrade::get_staged_code()
+  }
+
   pub fn get_version() -> String {
-    "2.0.0".to_string()
+    "2.0.1".to_string()
   }
 
   /**
@@ -739,7 +755,7 @@ mod tests {
       .predecessor_account_id(service_account())
       .build());
 
-    assert!(!contract.is_whitelisted(user_account(), default_profile()));
+    assert!(!contract.is_whitelisted(user_account(), default_profile(), None));
     contract.add_account(
       user_account(),
       "fractal".to_string(),
@@ -747,8 +763,10 @@ mod tests {
       Some("basic+liveness+uniq".to_string()),
       None
     );
-    assert!(contract.is_whitelisted(user_account(), default_profile()));
-    assert!(contract.is_whitelisted(user_account(), None));
+    assert!(contract.is_whitelisted(user_account(), default_profile(), None));
+    assert!(contract.is_whitelisted(user_account(), None, None));
+    assert!(contract.is_whitelisted(user_account(), None, Some(VerificationType::KYC)));
+    assert!(!contract.is_whitelisted(user_account(), None, Some(VerificationType::KYB)));
     assert_eq!(
       contract.get_whitelist_entry(user_account(), default_profile()),
       vec![
@@ -768,8 +786,8 @@ mod tests {
       VerificationType::KYC,
       Some("basic+liveness+uniq".to_string()),
     );
-    assert!(!contract.is_whitelisted(user_account(), default_profile()));
-    assert!(!contract.is_whitelisted(user_account(), None));
+    assert!(!contract.is_whitelisted(user_account(), default_profile(), None));
+    assert!(!contract.is_whitelisted(user_account(), None, None));
 
     testing_env!(context
       .predecessor_account_id(admin_account())
@@ -913,7 +931,7 @@ mod tests {
       None
     );
 
-    let _result = contract.is_whitelisted(user_account(), None);
+    let _result = contract.is_whitelisted(user_account(), None, None);
   }
 
   #[test]
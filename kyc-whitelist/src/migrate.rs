@@ -96,4 +96,28 @@ impl KycWhitelist {
       config: config.into(),
     }
   }
+
+  /// Stages the new contract code passed as the raw input of the call.
+  /// Returns the sha256 hash of the code that should be checked before the deployment.
+  pub fn stage_code(&mut self) -> Base58CryptoHash {
+    self.assert_is_whitelist_admin();
+    let code_hash = contract_upgrade::upload_code();
+    contract_upgrade::stage_code(code_hash, env::predecessor_account_id(), 0).code_hash
+  }
+
+  pub fn unstage_code(&mut self) {
+    self.assert_is_whitelist_admin();
+    contract_upgrade::unstage_code();
+  }
+
+  /// Deploys the staged code after the upgrade delay has expired and calls `migrate`
+  /// of the new code in the same batch.
+  pub fn deploy_staged_code(
+    &mut self,
+    code_hash: Base58CryptoHash,
+    migrate_args: Option<Base64VecU8>,
+  ) -> Promise {
+    self.assert_is_whitelist_admin();
+    contract_upgrade::deploy_staged_code(code_hash, migrate_args)
+  }
 }
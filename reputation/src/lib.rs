@@ -1,7 +1,10 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::{UnorderedMap, UnorderedSet};
+use near_sdk::json_types::{Base58CryptoHash, Base64VecU8};
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::{env, near_bindgen, AccountId, BorshStorageKey, PanicOnDefault};
+use near_sdk::{env, near_bindgen, AccountId, BorshStorageKey, PanicOnDefault, Promise};
+
+use contract_upgrade::StagedCode;
 
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
@@ -103,6 +106,8 @@ pub enum ActionKind {
   SuccessfulClaim { with_dispute: bool },
   /// Successful bounty
   SuccessfulBounty,
+  /// The claim was handed over to another claimant
+  ClaimHandedOver,
 }
 
 #[near_bindgen]
@@ -214,8 +219,13 @@ impl ReputationContract {
     self.admin_whitelist.to_vec()
   }
 
+  /// Returns the information about the staged contract code.
+  pub fn get_staged_code(&self) -> Option<StagedCode> {
+    contract_upgrade::get_staged_code()
+  }
+
   pub fn get_version() -> String {
-    "1.0.2".to_string()
+    "1.0.3".to_string()
   }
 
   /**
@@ -251,8 +261,10 @@ impl ReputationContract {
     let without_claimant = matches!(action_kind, ActionKind::BountyCreated) ||
       matches!(action_kind, ActionKind::BountyCancelled) ||
       matches!(action_kind, ActionKind::SuccessfulBounty);
+    // The members of a team claim, except for the claim owner, are recorded without the bounty owner
     let without_bounty_owner = matches!(action_kind, ActionKind::ClaimCancelled) ||
-      matches!(action_kind, ActionKind::ClaimExpired);
+      matches!(action_kind, ActionKind::ClaimExpired) ||
+      matches!(action_kind, ActionKind::SuccessfulClaim { .. }) && bounty_owner.is_none();
 
     if without_claimant {
       assert!(
@@ -331,6 +343,11 @@ impl ReputationContract {
       ActionKind::SuccessfulBounty => {
         bounty_owner_metrics.number_of_successful_bounties += 1;
       }
+      // The previous claimant is not penalized, the successor gets an accepted claim
+      ActionKind::ClaimHandedOver => {
+        claimant_metrics.number_of_claims += 1;
+        claimant_metrics.number_of_accepted_claims += 1;
+      }
       ActionKind::UnsuccessfulClaim {with_dispute} => {
         bounty_owner_metrics.number_of_rejected_claims += 1;
         claimant_metrics.number_of_unsuccessful_claims += 1;
@@ -395,6 +412,40 @@ impl ReputationContract {
     self.assert_admin_whitelist();
     self.bounties_contract = bounties_contract;
   }
+
+  /**
+    Upgrade
+  **/
+
+  #[private]
+  #[init(ignore_state)]
+  pub fn migrate() -> Self {
+    env::state_read().expect("Old state doesn't exist")
+  }
+
+  /// Stages the new contract code passed as the raw input of the call.
+  /// Returns the sha256 hash of the code that should be checked before the deployment.
+  pub fn stage_code(&mut self) -> Base58CryptoHash {
+    self.assert_admin_whitelist();
+    let code_hash = contract_upgrade::upload_code();
+    contract_upgrade::stage_code(code_hash, env::predecessor_account_id(), 0).code_hash
+  }
+
+  pub fn unstage_code(&mut self) {
+    self.assert_admin_whitelist();
+    contract_upgrade::unstage_code();
+  }
+
+  /// Deploys the staged code after the upgrade delay has expired and calls `migrate`
+  /// of the new code in the same batch.
+  pub fn deploy_staged_code(
+    &mut self,
+    code_hash: Base58CryptoHash,
+    migrate_args: Option<Base64VecU8>,
+  ) -> Promise {
+    self.assert_admin_whitelist();
+    contract_upgrade::deploy_staged_code(code_hash, migrate_args)
+  }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -803,6 +854,45 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_stats_after_successful_claim_of_team_member() {
+    let mut context = VMContextBuilder::new();
+    let mut contract = ReputationContract::new(get_bounties_contract(), get_admin_whitelist());
+    testing_env!(context
+      .predecessor_account_id(get_bounties_contract())
+      .build());
+
+    contract.emit(
+      Some(accounts(0)),
+      None,
+      ActionKind::SuccessfulClaim { with_dispute: false }
+    );
+
+    let claimant_stats = contract.claimants_entries.get(&accounts(0)).unwrap();
+    assert_eq!(claimant_stats.number_of_successful_claims, 1);
+    assert_eq!(contract.bounty_owners_entries.len(), 0);
+  }
+
+  #[test]
+  fn test_stats_after_claim_handed_over() {
+    let mut context = VMContextBuilder::new();
+    let mut contract = ReputationContract::new(get_bounties_contract(), get_admin_whitelist());
+    testing_env!(context
+      .predecessor_account_id(get_bounties_contract())
+      .build());
+
+    contract.emit(Some(accounts(0)), Some(accounts(1)), ActionKind::ClaimHandedOver);
+
+    let claimant_stats = contract.claimants_entries.get(&accounts(0)).unwrap();
+    assert_eq!(claimant_stats.number_of_claims, 1);
+    assert_eq!(claimant_stats.number_of_accepted_claims, 1);
+    assert_eq!(claimant_stats.number_of_canceled_claims, 0);
+    assert_eq!(
+      contract.bounty_owners_entries.get(&accounts(1)).unwrap(),
+      BountyOwnerMetrics::default()
+    );
+  }
+
   #[test]
   fn test_stats_after_unsuccessful_claim() {
     let mut context = VMContextBuilder::new();